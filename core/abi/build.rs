@@ -0,0 +1,337 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Generate the typed bindings from the JSON ABIs.
+
+// std
+use std::{env, fmt::Write, fs, path::Path};
+// crates.io
+use serde_json::{Map, Value};
+
+// The precompiles of this chain and the external contracts called by the runtime.
+const ABI_DIRS: [&str; 2] = ["../../precompile/metadata/abi", "metadata/abi"];
+
+fn main() {
+	let mut files = Vec::new();
+
+	for d in ABI_DIRS {
+		println!("cargo:rerun-if-changed={d}");
+
+		files.extend(
+			fs::read_dir(d)
+				.expect("ABI directory must exist; qed")
+				.map(|e| e.expect("ABI directory must be readable; qed").path())
+				.filter(|p| p.extension().is_some_and(|e| e == "json")),
+		);
+	}
+
+	let mut bindings = String::new();
+
+	files.sort();
+
+	for f in files {
+		println!("cargo:rerun-if-changed={}", f.display());
+
+		let json = serde_json::from_str::<Value>(
+			&fs::read_to_string(&f).expect("ABI file must be readable; qed"),
+		)
+		.unwrap_or_else(|e| panic!("invalid ABI file {}: {e}", f.display()));
+		let module = f
+			.file_stem()
+			.and_then(|s| s.to_str())
+			.expect("ABI file name must be UTF-8; qed")
+			.replace('-', "_");
+
+		for (name, contract) in json["contracts"].as_object().expect("contracts must be an object")
+		{
+			gen_contract(&mut bindings, &module, name, contract);
+		}
+	}
+
+	fs::write(
+		Path::new(&env::var("OUT_DIR").expect("OUT_DIR must be set; qed")).join("bindings.rs"),
+		bindings,
+	)
+	.expect("bindings must be writable; qed");
+}
+
+fn gen_contract(out: &mut String, module: &str, name: &str, contract: &Value) {
+	let empty = Map::new();
+	let hashes = contract["hashes"].as_object().unwrap_or(&empty);
+	let methods = contract["devdoc"]["methods"].as_object().unwrap_or(&empty);
+
+	writeln!(out, "/// Bindings of `{name}`.").unwrap();
+	writeln!(out, "pub mod {module} {{").unwrap();
+	writeln!(out, "\t#[allow(unused_imports)]").unwrap();
+	writeln!(out, "\tuse crate::*;").unwrap();
+	writeln!(out, "\t#[allow(unused_imports)]").unwrap();
+	writeln!(out, "\tuse alloc::{{string::String, vec, vec::Vec}};").unwrap();
+
+	for f in contract["abi"]
+		.as_array()
+		.expect("abi must be an array; qed")
+		.iter()
+		.filter(|e| e["type"] == "function")
+	{
+		let fn_name = f["name"].as_str().expect("function name must be a string; qed");
+		let inputs = f["inputs"].as_array().expect("inputs must be an array; qed");
+		let outputs = f["outputs"].as_array().expect("outputs must be an array; qed");
+		let signature = format!(
+			"{fn_name}({})",
+			inputs.iter().map(canonical_type).collect::<Vec<_>>().join(",")
+		);
+		let selector = hashes
+			.get(&signature)
+			.and_then(|h| h.as_str())
+			.unwrap_or_else(|| panic!("missing selector of `{signature}` in `{name}`"));
+		let selector = (0..4)
+			.map(|i| format!("0x{}", &selector[i * 2..i * 2 + 2]))
+			.collect::<Vec<_>>()
+			.join(", ");
+		let doc = methods.get(&signature).and_then(|m| m["details"].as_str());
+		let params =
+			methods.get(&signature).and_then(|m| m["params"].as_object()).unwrap_or(&empty);
+		let call = format!("{}Call", to_camel_case(fn_name));
+		let fields = inputs
+			.iter()
+			.enumerate()
+			.map(|(i, p)| {
+				let n = p["name"].as_str().unwrap_or_default();
+				let field = if n.is_empty() { format!("arg{i}") } else { to_snake_case(n) };
+
+				(field, n, rust_type(p))
+			})
+			.collect::<Vec<_>>();
+		let output_types = outputs.iter().map(rust_type).collect::<Vec<_>>();
+
+		writeln!(out).unwrap();
+		writeln!(out, "\t/// `{signature}`.").unwrap();
+
+		if let Some(doc) = doc {
+			writeln!(out, "\t///").unwrap();
+			writeln!(out, "\t/// {doc}").unwrap();
+		}
+
+		writeln!(out, "\t#[derive(Clone, Debug, PartialEq, Eq)]").unwrap();
+
+		if fields.is_empty() {
+			writeln!(out, "\tpub struct {call};").unwrap();
+		} else {
+			writeln!(out, "\tpub struct {call} {{").unwrap();
+
+			for (field, n, ty) in &fields {
+				match params.get(*n).and_then(|d| d.as_str()) {
+					Some(d) => writeln!(out, "\t\t/// {}.", d.trim_end_matches('.')).unwrap(),
+					None => writeln!(out, "\t\t/// `{n}`.").unwrap(),
+				}
+
+				writeln!(out, "\t\tpub {field}: {ty},").unwrap();
+			}
+
+			writeln!(out, "\t}}").unwrap();
+		}
+
+		writeln!(out, "\timpl SolCall for {call} {{").unwrap();
+		writeln!(out, "\t\tconst NAME: &'static str = \"{fn_name}\";").unwrap();
+		writeln!(out, "\t\tconst SELECTOR: [u8; 4] = [{selector}];").unwrap();
+		writeln!(out, "\t\tconst SIGNATURE: &'static str = \"{signature}\";").unwrap();
+		writeln!(out).unwrap();
+		writeln!(
+			out,
+			"\t\ttype Return = {};",
+			match output_types.len() {
+				0 => "()".into(),
+				1 => output_types[0].clone(),
+				_ => format!("({})", output_types.join(", ")),
+			}
+		)
+		.unwrap();
+		writeln!(out).unwrap();
+		writeln!(out, "\t\tfn input_types() -> Vec<ParamType> {{").unwrap();
+		writeln!(out, "\t\t\t{}", param_types(fields.iter().map(|(_, _, ty)| ty))).unwrap();
+		writeln!(out, "\t\t}}").unwrap();
+		writeln!(out).unwrap();
+		writeln!(out, "\t\tfn output_types() -> Vec<ParamType> {{").unwrap();
+		writeln!(out, "\t\t\t{}", param_types(output_types.iter())).unwrap();
+		writeln!(out, "\t\t}}").unwrap();
+		writeln!(out).unwrap();
+
+		if fields.is_empty() {
+			writeln!(out, "\t\tfn into_tokens(self) -> Vec<Token> {{").unwrap();
+			writeln!(out, "\t\t\tVec::new()").unwrap();
+		} else {
+			writeln!(out, "\t\tfn into_tokens(self) -> Vec<Token> {{").unwrap();
+			writeln!(
+				out,
+				"\t\t\tvec![{}]",
+				fields
+					.iter()
+					.map(|(field, _, _)| format!("self.{field}.into_token()"))
+					.collect::<Vec<_>>()
+					.join(", ")
+			)
+			.unwrap();
+		}
+
+		writeln!(out, "\t\t}}").unwrap();
+		writeln!(out).unwrap();
+
+		match output_types.len() {
+			0 => {
+				writeln!(
+					out,
+					"\t\tfn return_from_tokens(_: Vec<Token>) -> Option<Self::Return> {{"
+				)
+				.unwrap();
+				writeln!(out, "\t\t\tSome(())").unwrap();
+			},
+			n => {
+				writeln!(
+					out,
+					"\t\tfn return_from_tokens(tokens: Vec<Token>) -> Option<Self::Return> {{"
+				)
+				.unwrap();
+				writeln!(out, "\t\t\tlet mut tokens = tokens.into_iter();").unwrap();
+				writeln!(out).unwrap();
+
+				if n == 1 {
+					writeln!(
+						out,
+						"\t\t\t<{} as SolType>::from_token(tokens.next()?)",
+						output_types[0]
+					)
+					.unwrap();
+				} else {
+					let values = output_types
+						.iter()
+						.map(|ty| format!("<{ty} as SolType>::from_token(tokens.next()?)?"))
+						.collect::<Vec<_>>();
+
+					writeln!(out, "\t\t\tSome(({}))", values.join(", ")).unwrap();
+				}
+			},
+		}
+
+		writeln!(out, "\t\t}}").unwrap();
+		writeln!(out, "\t}}").unwrap();
+	}
+
+	writeln!(out, "}}").unwrap();
+}
+
+fn param_types<'a>(types: impl Iterator<Item = &'a String>) -> String {
+	let types = types.map(|ty| format!("<{ty} as SolType>::param_type()")).collect::<Vec<_>>();
+
+	if types.is_empty() {
+		"Vec::new()".into()
+	} else {
+		format!("vec![{}]", types.join(", "))
+	}
+}
+
+fn canonical_type(param: &Value) -> String {
+	let ty = param["type"].as_str().expect("type must be a string; qed");
+
+	match ty.strip_prefix("tuple") {
+		Some(suffix) => format!(
+			"({}){suffix}",
+			components(param).iter().map(canonical_type).collect::<Vec<_>>().join(",")
+		),
+		None => ty.into(),
+	}
+}
+
+fn rust_type(param: &Value) -> String {
+	let ty = param["type"].as_str().expect("type must be a string; qed");
+
+	if let Some(ty) = ty.strip_suffix("[]") {
+		let mut param = param.clone();
+
+		param["type"] = ty.into();
+
+		return format!("Vec<{}>", rust_type(&param));
+	}
+
+	match ty {
+		"address" => "Address".into(),
+		"bool" => "bool".into(),
+		"bytes" => "Bytes".into(),
+		"bytes32" => "[u8; 32]".into(),
+		"string" => "String".into(),
+		"uint8" => "u8".into(),
+		"uint16" => "u16".into(),
+		"uint32" => "u32".into(),
+		"uint64" => "u64".into(),
+		"uint128" => "u128".into(),
+		"uint256" => "Uint".into(),
+		"tuple" => {
+			let types = components(param).iter().map(rust_type).collect::<Vec<_>>();
+
+			if types.len() == 1 {
+				format!("({},)", types[0])
+			} else {
+				format!("({})", types.join(", "))
+			}
+		},
+		ty => panic!("unsupported Solidity type `{ty}`"),
+	}
+}
+
+fn components(param: &Value) -> &Vec<Value> {
+	param["components"].as_array().expect("tuple must have components; qed")
+}
+
+fn to_camel_case(s: &str) -> String {
+	s.split('_')
+		.filter(|w| !w.is_empty())
+		.map(|w| {
+			// `DOMAIN_SEPARATOR` becomes `DomainSeparator`.
+			let w = if w.chars().any(|c| c.is_ascii_lowercase()) {
+				w.to_owned()
+			} else {
+				w.to_ascii_lowercase()
+			};
+			let mut cs = w.chars();
+
+			cs.next().map(|c| c.to_ascii_uppercase().to_string() + cs.as_str()).unwrap_or_default()
+		})
+		.collect()
+}
+
+fn to_snake_case(s: &str) -> String {
+	let mut snake = String::new();
+
+	for (i, c) in s.chars().enumerate() {
+		if c.is_ascii_uppercase() {
+			if i != 0 && !snake.ends_with('_') {
+				snake.push('_');
+			}
+
+			snake.push(c.to_ascii_lowercase());
+		} else {
+			snake.push(c);
+		}
+	}
+
+	match snake.as_str() {
+		"as" | "const" | "enum" | "fn" | "impl" | "in" | "let" | "loop" | "match" | "mod"
+		| "move" | "ref" | "static" | "struct" | "trait" | "type" | "use" | "where" =>
+			format!("r#{snake}"),
+		_ => snake,
+	}
+}
@@ -0,0 +1,34 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+
+pragma solidity >=0.8.3;
+
+/// @title CollatorStakingHub
+/// @notice The interface of the RING staking contract, called by the darwinia staking pallet.
+/// @dev https://github.com/darwinia-network/DIP-7/blob/7fa307136586f06c6911ce98d16c88689d91ba8c/src/collator/CollatorStakingHub.sol
+interface CollatorStakingHub {
+	/// @dev Get the top collators by their staked RING.
+	/// @param k The number of the collators
+	/// @return collators The top collators, padded with the zero address
+	function getTopCollators(uint256 k) external view returns (address[] memory collators);
+
+	/// @dev Distribute the staking reward of a collator to its stakers.
+	/// @param collator The collator address
+	function distributeReward(address collator) external payable;
+}
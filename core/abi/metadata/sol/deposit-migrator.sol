@@ -0,0 +1,36 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+
+pragma solidity >=0.8.3;
+
+/// @title DepositMigrator
+/// @notice The interface of the deposit contract, called by the darwinia deposit pallet.
+interface DepositMigrator {
+	/// @dev The deposit to migrate.
+	struct DepositInfo {
+		uint128 value;
+		uint64 startTime;
+		uint64 expiredTime;
+	}
+
+	/// @dev Migrate the deposits of an account from the deposit pallet.
+	/// @param account The owner of the deposits
+	/// @param deposits The deposits list, the timestamps are in seconds
+	function migrate(address account, DepositInfo[] calldata deposits) external payable;
+}
@@ -0,0 +1,29 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+
+pragma solidity >=0.8.3;
+
+/// @title KtonDAOVault
+/// @notice The interface of the KTON staking contract, called by the darwinia staking pallet.
+/// @dev https://github.com/darwinia-network/KtonDAO/blob/2de20674f2ef90b749ade746d0768c7bda356402/src/staking/KtonDAOVault.sol
+interface KtonDAOVault {
+	/// @dev Distribute the staking reward to the KTON stakers.
+	/// @return returns true on success, false otherwise.
+	function distributeRewards() external payable returns (bool);
+}
@@ -0,0 +1,328 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Darwinia typed Solidity ABI bindings.
+//!
+//! The bindings are generated from the JSON ABIs of the precompiles under
+//! `precompile/metadata/abi` and of the external contracts under `core/abi/metadata/abi`.
+//! Each file becomes a module named after it, e.g. `collator-staking-hub.json` becomes
+//! [`collator_staking_hub`], and each function becomes a `<Name>Call` struct implementing
+//! [`SolCall`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![deny(missing_docs)]
+
+extern crate alloc;
+
+#[cfg(test)]
+mod test;
+
+// core
+use core::fmt::Debug;
+// alloc
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+// crates.io
+pub use ethabi::{Address, ParamType, Token, Uint};
+
+/// ABI encoding or decoding error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+	/// The data is not a valid ABI encoding of the expected types.
+	InvalidData,
+	/// The decoded token does not fit the expected Rust type.
+	InvalidToken,
+}
+
+/// Rust type with a Solidity counterpart.
+pub trait SolType: Sized {
+	/// The Solidity type.
+	fn param_type() -> ParamType;
+
+	/// Convert the value into an ABI token.
+	fn into_token(self) -> Token;
+
+	/// Convert an ABI token back into the value.
+	fn from_token(token: Token) -> Option<Self>;
+}
+
+/// Typed Solidity function call.
+pub trait SolCall: Sized + Debug {
+	/// Function name.
+	const NAME: &'static str;
+	/// Canonical function signature, e.g. `transfer(address,uint256)`.
+	const SIGNATURE: &'static str;
+	/// Function selector.
+	const SELECTOR: [u8; 4];
+
+	/// Decoded return value of the function.
+	type Return;
+
+	/// Solidity types of the function inputs.
+	fn input_types() -> Vec<ParamType>;
+
+	/// Solidity types of the function outputs.
+	fn output_types() -> Vec<ParamType>;
+
+	/// Convert the call arguments into ABI tokens.
+	fn into_tokens(self) -> Vec<Token>;
+
+	/// Convert the output tokens into the return value.
+	fn return_from_tokens(tokens: Vec<Token>) -> Option<Self::Return>;
+
+	/// Encode the call into EVM input data.
+	fn encode(self) -> Vec<u8> {
+		let mut data = Self::SELECTOR.to_vec();
+
+		data.extend(ethabi::encode(&self.into_tokens()));
+
+		data
+	}
+
+	/// Decode the EVM output data into the return value.
+	fn decode_output(data: &[u8]) -> Result<Self::Return, Error> {
+		let tokens = ethabi::decode(&Self::output_types(), data).map_err(|_| Error::InvalidData)?;
+
+		Self::return_from_tokens(tokens).ok_or(Error::InvalidToken)
+	}
+}
+
+/// Decoded EVM revert data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Revert {
+	/// Reverted with `Error(string)`, e.g. `require(false, "reason")`.
+	Error(String),
+	/// Reverted with `Panic(uint256)`, e.g. an arithmetic overflow.
+	Panic(Uint),
+	/// Reverted with a custom error or without any data.
+	Custom(Vec<u8>),
+}
+impl Revert {
+	/// Selector of `Error(string)`.
+	pub const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+	/// Selector of `Panic(uint256)`.
+	pub const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+	/// Decode the revert data.
+	pub fn decode(data: &[u8]) -> Self {
+		let decoded = match data.split_first_chunk::<4>() {
+			Some((&Self::ERROR_SELECTOR, rest)) => ethabi::decode(&[ParamType::String], rest)
+				.ok()
+				.and_then(|ts| String::from_token(ts.into_iter().next()?))
+				.map(Self::Error),
+			Some((&Self::PANIC_SELECTOR, rest)) => ethabi::decode(&[ParamType::Uint(256)], rest)
+				.ok()
+				.and_then(|ts| Uint::from_token(ts.into_iter().next()?))
+				.map(Self::Panic),
+			_ => None,
+		};
+
+		decoded.unwrap_or_else(|| Self::Custom(data.to_vec()))
+	}
+}
+
+/// Solidity dynamic `bytes`.
+///
+/// `Vec<u8>` stands for `uint8[]`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bytes(pub Vec<u8>);
+impl From<Vec<u8>> for Bytes {
+	fn from(bytes: Vec<u8>) -> Self {
+		Self(bytes)
+	}
+}
+impl SolType for Bytes {
+	fn param_type() -> ParamType {
+		ParamType::Bytes
+	}
+
+	fn into_token(self) -> Token {
+		Token::Bytes(self.0)
+	}
+
+	fn from_token(token: Token) -> Option<Self> {
+		match token {
+			Token::Bytes(b) => Some(Self(b)),
+			_ => None,
+		}
+	}
+}
+
+impl SolType for [u8; 32] {
+	fn param_type() -> ParamType {
+		ParamType::FixedBytes(32)
+	}
+
+	fn into_token(self) -> Token {
+		Token::FixedBytes(self.to_vec())
+	}
+
+	fn from_token(token: Token) -> Option<Self> {
+		match token {
+			Token::FixedBytes(b) => b.try_into().ok(),
+			_ => None,
+		}
+	}
+}
+
+impl SolType for Address {
+	fn param_type() -> ParamType {
+		ParamType::Address
+	}
+
+	fn into_token(self) -> Token {
+		Token::Address(self)
+	}
+
+	fn from_token(token: Token) -> Option<Self> {
+		match token {
+			Token::Address(a) => Some(a),
+			_ => None,
+		}
+	}
+}
+
+impl SolType for Uint {
+	fn param_type() -> ParamType {
+		ParamType::Uint(256)
+	}
+
+	fn into_token(self) -> Token {
+		Token::Uint(self)
+	}
+
+	fn from_token(token: Token) -> Option<Self> {
+		match token {
+			Token::Uint(u) => Some(u),
+			_ => None,
+		}
+	}
+}
+
+impl SolType for bool {
+	fn param_type() -> ParamType {
+		ParamType::Bool
+	}
+
+	fn into_token(self) -> Token {
+		Token::Bool(self)
+	}
+
+	fn from_token(token: Token) -> Option<Self> {
+		match token {
+			Token::Bool(b) => Some(b),
+			_ => None,
+		}
+	}
+}
+
+impl SolType for String {
+	fn param_type() -> ParamType {
+		ParamType::String
+	}
+
+	fn into_token(self) -> Token {
+		Token::String(self)
+	}
+
+	fn from_token(token: Token) -> Option<Self> {
+		match token {
+			Token::String(s) => Some(s),
+			_ => None,
+		}
+	}
+}
+
+impl<T> SolType for Vec<T>
+where
+	T: SolType,
+{
+	fn param_type() -> ParamType {
+		ParamType::Array(Box::new(T::param_type()))
+	}
+
+	fn into_token(self) -> Token {
+		Token::Array(self.into_iter().map(T::into_token).collect())
+	}
+
+	fn from_token(token: Token) -> Option<Self> {
+		match token {
+			Token::Array(ts) => ts.into_iter().map(T::from_token).collect(),
+			_ => None,
+		}
+	}
+}
+
+macro_rules! impl_sol_type_for_uint {
+	($($t:ty),+) => {
+		$(
+			impl SolType for $t {
+				fn param_type() -> ParamType {
+					ParamType::Uint(<$t>::BITS as _)
+				}
+
+				fn into_token(self) -> Token {
+					Token::Uint(self.into())
+				}
+
+				fn from_token(token: Token) -> Option<Self> {
+					match token {
+						Token::Uint(u) if u <= Uint::from(<$t>::MAX) => Some(u.low_u128() as _),
+						_ => None,
+					}
+				}
+			}
+		)+
+	};
+}
+impl_sol_type_for_uint!(u8, u16, u32, u64, u128);
+
+macro_rules! impl_sol_type_for_tuple {
+	($($t:ident),+) => {
+		impl<$($t),+> SolType for ($($t,)+)
+		where
+			$($t: SolType,)+
+		{
+			fn param_type() -> ParamType {
+				ParamType::Tuple(vec![$($t::param_type()),+])
+			}
+
+			#[allow(non_snake_case)]
+			fn into_token(self) -> Token {
+				let ($($t,)+) = self;
+
+				Token::Tuple(vec![$($t.into_token()),+])
+			}
+
+			fn from_token(token: Token) -> Option<Self> {
+				let Token::Tuple(ts) = token else { return None };
+				let mut ts = ts.into_iter();
+				let v = ($($t::from_token(ts.next()?)?,)+);
+
+				ts.next().is_none().then_some(v)
+			}
+		}
+	};
+}
+impl_sol_type_for_tuple!(A);
+impl_sol_type_for_tuple!(A, B);
+impl_sol_type_for_tuple!(A, B, C);
+impl_sol_type_for_tuple!(A, B, C, D);
+impl_sol_type_for_tuple!(A, B, C, D, E);
+impl_sol_type_for_tuple!(A, B, C, D, E, F);
+
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
@@ -0,0 +1,116 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// crates.io
+use ethabi::short_signature;
+// darwinia
+use crate::*;
+
+#[test]
+fn selectors_should_match_signatures() {
+	assert_eq!(Revert::ERROR_SELECTOR, short_signature("Error", &[ParamType::String]));
+	assert_eq!(Revert::PANIC_SELECTOR, short_signature("Panic", &[ParamType::Uint(256)]));
+
+	fn check<C>()
+	where
+		C: SolCall,
+	{
+		assert_eq!(C::SELECTOR, short_signature(C::NAME, &C::input_types()), "{}", C::SIGNATURE);
+	}
+
+	check::<collator_staking_hub::GetTopCollatorsCall>();
+	check::<collator_staking_hub::DistributeRewardCall>();
+	check::<kton_dao_vault::DistributeRewardsCall>();
+	check::<deposit_migrator::MigrateCall>();
+	check::<asset::TransferFromCall>();
+	check::<conviction_voting::VotingForCall>();
+	check::<staking::AllocateRingStakingRewardOfCall>();
+	check::<deposit::MigrationFailuresCall>();
+}
+
+#[test]
+fn encode_should_work() {
+	let call = deposit_migrator::MigrateCall {
+		account: Address::repeat_byte(1),
+		deposits: vec![(1, 2, 3), (4, 5, 6)],
+	};
+	let mut expected = deposit_migrator::MigrateCall::SELECTOR.to_vec();
+
+	expected.extend(ethabi::encode(&[
+		Token::Address(Address::repeat_byte(1)),
+		Token::Array(vec![
+			Token::Tuple(vec![Token::Uint(1.into()), Token::Uint(2.into()), Token::Uint(3.into())]),
+			Token::Tuple(vec![Token::Uint(4.into()), Token::Uint(5.into()), Token::Uint(6.into())]),
+		]),
+	]));
+
+	assert_eq!(call.encode(), expected);
+	assert_eq!(kton_dao_vault::DistributeRewardsCall.encode(), [0x6f, 0x4a, 0x2c, 0xd0]);
+}
+
+#[test]
+fn decode_output_should_work() {
+	let collators = vec![Address::repeat_byte(1), Address::zero()];
+
+	assert_eq!(
+		collator_staking_hub::GetTopCollatorsCall::decode_output(&ethabi::encode(&[collators
+			.clone()
+			.into_token()])),
+		Ok(collators)
+	);
+	assert_eq!(
+		kton_dao_vault::DistributeRewardsCall::decode_output(&ethabi::encode(&[Token::Bool(true)])),
+		Ok(true)
+	);
+	assert_eq!(
+		collator_staking_hub::GetTopCollatorsCall::decode_output(&[1]),
+		Err(Error::InvalidData)
+	);
+	assert_eq!(
+		collator_staking_hub::GetTopCollatorsCall::return_from_tokens(vec![Token::Bool(true)]),
+		None
+	);
+}
+
+#[test]
+fn uint_should_not_overflow() {
+	assert_eq!(u8::from_token(Token::Uint(255.into())), Some(255));
+	assert_eq!(u8::from_token(Token::Uint(256.into())), None);
+	assert_eq!(<(u8, bool)>::from_token(Token::Tuple(vec![Token::Uint(1.into())])), None);
+}
+
+#[test]
+fn revert_decode_should_work() {
+	let mut data = Revert::ERROR_SELECTOR.to_vec();
+
+	data.extend(ethabi::encode(&[Token::String("reason".into())]));
+
+	assert_eq!(Revert::decode(&data), Revert::Error("reason".into()));
+
+	let mut data = Revert::PANIC_SELECTOR.to_vec();
+
+	data.extend(ethabi::encode(&[Token::Uint(0x11.into())]));
+
+	assert_eq!(Revert::decode(&data), Revert::Panic(0x11.into()));
+	assert_eq!(Revert::decode(&[]), Revert::Custom(Vec::new()));
+	assert_eq!(
+		Revert::decode(&Revert::ERROR_SELECTOR),
+		Revert::Custom(Revert::ERROR_SELECTOR.to_vec())
+	);
+	assert_eq!(Revert::decode(&[1, 2, 3, 4, 5]), Revert::Custom(vec![1, 2, 3, 4, 5]));
+}
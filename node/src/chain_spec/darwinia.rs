@@ -70,33 +70,18 @@ pub fn development_config() -> ChainSpec {
 
 		// EVM stuff.
 		"evm": {
-			"accounts": BTreeMap::from_iter(
-				Precompiles::set()
-					.into_iter()
-					.map(|p| {
-						(
-							H160(p),
-							GenesisAccount {
-								nonce: Default::default(),
-								balance: Default::default(),
-								storage: Default::default(),
-								code: REVERT_BYTECODE.to_vec(),
-							},
-						)
-					})
-					.chain([
-						// Benchmarking account.
-						(
-							H160::from_str("1000000000000000000000000000000000000001").unwrap(),
-							GenesisAccount {
-								nonce: 1.into(),
-								balance: (10_000_000 * UNIT).into(),
-								storage: Default::default(),
-								code: vec![0x00],
-							},
-						),
-					]),
-			)
+			"accounts": BTreeMap::from_iter([
+				// Benchmarking account.
+				(
+					H160::from_str("1000000000000000000000000000000000000001").unwrap(),
+					GenesisAccount {
+						nonce: 1.into(),
+						balance: (10_000_000 * UNIT).into(),
+						storage: Default::default(),
+						code: vec![0x00],
+					},
+				),
+			])
 		}
 	});
 	#[cfg(feature = "dev")]
@@ -219,24 +204,7 @@ pub fn genesis_config() -> ChainSpec {
 		},
 
 		// XCM stuff.
-		"polkadotXcm": { "safeXcmVersion": Some(SAFE_XCM_VERSION) },
-
-		// EVM stuff.
-		"evm": {
-			"accounts": BTreeMap::from_iter(
-				Precompiles::set().into_iter().map(|p| {
-					(
-						H160(p),
-						GenesisAccount {
-							nonce: Default::default(),
-							balance: Default::default(),
-							storage: Default::default(),
-							code: REVERT_BYTECODE.to_vec(),
-						},
-					)
-				}),
-			)
-		}
+		"polkadotXcm": { "safeXcmVersion": Some(SAFE_XCM_VERSION) }
 	});
 
 	ChainSpec::builder(WASM_BINARY.unwrap(), Extensions {
@@ -51,11 +51,6 @@ const FAITH: &str = "0xC0F0f4ab324C46e55D02D0033343B4Be8A55532d";
 // The default XCM version to set in genesis config.
 const SAFE_XCM_VERSION: u32 = xcm::prelude::XCM_VERSION;
 
-// This is the simplest bytecode to revert without returning any data.
-// We will pre-deploy it under all of our precompiles to ensure they can be called from within
-// contracts. (PUSH1 0x00 PUSH1 0x00 REVERT)
-const REVERT_BYTECODE: [u8; 5] = [0x60, 0x00, 0x60, 0x00, 0xFD];
-
 const TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit";
 
 /// The extensions for the [`ChainSpec`].
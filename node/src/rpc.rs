@@ -21,6 +21,9 @@
 //! used by Substrate nodes. This file extends those RPC definitions with
 //! capabilities that are specific to this project's runtime configuration.
 
+pub mod account_migration;
+pub mod staking;
+
 // std
 use std::{collections::BTreeMap, sync::Arc};
 // darwinia
@@ -125,7 +128,9 @@ where
 		+ sp_api::ProvideRuntimeApi<Block>
 		+ sp_blockchain::HeaderBackend<Block>
 		+ sp_blockchain::HeaderMetadata<Block, Error = sp_blockchain::Error>,
-	C::Api: fp_rpc::ConvertTransactionRuntimeApi<Block>
+	C::Api: darwinia_account_migration::AccountMigrationApi<Block>
+		+ darwinia_staking::StakingApi<Block, AccountId>
+		+ fp_rpc::ConvertTransactionRuntimeApi<Block>
 		+ fp_rpc::EthereumRuntimeRPCApi<Block>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
 		+ sp_block_builder::BlockBuilder<Block>
@@ -142,6 +147,9 @@ where
 		NetApiServer, TxPool, TxPoolApiServer, Web3, Web3ApiServer,
 	};
 	use fp_rpc::NoTransactionConverter;
+	// darwinia
+	use account_migration::{AccountMigration, AccountMigrationApiServer};
+	use staking::{Staking, StakingApiServer};
 	// polkadot-sdk
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
@@ -210,6 +218,8 @@ where
 		.into_rpc(),
 	)?;
 	io.merge(Web3::new(client.clone()).into_rpc())?;
+	io.merge(AccountMigration::new(client.clone()).into_rpc())?;
+	io.merge(Staking::new(client.clone()).into_rpc())?;
 	io.merge(tx_pool.into_rpc())?;
 
 	if let Some(tracing_config) = maybe_tracing_config {
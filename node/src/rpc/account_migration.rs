@@ -0,0 +1,176 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Account migration progress introspection RPC.
+
+// std
+use std::sync::Arc;
+// crates.io
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{
+		error::{ErrorObject, INTERNAL_ERROR_CODE},
+		ErrorObjectOwned,
+	},
+};
+use serde::{Deserialize, Serialize};
+// darwinia
+use darwinia_account_migration::{
+	AccountMigrationApi as AccountMigrationRuntimeApi, AccountStatus as RuntimeAccountStatus,
+	MigrationTotals as RuntimeMigrationTotals,
+};
+use dc_primitives::*;
+// polkadot-sdk
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::AccountId32;
+
+/// Migration status of a legacy account.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum AccountStatus {
+	/// Neither waiting to be migrated nor recorded as migrated.
+	Unknown,
+	/// Waiting to be migrated.
+	Pending {
+		/// RING of the account, including the reserved and the deposited.
+		ring: Balance,
+		/// KTON of the account.
+		kton: Balance,
+		/// Number of the deposits.
+		deposits: u32,
+	},
+	/// Migrated to the new account.
+	Migrated {
+		/// The new account.
+		to: AccountId,
+	},
+	/// Swept into the pallet account, could still be reclaimed through the migration.
+	Swept {
+		/// RING of the account, including the reserved and the deposited.
+		ring: Balance,
+		/// KTON of the account.
+		kton: Balance,
+		/// Number of the deposits.
+		deposits: u32,
+	},
+}
+impl From<RuntimeAccountStatus> for AccountStatus {
+	fn from(status: RuntimeAccountStatus) -> Self {
+		match status {
+			RuntimeAccountStatus::Unknown => Self::Unknown,
+			RuntimeAccountStatus::Pending { ring, kton, deposits } =>
+				Self::Pending { ring, kton, deposits },
+			RuntimeAccountStatus::Migrated { to } => Self::Migrated { to },
+			RuntimeAccountStatus::Swept { ring, kton, deposits } =>
+				Self::Swept { ring, kton, deposits },
+		}
+	}
+}
+
+/// Aggregates of the legacy data waiting to be migrated.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationTotals {
+	/// Number of the accounts.
+	pub accounts: u32,
+	/// RING of the accounts, including the reserved and the deposited.
+	pub ring: Balance,
+	/// KTON of the accounts.
+	pub kton: Balance,
+	/// Number of the deposits.
+	pub deposits: u32,
+	/// RING of the deposits.
+	pub deposit_ring: Balance,
+}
+impl From<RuntimeMigrationTotals> for MigrationTotals {
+	fn from(totals: RuntimeMigrationTotals) -> Self {
+		let RuntimeMigrationTotals { accounts, ring, kton, deposits, deposit_ring } = totals;
+
+		Self { accounts, ring, kton, deposits, deposit_ring }
+	}
+}
+
+/// Account migration RPC methods.
+#[rpc(client, server, namespace = "accountMigration")]
+pub trait AccountMigrationApi<BlockHash> {
+	/// Migration status of the legacy account.
+	#[method(name = "accountStatus")]
+	fn account_status(&self, from: AccountId32, at: Option<BlockHash>) -> RpcResult<AccountStatus>;
+
+	/// Whether the `from` could be migrated to `to`.
+	#[method(name = "isDestinationUsable")]
+	fn is_destination_usable(
+		&self,
+		from: AccountId32,
+		to: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+
+	/// Aggregates of the legacy data waiting to be migrated.
+	#[method(name = "totals")]
+	fn totals(&self, at: Option<BlockHash>) -> RpcResult<MigrationTotals>;
+}
+
+/// Account migration RPC implementation.
+pub struct AccountMigration<C> {
+	client: Arc<C>,
+}
+impl<C> AccountMigration<C> {
+	/// Create a new account migration RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+impl<C> AccountMigrationApiServer<Hash> for AccountMigration<C>
+where
+	C: 'static + Send + Sync + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: AccountMigrationRuntimeApi<Block>,
+{
+	fn account_status(&self, from: AccountId32, at: Option<Hash>) -> RpcResult<AccountStatus> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().account_status(at, from).map(Into::into).map_err(runtime_error)
+	}
+
+	fn is_destination_usable(
+		&self,
+		from: AccountId32,
+		to: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<bool> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().is_destination_usable(at, from, to).map_err(runtime_error)
+	}
+
+	fn totals(&self, at: Option<Hash>) -> RpcResult<MigrationTotals> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().totals(at).map(Into::into).map_err(runtime_error)
+	}
+}
+
+fn runtime_error(e: sp_api::ApiError) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		INTERNAL_ERROR_CODE,
+		"Unable to query the account migration runtime API.",
+		Some(e.to_string()),
+	)
+}
@@ -0,0 +1,130 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Staking reward and session introspection RPC.
+
+// std
+use std::sync::Arc;
+// crates.io
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{
+		error::{ErrorObject, INTERNAL_ERROR_CODE},
+		ErrorObjectOwned,
+	},
+};
+use serde::{Deserialize, Serialize};
+// darwinia
+use darwinia_staking::StakingApi as StakingRuntimeApi;
+use dc_primitives::*;
+// polkadot-sdk
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+
+/// Projected session reward of a collator.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectedReward {
+	/// Collator.
+	pub who: AccountId,
+	/// Projected RING staking reward.
+	pub reward: Balance,
+}
+
+/// Reward balances of a collator.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RewardBalances {
+	/// Reward waiting to be allocated.
+	pub pending: Balance,
+	/// Reward failed to be allocated.
+	pub unallocated: Balance,
+}
+
+/// Staking RPC methods.
+#[rpc(client, server, namespace = "staking")]
+pub trait StakingApi<BlockHash> {
+	/// Projected RING staking reward of each collator for the current session.
+	#[method(name = "projectedSessionRewards")]
+	fn projected_session_rewards(&self, at: Option<BlockHash>) -> RpcResult<Vec<ProjectedReward>>;
+
+	/// Pending and unallocated RING staking rewards of the collator.
+	#[method(name = "rewardBalances")]
+	fn reward_balances(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<RewardBalances>;
+
+	/// Unallocated KTON staking reward.
+	#[method(name = "unallocatedKtonReward")]
+	fn unallocated_kton_reward(&self, at: Option<BlockHash>) -> RpcResult<Balance>;
+
+	/// Collators elected for the next session.
+	#[method(name = "nextCollators")]
+	fn next_collators(&self, at: Option<BlockHash>) -> RpcResult<Vec<AccountId>>;
+}
+
+/// Staking RPC implementation.
+pub struct Staking<C> {
+	client: Arc<C>,
+}
+impl<C> Staking<C> {
+	/// Create a new staking RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+impl<C> StakingApiServer<Hash> for Staking<C>
+where
+	C: 'static + Send + Sync + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: StakingRuntimeApi<Block, AccountId>,
+{
+	fn projected_session_rewards(&self, at: Option<Hash>) -> RpcResult<Vec<ProjectedReward>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let rewards =
+			self.client.runtime_api().projected_session_rewards(at).map_err(runtime_error)?;
+
+		Ok(rewards.into_iter().map(|(who, reward)| ProjectedReward { who, reward }).collect())
+	}
+
+	fn reward_balances(&self, who: AccountId, at: Option<Hash>) -> RpcResult<RewardBalances> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let (pending, unallocated) =
+			self.client.runtime_api().reward_balances_of(at, who).map_err(runtime_error)?;
+
+		Ok(RewardBalances { pending, unallocated })
+	}
+
+	fn unallocated_kton_reward(&self, at: Option<Hash>) -> RpcResult<Balance> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().unallocated_kton_reward(at).map_err(runtime_error)
+	}
+
+	fn next_collators(&self, at: Option<Hash>) -> RpcResult<Vec<AccountId>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().next_collators(at).map_err(runtime_error)
+	}
+}
+
+fn runtime_error(e: sp_api::ApiError) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		INTERNAL_ERROR_CODE,
+		"Unable to query the staking runtime API.",
+		Some(e.to_string()),
+	)
+}
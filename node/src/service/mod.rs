@@ -123,6 +123,8 @@ impl IdentifyVariant for Box<dyn sc_service::ChainSpec> {
 pub trait RuntimeApiCollection:
 	cumulus_primitives_aura::AuraUnincludedSegmentApi<Block>
 	+ cumulus_primitives_core::CollectCollationInfo<Block>
+	+ darwinia_account_migration::AccountMigrationApi<Block>
+	+ darwinia_staking::StakingApi<Block, AccountId>
 	+ fp_rpc::ConvertTransactionRuntimeApi<Block>
 	+ fp_rpc::EthereumRuntimeRPCApi<Block>
 	+ moonbeam_rpc_primitives_debug::DebugRuntimeApi<Block>
@@ -140,6 +142,8 @@ pub trait RuntimeApiCollection:
 impl<Api> RuntimeApiCollection for Api where
 	Api: cumulus_primitives_aura::AuraUnincludedSegmentApi<Block>
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
+		+ darwinia_account_migration::AccountMigrationApi<Block>
+		+ darwinia_staking::StakingApi<Block, AccountId>
 		+ fp_rpc::ConvertTransactionRuntimeApi<Block>
 		+ fp_rpc::EthereumRuntimeRPCApi<Block>
 		+ moonbeam_rpc_primitives_debug::DebugRuntimeApi<Block>
@@ -56,7 +56,11 @@ mod benchmarks {
 		<Accounts<T>>::insert(
 			from,
 			AccountInfo {
-				data: AccountData { free: AMOUNT, ..Default::default() },
+				data: AccountData {
+					free: AMOUNT - MAX_BALANCE_ENTRIES as Balance,
+					reserved: MAX_BALANCE_ENTRIES as _,
+					..Default::default()
+				},
 				..Default::default()
 			},
 		);
@@ -82,6 +86,26 @@ mod benchmarks {
 				512
 			],
 		);
+		<Reserves<T>>::insert(
+			from,
+			BoundedVec::truncate_from(
+				(0..MAX_BALANCE_ENTRIES as u64).map(|i| (i.to_le_bytes(), 1)).collect(),
+			),
+		);
+		<Locks<T>>::insert(
+			from,
+			BoundedVec::truncate_from(
+				(0..MAX_BALANCE_ENTRIES as u64).map(|i| (i.to_le_bytes(), 1)).collect(),
+			),
+		);
+		<Vestings<T>>::insert(from, vesting_schedules());
+	}
+
+	fn vesting_schedules() -> BoundedVec<VestingSchedule, ConstU32<MAX_VESTING_SCHEDULES>> {
+		BoundedVec::truncate_from(vec![
+			VestingSchedule { locked: 1, start: 0, end: Moment::MAX };
+			MAX_VESTING_SCHEDULES as _
+		])
 	}
 
 	#[benchmark]
@@ -98,6 +122,158 @@ mod benchmarks {
 		_(RawOrigin::None, from, to, [0; 64]);
 	}
 
+	#[benchmark]
+	fn migrate_ecdsa() {
+		let from = [0; 32].into();
+		let to = [0; 20].into();
+
+		// Worst-case scenario:
+		//
+		// Migrate all kinds of data.
+		preset_data::<T>(&from);
+
+		#[extrinsic_call]
+		_(RawOrigin::None, from, to, [0; 65]);
+	}
+
+	#[benchmark]
+	fn migrate_multisig(s: Linear<2, MAX_SIGNATORIES>) {
+		let signatories = (0..s)
+			.map(|i| {
+				let mut who = [0; 32];
+
+				who[..4].copy_from_slice(&i.to_be_bytes());
+
+				who.into()
+			})
+			.collect::<Vec<AccountId32>>();
+		let threshold = s as _;
+		let to = [0; 20].into();
+
+		// Worst-case scenario:
+		//
+		// Migrate all kinds of data.
+		preset_data::<T>(&multisig_of(&signatories, threshold));
+
+		#[extrinsic_call]
+		_(RawOrigin::None, signatories, threshold, to, vec![Some([0; 64]); s as _]);
+	}
+
+	#[benchmark]
+	fn migrate_pure_proxy() {
+		let spawner = [0; 32].into();
+		let to = [0; 20].into();
+
+		// Worst-case scenario:
+		//
+		// Migrate all kinds of data.
+		preset_data::<T>(&pure_proxy_of(&spawner, 0, 0, 0, 0));
+
+		#[extrinsic_call]
+		_(RawOrigin::None, spawner, 0, 0, 0, 0, to, [0; 64]);
+	}
+
+	#[benchmark]
+	fn set_claim_deadline() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, Some(0));
+	}
+
+	#[benchmark]
+	fn start_sweep() {
+		<ClaimDeadline<T>>::put(0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root);
+	}
+
+	#[benchmark]
+	fn stop_sweep() {
+		<Sweeping<T>>::put(true);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root);
+	}
+
+	#[benchmark]
+	fn sweep() {
+		let from = [0; 32].into();
+
+		// Worst-case scenario:
+		//
+		// Sweep RING, KTON and the deposits.
+		preset_data::<T>(&from);
+
+		#[block]
+		{
+			Pallet::<T>::sweep(&from).unwrap();
+		}
+	}
+
+	#[benchmark]
+	fn import_legacy_balance() {
+		let from = [0; 32].into();
+		let entries = BoundedVec::truncate_from(
+			(0..MAX_BALANCE_ENTRIES as u64).map(|i| (i.to_le_bytes(), 1)).collect(),
+		);
+
+		// Worst-case scenario:
+		//
+		// The account has been swept.
+		<SweptAccounts<T>>::insert(
+			&from,
+			SweptAccount { account: Default::default(), kton: Default::default() },
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, from, entries.clone(), entries, vesting_schedules());
+	}
+
+	#[benchmark]
+	fn release_legacy_balance() {
+		let who = [0; 20].into();
+
+		// Worst-case scenario:
+		//
+		// Release both the hold and the freeze.
+		<pallet_balances::Pallet<T> as fungible::Mutate<_>>::set_balance(&who, 1_024);
+		<pallet_balances::Pallet<T> as fungible::MutateHold<_>>::hold(
+			&HoldReason::LegacyReserve.into(),
+			&who,
+			1,
+		)
+		.unwrap();
+		<pallet_balances::Pallet<T> as fungible::MutateFreeze<_>>::set_freeze(
+			&FreezeReason::LegacyLock.into(),
+			&who,
+			1,
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, who);
+	}
+
+	#[benchmark]
+	fn vest() {
+		let who = [0; 20].into();
+
+		// Worst-case scenario:
+		//
+		// Update the freeze with the maximum number of schedules.
+		<pallet_balances::Pallet<T> as fungible::Mutate<_>>::set_balance(&who, 1_024);
+		<pallet_balances::Pallet<T> as fungible::MutateFreeze<_>>::set_freeze(
+			&FreezeReason::LegacyVesting.into(),
+			&who,
+			1_024,
+		)
+		.unwrap();
+		<VestingSchedules<T>>::insert(who, vesting_schedules());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who));
+	}
+
 	frame_benchmarking::impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(),
@@ -30,6 +30,8 @@
 //! This extrinsic should be unsigned, the reason is the same as `pallet-claims`.
 //! This extrinsic's payload must contain a signature to the new ECDSA address, signed by their
 //! origin SR25519 key.
+//! ED25519 keys are supported as well, and so are ECDSA keys through `migrate_ecdsa`, whose
+//! `AccountId32` is the BLAKE2 hash of the compressed public key.
 //!
 //! This pallet will store all the account data from Darwinia1 and Darwinia Parachain.
 //! This pallet's genesis will be write into the chain spec JSON directly.
@@ -39,17 +41,41 @@
 //! ```nocompile
 //! user -> send extrinsic -> verify -> put(storages, ECDSA, take(storages, SR25519))
 //! ```
+//!
+//! Accounts without a private key can be migrated as well.
+//! A multisig account is derived from its signatories and threshold, at least `threshold` of the
+//! signatories must sign the migration message.
+//! A pure proxy account is derived from its spawner and creation parameters, the spawner must
+//! sign the migration message.
+//! Their migration messages name the migrated account and its kind, see [`signable_message`].
+//!
+//! The legacy reserves and locks are carried into the new chain as holds and freezes according to
+//! [`Config::LegacyBalanceConverter`], anything not converted becomes free balance.
+//! The unvested part of the legacy vesting schedules is frozen under
+//! [`FreezeReason::LegacyVesting`] and thawed by [`Pallet::vest`] as time goes by.
+//! The legacy reserves, locks and vesting schedules are imported by the governance through
+//! [`Pallet::import_legacy_balance`].
+//!
+//! After the claim deadline, the governance could start a sweep, which moves the unclaimed RING,
+//! KTON and deposits into the pallet account in `on_idle`.
+//! A record is kept for each swept account, its owner could still reclaim through the migration.
+//! The pallet account holds nothing else, so a reclaim always has the funds to move back.
+//! The KTON which could not be deposited into the pallet account, e.g. the dust below the minimum
+//! balance, is left unswept in [`KtonAccounts`].
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
-#[cfg(all(test, feature = "runtime-benchmarks"))]
+#[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
+mod runtime_api;
+pub use runtime_api::*;
+
 mod weights;
 pub use weights::WeightInfo;
 
@@ -57,23 +83,32 @@ pub use weights::WeightInfo;
 use array_bytes::Hexify;
 // darwinia
 use darwinia_deposit::Deposit;
-use dc_primitives::{AccountId as AccountId20, AssetId, Balance, Nonce};
+use dc_primitives::{AccountId as AccountId20, AssetId, Balance, Moment, Nonce};
 // polkadot-sdk
 use frame_support::{
 	migration,
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement::AllowDeath},
-	StorageHasher,
+	storage::with_storage_layer,
+	traits::{
+		fungible, fungibles,
+		tokens::{DepositConsequence, Fortitude, Precision, Preservation, Provenance},
+		Currency,
+		ExistenceRequirement::AllowDeath,
+		UnixTime,
+	},
+	PalletId, StorageHasher,
 };
 use frame_system::{pallet_prelude::*, AccountInfo};
 use pallet_balances::AccountData;
 use sp_core::{
+	blake2_256,
+	ecdsa::Signature as Ecs,
 	ed25519::{Public as Ep, Signature as Es},
 	sr25519::{Public as Sp, Signature as Ss},
 };
 use sp_runtime::{
-	traits::{IdentityLookup, Verify},
-	AccountId32,
+	traits::{AccountIdConversion, IdentityLookup, TrailingZeroInput, Verify},
+	AccountId32, MultiSignature, Perquintill,
 };
 use sp_std::prelude::*;
 
@@ -89,6 +124,25 @@ pub mod pallet {
 	pub(crate) const E_ACCOUNT_NOT_FOUND: u8 = 1;
 	/// Invalid signature.
 	pub(crate) const E_INVALID_SIGNATURE: u8 = 2;
+	/// Invalid multisig signatories or threshold.
+	pub(crate) const E_INVALID_MULTISIG_PARAMS: u8 = 3;
+
+	/// Maximum number of signatories of a multisig account.
+	///
+	/// Same as the `MaxSignatories` of Darwinia1 and Crab1.
+	pub const MAX_SIGNATORIES: u32 = 100;
+	/// Maximum number of legacy reserves or locks of an account.
+	///
+	/// Same as the `MaxReserves` and `MaxLocks` of Darwinia1 and Crab1.
+	pub const MAX_BALANCE_ENTRIES: u32 = 50;
+	/// Maximum number of legacy vesting schedules of an account.
+	///
+	/// Same as the `MAX_VESTING_SCHEDULES` of Darwinia1 and Crab1.
+	pub const MAX_VESTING_SCHEDULES: u32 = 28;
+	/// Maximum number of accounts to sweep in a block.
+	pub const MAX_SWEEPS_PER_BLOCK: u32 = 64;
+	/// Maximum length of a raw storage key kept in the [`LegacyCursor`].
+	pub const MAX_RAW_KEY_LEN: u32 = 128;
 
 	#[pallet::config]
 	pub trait Config:
@@ -98,14 +152,26 @@ pub mod pallet {
 			AccountData = AccountData<Balance>,
 			Lookup = IdentityLookup<AccountId20>,
 		> + pallet_assets::Config<Balance = Balance, AssetId = AssetId>
-		+ pallet_balances::Config<Balance = Balance>
-		+ darwinia_deposit::Config
+		+ pallet_balances::Config<
+			Balance = Balance,
+			RuntimeHoldReason: From<HoldReason>,
+			FreezeIdentifier: From<FreezeReason>,
+		> + darwinia_deposit::Config
 	{
 		/// Override the [`frame_system::Config::RuntimeEvent`].
 		type RuntimeEvent: From<Event> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Weight information for extrinsic in this pallet.
 		type WeightInfo: WeightInfo;
+
+		/// Decide how the legacy reserves and locks are carried into the new chain.
+		type LegacyBalanceConverter: ConvertLegacyBalance<
+			<Self as pallet_balances::Config>::RuntimeHoldReason,
+			<Self as pallet_balances::Config>::FreezeIdentifier,
+		>;
+
+		/// Origin allowed to manage the claim deadline and the sweep.
+		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 	}
 
 	#[allow(missing_docs)]
@@ -114,12 +180,65 @@ pub mod pallet {
 	pub enum Event {
 		/// An account has been migrated.
 		Migrated { from: AccountId32, to: AccountId20 },
+		/// The legacy reserves, locks and vesting schedules of a migrated account have been
+		/// converted.
+		///
+		/// `held` and `frozen` are keyed by the legacy identifiers.
+		BalanceConverted {
+			to: AccountId20,
+			held: Vec<(LegacyId, Balance)>,
+			frozen: Vec<(LegacyId, Balance)>,
+			vesting: Balance,
+			released: Balance,
+		},
+		/// The legacy reserves, locks and vesting schedules of an account have been imported.
+		LegacyBalanceImported { from: AccountId32 },
+		/// The converted legacy reserves and locks of an account have been released.
+		LegacyBalanceReleased { who: AccountId20 },
+		/// The vesting freeze of an account has been updated.
+		Vested { who: AccountId20, unvested: Balance },
+		/// The claim deadline has been set.
+		ClaimDeadlineSet { deadline: Option<Moment> },
+		/// The sweep has been started.
+		SweepStarted,
+		/// The sweep has been stopped.
+		SweepStopped,
+		/// All the unclaimed accounts have been swept.
+		SweepCompleted,
+		/// Failed to sweep an account, the sweep has been stopped.
+		SweepFailed { from: AccountId32 },
+		/// An unclaimed account has been swept into the pallet account.
+		Swept { from: AccountId32, ring: Balance, kton: Balance, deposits: u32 },
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Exceed maximum deposit count.
 		ExceedMaxDeposits,
+		/// The claim deadline is not set.
+		ClaimDeadlineNotSet,
+		/// The claim deadline has not been reached yet.
+		ClaimDeadlineNotReached,
+		/// The legacy account does not exist or has been migrated.
+		AccountNotFound,
+		/// The account has no vesting schedule.
+		NotVesting,
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// A legacy reserve carried through the migration.
+		LegacyReserve,
+	}
+
+	/// A reason for the pallet freezing funds.
+	#[pallet::composite_enum]
+	pub enum FreezeReason {
+		/// A legacy lock carried through the migration.
+		LegacyLock,
+		/// The unvested part of the legacy vesting schedules.
+		LegacyVesting,
 	}
 
 	/// [`frame_system::Account`] data.
@@ -140,8 +259,126 @@ pub mod pallet {
 	#[pallet::unbounded]
 	pub type Deposits<T: Config> = StorageMap<_, Blake2_128Concat, AccountId32, Vec<Deposit>>;
 
+	/// Aggregates of the legacy data waiting to be migrated.
+	///
+	/// Counted step by step in `on_idle` after [`Pallet::initialize_totals`], see
+	/// [`TotalsCursor`], then maintained incrementally on every migration.
+	#[pallet::storage]
+	pub type Totals<T: Config> = StorageValue<_, MigrationTotals, ValueQuery>;
+
+	/// Progress of counting the legacy data into the [`Totals`].
+	///
+	/// Exists only while the counting is in progress.
+	#[pallet::storage]
+	pub type TotalsCursor<T: Config> = StorageValue<_, LegacyCursor>;
+
+	/// The destination of each migrated account.
+	///
+	/// Only the migrations after this record was introduced are tracked.
+	#[pallet::storage]
+	pub type MigratedTo<T: Config> = StorageMap<_, Blake2_128Concat, AccountId32, AccountId20>;
+
+	/// Unix time in milliseconds, after which the unclaimed accounts could be swept.
+	#[pallet::storage]
+	pub type ClaimDeadline<T: Config> = StorageValue<_, Moment>;
+
+	/// Whether the sweep is in progress.
+	#[pallet::storage]
+	pub type Sweeping<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Records of the swept accounts.
+	///
+	/// Used to restore the account if its owner reclaims it later.
+	#[pallet::storage]
+	pub type SweptAccounts<T: Config> = StorageMap<_, Blake2_128Concat, AccountId32, SweptAccount>;
+
+	/// [`Deposits`] of the swept accounts.
+	///
+	/// Used to restore the deposits if their owner reclaims the account later.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type SweptDeposits<T: Config> = StorageMap<_, Blake2_128Concat, AccountId32, Vec<Deposit>>;
+
+	/// [`pallet_balances::Reserves`] data.
+	///
+	/// The anonymous part of [`AccountData::reserved`] is not recorded here and will be released.
+	#[pallet::storage]
+	pub type Reserves<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AccountId32,
+		BoundedVec<(LegacyId, Balance), ConstU32<MAX_BALANCE_ENTRIES>>,
+	>;
+
+	/// [`pallet_balances::Locks`] data.
+	#[pallet::storage]
+	pub type Locks<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AccountId32,
+		BoundedVec<(LegacyId, Balance), ConstU32<MAX_BALANCE_ENTRIES>>,
+	>;
+
+	/// `pallet_vesting::Vesting` data, converted to Unix time.
+	#[pallet::storage]
+	pub type Vestings<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AccountId32,
+		BoundedVec<VestingSchedule, ConstU32<MAX_VESTING_SCHEDULES>>,
+	>;
+
+	/// Vesting schedules of the migrated accounts.
+	///
+	/// The unvested part is frozen under [`FreezeReason::LegacyVesting`].
+	#[pallet::storage]
+	pub type VestingSchedules<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AccountId20,
+		BoundedVec<VestingSchedule, ConstU32<MAX_VESTING_SCHEDULES>>,
+	>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut consumed_weight = Self::count_totals(remaining_weight);
+
+			consumed_weight.saturating_accrue(T::DbWeight::get().reads(1));
+
+			if !<Sweeping<T>>::get() {
+				return consumed_weight;
+			}
+
+			let wt = <T as Config>::WeightInfo::sweep();
+
+			for _ in 0..MAX_SWEEPS_PER_BLOCK {
+				if consumed_weight.saturating_add(wt).any_gt(remaining_weight) {
+					break;
+				}
+
+				consumed_weight.saturating_accrue(wt);
+
+				let Some(from) = <Accounts<T>>::iter_keys().next() else {
+					<Sweeping<T>>::kill();
+					Self::deposit_event(Event::SweepCompleted);
+
+					break;
+				};
+
+				if with_storage_layer(|| Self::sweep(&from)).is_err() {
+					<Sweeping<T>>::kill();
+					Self::deposit_event(Event::SweepFailed { from });
+
+					break;
+				}
+			}
+
+			consumed_weight
+		}
+	}
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Migrate all the account data under the `from` to `to`.
@@ -160,6 +397,193 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Migrate all the account data under a multisig account to `to`.
+		///
+		/// The multisig account is derived from the sorted `signatories` and the `threshold`.
+		/// `signatures` must have the same length as `signatories`, each item is the signature of
+		/// the signatory at the same position or `None` if they didn't sign.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_multisig(signatories.len() as _))]
+		pub fn migrate_multisig(
+			origin: OriginFor<T>,
+			signatories: Vec<AccountId32>,
+			threshold: u16,
+			to: AccountId20,
+			_signatures: Vec<Option<Signature>>,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let from = multisig_of(&signatories, threshold);
+
+			Self::migrate_inner(&from, &to)?;
+			Self::deposit_event(Event::Migrated { from, to });
+
+			Ok(())
+		}
+
+		/// Migrate all the account data under a pure proxy account to `to`.
+		///
+		/// The pure proxy account is derived from the `spawner` and the parameters used to create
+		/// it on Darwinia1 and Crab1.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_pure_proxy())]
+		pub fn migrate_pure_proxy(
+			origin: OriginFor<T>,
+			spawner: AccountId32,
+			proxy_type: u8,
+			index: u16,
+			height: u32,
+			ext_index: u32,
+			to: AccountId20,
+			_signature: Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let from = pure_proxy_of(&spawner, proxy_type, index, height, ext_index);
+
+			Self::migrate_inner(&from, &to)?;
+			Self::deposit_event(Event::Migrated { from, to });
+
+			Ok(())
+		}
+
+		/// Migrate all the account data under the ECDSA keyed `from` to `to`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_ecdsa())]
+		pub fn migrate_ecdsa(
+			origin: OriginFor<T>,
+			from: AccountId32,
+			to: AccountId20,
+			_signature: EcdsaSignature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			Self::migrate_inner(&from, &to)?;
+			Self::deposit_event(Event::Migrated { from, to });
+
+			Ok(())
+		}
+
+		/// Set the claim deadline, in Unix time milliseconds.
+		///
+		/// `None` means there is no deadline.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_claim_deadline())]
+		pub fn set_claim_deadline(
+			origin: OriginFor<T>,
+			deadline: Option<Moment>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			<ClaimDeadline<T>>::set(deadline);
+			Self::deposit_event(Event::ClaimDeadlineSet { deadline });
+
+			Ok(())
+		}
+
+		/// Start sweeping the unclaimed accounts into the pallet account.
+		///
+		/// The sweep is processed in `on_idle` until all the accounts have been swept.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::start_sweep())]
+		pub fn start_sweep(origin: OriginFor<T>) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			let deadline = <ClaimDeadline<T>>::get().ok_or(<Error<T>>::ClaimDeadlineNotSet)?;
+
+			if Self::now() < deadline {
+				Err(<Error<T>>::ClaimDeadlineNotReached)?;
+			}
+
+			<Sweeping<T>>::put(true);
+			Self::deposit_event(Event::SweepStarted);
+
+			Ok(())
+		}
+
+		/// Stop sweeping the unclaimed accounts.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::stop_sweep())]
+		pub fn stop_sweep(origin: OriginFor<T>) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			<Sweeping<T>>::kill();
+			Self::deposit_event(Event::SweepStopped);
+
+			Ok(())
+		}
+
+		/// Import the legacy reserves, locks and vesting schedules of an account.
+		///
+		/// Replace the existing ones, they will be converted when the account is migrated.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::import_legacy_balance())]
+		pub fn import_legacy_balance(
+			origin: OriginFor<T>,
+			from: AccountId32,
+			reserves: BoundedVec<(LegacyId, Balance), ConstU32<MAX_BALANCE_ENTRIES>>,
+			locks: BoundedVec<(LegacyId, Balance), ConstU32<MAX_BALANCE_ENTRIES>>,
+			vestings: BoundedVec<VestingSchedule, ConstU32<MAX_VESTING_SCHEDULES>>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			if !<Accounts<T>>::contains_key(&from) && !<SweptAccounts<T>>::contains_key(&from) {
+				Err(<Error<T>>::AccountNotFound)?;
+			}
+
+			<Reserves<T>>::set(&from, Some(reserves).filter(|r| !r.is_empty()));
+			<Locks<T>>::set(&from, Some(locks).filter(|l| !l.is_empty()));
+			<Vestings<T>>::set(&from, Some(vestings).filter(|v| !v.is_empty()));
+			Self::deposit_event(Event::LegacyBalanceImported { from });
+
+			Ok(())
+		}
+
+		/// Release the converted legacy reserves and locks of a migrated account.
+		///
+		/// The vesting freeze is not affected, see [`Pallet::vest`].
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::release_legacy_balance())]
+		pub fn release_legacy_balance(origin: OriginFor<T>, who: AccountId20) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			<pallet_balances::Pallet<T> as fungible::MutateHold<_>>::release_all(
+				&HoldReason::LegacyReserve.into(),
+				&who,
+				Precision::BestEffort,
+			)?;
+			<pallet_balances::Pallet<T> as fungible::MutateFreeze<_>>::thaw(
+				&FreezeReason::LegacyLock.into(),
+				&who,
+			)?;
+			Self::deposit_event(Event::LegacyBalanceReleased { who });
+
+			Ok(())
+		}
+
+		/// Thaw the vested part of the caller's legacy vesting schedules.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::vest())]
+		pub fn vest(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let schedules = <VestingSchedules<T>>::get(who).ok_or(<Error<T>>::NotVesting)?;
+			let unvested = VestingSchedule::total_locked_at(&schedules, Self::now());
+			let reason = FreezeReason::LegacyVesting.into();
+
+			if unvested == 0 {
+				<pallet_balances::Pallet<T> as fungible::MutateFreeze<_>>::thaw(&reason, &who)?;
+				<VestingSchedules<T>>::remove(who);
+			} else {
+				<pallet_balances::Pallet<T> as fungible::MutateFreeze<_>>::set_freeze(
+					&reason, &who, unvested,
+				)?;
+			}
+
+			Self::deposit_event(Event::Vested { who, unvested });
+
+			Ok(())
+		}
 	}
 	#[pallet::validate_unsigned]
 	impl<T: Config> ValidateUnsigned for Pallet<T> {
@@ -172,6 +596,64 @@ pub mod pallet {
 
 					Self::pre_check_signature(from, to, signature)
 				},
+				Call::migrate_ecdsa { from, to, signature } => {
+					Self::pre_check_existing(from, to)?;
+
+					if verify_ecdsa_signature(
+						from,
+						&signable_message(
+							T::Version::get().spec_name.as_ref(),
+							MigrationKind::Key,
+							from,
+							to,
+						),
+						signature,
+					) {
+						Self::valid_transaction(from)
+					} else {
+						InvalidTransaction::Custom(E_INVALID_SIGNATURE).into()
+					}
+				},
+				Call::migrate_multisig { signatories, threshold, to, signatures } => {
+					let from = multisig_of(signatories, *threshold);
+
+					Self::pre_check_existing(&from, to)?;
+					Self::pre_check_multisig_signatures(
+						&from,
+						signatories,
+						*threshold,
+						to,
+						signatures,
+					)
+				},
+				Call::migrate_pure_proxy {
+					spawner,
+					proxy_type,
+					index,
+					height,
+					ext_index,
+					to,
+					signature,
+				} => {
+					let from = pure_proxy_of(spawner, *proxy_type, *index, *height, *ext_index);
+
+					Self::pre_check_existing(&from, to)?;
+
+					if verify_curve_25519_signature(
+						spawner,
+						&signable_message(
+							T::Version::get().spec_name.as_ref(),
+							MigrationKind::PureProxy,
+							&from,
+							to,
+						),
+						signature,
+					) {
+						Self::valid_transaction(&from)
+					} else {
+						InvalidTransaction::Custom(E_INVALID_SIGNATURE).into()
+					}
+				},
 				_ => InvalidTransaction::Call.into(),
 			}
 		}
@@ -184,7 +666,7 @@ pub mod pallet {
 			from: &AccountId32,
 			to: &AccountId20,
 		) -> Result<(), TransactionValidityError> {
-			if !<Accounts<T>>::contains_key(from) {
+			if !<Accounts<T>>::contains_key(from) && !<SweptAccounts<T>>::contains_key(from) {
 				Err(InvalidTransaction::Custom(E_ACCOUNT_NOT_FOUND))?;
 			}
 			if <frame_system::Account<T>>::contains_key(to) {
@@ -199,32 +681,100 @@ pub mod pallet {
 			to: &AccountId20,
 			signature: &Signature,
 		) -> TransactionValidity {
-			let message = signable_message(T::Version::get().spec_name.as_ref(), to);
+			let message = signable_message(
+				T::Version::get().spec_name.as_ref(),
+				MigrationKind::Key,
+				from,
+				to,
+			);
 
 			if verify_curve_25519_signature(from, &message, signature) {
-				ValidTransaction::with_tag_prefix("account-migration")
-					.and_provides(from)
-					.priority(100)
-					.longevity(TransactionLongevity::MAX)
-					.propagate(true)
-					.build()
+				Self::valid_transaction(from)
 			} else {
 				InvalidTransaction::Custom(E_INVALID_SIGNATURE).into()
 			}
 		}
 
+		fn pre_check_multisig_signatures(
+			from: &AccountId32,
+			signatories: &[AccountId32],
+			threshold: u16,
+			to: &AccountId20,
+			signatures: &[Option<Signature>],
+		) -> TransactionValidity {
+			if signatories.len() < 2
+				|| signatories.len() > MAX_SIGNATORIES as usize
+				|| signatories.windows(2).any(|w| w[0] >= w[1])
+				|| threshold < 1
+				|| threshold as usize > signatories.len()
+				|| signatures.len() != signatories.len()
+			{
+				Err(InvalidTransaction::Custom(E_INVALID_MULTISIG_PARAMS))?;
+			}
+
+			let message = signable_message(
+				T::Version::get().spec_name.as_ref(),
+				MigrationKind::Multisig,
+				from,
+				to,
+			);
+			let mut approvals = 0_u16;
+
+			for (who, signature) in signatories.iter().zip(signatures) {
+				if let Some(signature) = signature {
+					if !verify_curve_25519_signature(who, &message, signature) {
+						Err(InvalidTransaction::Custom(E_INVALID_SIGNATURE))?;
+					}
+
+					approvals += 1;
+				}
+			}
+
+			if approvals < threshold {
+				Err(InvalidTransaction::Custom(E_INVALID_SIGNATURE))?;
+			}
+
+			Self::valid_transaction(from)
+		}
+
+		fn valid_transaction(from: &AccountId32) -> TransactionValidity {
+			ValidTransaction::with_tag_prefix("account-migration")
+				.and_provides(from)
+				.priority(100)
+				.longevity(TransactionLongevity::MAX)
+				.propagate(true)
+				.build()
+		}
+
 		fn migrate_inner(from: &AccountId32, to: &AccountId20) -> DispatchResult {
+			// The account was swept, reclaim it from the pallet account first.
+			Self::restore(from)?;
+
 			let mut account = <Accounts<T>>::take(from)
 				.expect("[pallet::account-migration] already checked in `pre_dispatch`; qed");
 
+			let legacy_reserved = account.data.reserved;
+
+			Self::update_totals(LegacyMap::Accounts, from, |t| {
+				t.accounts = t.accounts.saturating_sub(1);
+				t.ring = t.ring.saturating_sub(account.data.free + account.data.reserved);
+			});
+			<MigratedTo<T>>::insert(from, to);
+
 			account.data.free += account.data.reserved;
 			account.data.reserved = 0;
+			// Will be recalculated while converting the locks.
+			account.data.frozen = 0;
 
 			<frame_system::Account<T>>::insert(to, account);
 
 			if let Some(a) = <KtonAccounts<T>>::take(from) {
 				let encoded_kton_id = KTON_ID.encode();
 
+				Self::update_totals(LegacyMap::KtonAccounts, from, |t| {
+					t.kton = t.kton.saturating_sub(a.balance)
+				});
+
 				migration::put_storage_value(
 					b"Assets",
 					b"Account",
@@ -253,6 +803,11 @@ pub mod pallet {
 					);
 				}
 				if let Some(ds) = <Deposits<T>>::take(from) {
+					Self::update_totals(LegacyMap::Deposits, from, |t| {
+						t.deposits = t.deposits.saturating_sub(ds.len() as _);
+						t.deposit_ring =
+							t.deposit_ring.saturating_sub(ds.iter().map(|d| d.value).sum());
+					});
 					<pallet_balances::Pallet<T> as Currency<_>>::transfer(
 						to,
 						&<T as darwinia_deposit::Config>::Treasury::get(),
@@ -266,14 +821,572 @@ pub mod pallet {
 				}
 			}
 
+			Self::convert_balance(from, to, legacy_reserved);
+
+			Ok(())
+		}
+
+		// Count the legacy entries after the [`TotalsCursor`] within the weight limit.
+		//
+		// Return the consumed weight.
+		fn count_totals(remaining_weight: Weight) -> Weight {
+			let mut consumed_weight = T::DbWeight::get().reads(1);
+			let Some(mut cursor) = <TotalsCursor<T>>::get() else {
+				return consumed_weight;
+			};
+			// Read and write the totals, write the cursor.
+			let wt = T::DbWeight::get().reads_writes(1, 2);
+			// Read a legacy entry.
+			let entry_wt = T::DbWeight::get().reads(1);
+
+			if consumed_weight.saturating_add(wt).saturating_add(entry_wt).any_gt(remaining_weight)
+			{
+				return consumed_weight;
+			}
+
+			consumed_weight.saturating_accrue(wt);
+
+			let mut totals = <Totals<T>>::get();
+			let mut completed = false;
+
+			while !consumed_weight.saturating_add(entry_wt).any_gt(remaining_weight) {
+				consumed_weight.saturating_accrue(entry_wt);
+
+				if !Self::count_next(&mut cursor, &mut totals) {
+					completed = true;
+
+					break;
+				}
+			}
+
+			<Totals<T>>::put(totals);
+
+			if completed {
+				<TotalsCursor<T>>::kill();
+			} else {
+				<TotalsCursor<T>>::put(cursor);
+			}
+
+			consumed_weight
+		}
+
+		// Count the next legacy entry and move the cursor.
+		//
+		// Return `false` if all the entries were counted.
+		fn count_next(cursor: &mut LegacyCursor, totals: &mut MigrationTotals) -> bool {
+			let start_key = cursor.last_key.as_ref().map(|k| k.to_vec());
+			let last_key = match cursor.map {
+				LegacyMap::Accounts => {
+					let mut iter =
+						start_key.map(<Accounts<T>>::iter_from).unwrap_or_else(<Accounts<T>>::iter);
+
+					iter.next().map(|(_, a)| {
+						totals.accounts += 1;
+						totals.ring += a.data.free + a.data.reserved;
+
+						iter.last_raw_key().to_vec()
+					})
+				},
+				LegacyMap::KtonAccounts => {
+					let mut iter = start_key
+						.map(<KtonAccounts<T>>::iter_from)
+						.unwrap_or_else(<KtonAccounts<T>>::iter);
+
+					iter.next().map(|(_, a)| {
+						totals.kton += a.balance;
+
+						iter.last_raw_key().to_vec()
+					})
+				},
+				LegacyMap::Deposits => {
+					let mut iter =
+						start_key.map(<Deposits<T>>::iter_from).unwrap_or_else(<Deposits<T>>::iter);
+
+					iter.next().map(|(_, ds)| {
+						totals.deposits += ds.len() as u32;
+						totals.deposit_ring += ds.iter().map(|d| d.value).sum::<Balance>();
+
+						iter.last_raw_key().to_vec()
+					})
+				},
+			};
+
+			match (last_key, cursor.map.next()) {
+				(Some(k), _) => cursor.last_key = Some(BoundedVec::truncate_from(k)),
+				(None, Some(map)) => *cursor = LegacyCursor { map, last_key: None },
+				(None, None) => return false,
+			}
+
+			true
+		}
+
+		// Update the [`Totals`] for a legacy entry.
+		//
+		// Skip the entries which are not counted yet, they will be counted with their latest
+		// state.
+		fn update_totals<F>(map: LegacyMap, who: &AccountId32, f: F)
+		where
+			F: FnOnce(&mut MigrationTotals),
+		{
+			if let Some(cursor) = <TotalsCursor<T>>::get() {
+				let counted = map < cursor.map
+					|| (map == cursor.map
+						&& cursor.last_key.is_some_and(|k| {
+							map.hashed_key_for::<T>(who).as_slice() <= k.as_slice()
+						}));
+
+				if !counted {
+					return;
+				}
+			}
+
+			<Totals<T>>::mutate(f);
+		}
+
+		fn now() -> Moment {
+			<pallet_timestamp::Pallet<T> as UnixTime>::now().as_millis()
+		}
+
+		// Move the unclaimed RING, KTON and deposits into the pallet account.
+		//
+		// The deposited RING is a part of the account RING, which is moved into the pallet account
+		// as a whole. The reserves and locks are kept, they will be processed on reclaim.
+		pub(crate) fn sweep(from: &AccountId32) -> DispatchResult {
+			let Some(account) = <Accounts<T>>::take(from) else {
+				return Ok(());
+			};
+			let pallet_account = account_id();
+			// Leave the KTON unswept if it can not be deposited, instead of failing the sweep.
+			let kton = <KtonAccounts<T>>::get(from).filter(|a| {
+				<pallet_assets::Pallet<T> as fungibles::Inspect<_>>::can_deposit(
+					KTON_ID,
+					&pallet_account,
+					a.balance,
+					Provenance::Extant,
+				) == DepositConsequence::Success
+			});
+
+			if kton.is_some() {
+				<KtonAccounts<T>>::remove(from);
+			}
+
+			let deposits = <Deposits<T>>::take(from);
+			let ring_amount = account.data.free + account.data.reserved;
+			let kton_amount = kton.as_ref().map(|a| a.balance).unwrap_or_default();
+			let deposit_count = deposits.as_ref().map(|ds| ds.len() as u32).unwrap_or_default();
+			let deposit_ring = deposits.iter().flatten().map(|d| d.value).sum::<Balance>();
+
+			// The legacy balances are already counted in the total issuance and the asset supply.
+			//
+			// Unlike `migrate_inner`, the asset details of KTON are not patched by hand here, the
+			// asset account of the pallet account is created and counted by `pallet_assets` if
+			// needed.
+			<pallet_balances::Pallet<T> as fungible::Unbalanced<_>>::increase_balance(
+				&pallet_account,
+				ring_amount,
+				Precision::Exact,
+			)?;
+
+			if kton_amount != 0 {
+				<pallet_assets::Pallet<T> as fungibles::Unbalanced<_>>::increase_balance(
+					KTON_ID,
+					&pallet_account,
+					kton_amount,
+					Precision::Exact,
+				)?;
+			}
+
+			Self::update_totals(LegacyMap::Accounts, from, |t| {
+				t.accounts = t.accounts.saturating_sub(1);
+				t.ring = t.ring.saturating_sub(ring_amount);
+			});
+			Self::update_totals(LegacyMap::KtonAccounts, from, |t| {
+				t.kton = t.kton.saturating_sub(kton_amount);
+			});
+			<SweptAccounts<T>>::insert(from, SweptAccount { account, kton });
+
+			if let Some(ds) = deposits {
+				Self::update_totals(LegacyMap::Deposits, from, |t| {
+					t.deposits = t.deposits.saturating_sub(deposit_count);
+					t.deposit_ring = t.deposit_ring.saturating_sub(deposit_ring);
+				});
+				<SweptDeposits<T>>::insert(from, ds);
+			}
+
+			Self::deposit_event(Event::Swept {
+				from: from.to_owned(),
+				ring: ring_amount,
+				kton: kton_amount,
+				deposits: deposit_count,
+			});
+
+			Ok(())
+		}
+
+		// Move the swept RING and KTON back from the pallet account and restore the legacy data.
+		fn restore(from: &AccountId32) -> DispatchResult {
+			let Some(SweptAccount { account, kton }) = <SweptAccounts<T>>::take(from) else {
+				return Ok(());
+			};
+			let pallet_account = account_id();
+			let ring_amount = account.data.free + account.data.reserved;
+			let kton_amount = kton.as_ref().map(|a| a.balance).unwrap_or_default();
+
+			<pallet_balances::Pallet<T> as fungible::Unbalanced<_>>::decrease_balance(
+				&pallet_account,
+				ring_amount,
+				Precision::Exact,
+				Preservation::Expendable,
+				Fortitude::Polite,
+			)?;
+
+			if kton_amount != 0 {
+				<pallet_assets::Pallet<T> as fungibles::Unbalanced<_>>::decrease_balance(
+					KTON_ID,
+					&pallet_account,
+					kton_amount,
+					Precision::Exact,
+					Preservation::Expendable,
+					Fortitude::Polite,
+				)?;
+			}
+
+			Self::update_totals(LegacyMap::Accounts, from, |t| {
+				t.accounts = t.accounts.saturating_add(1);
+				t.ring = t.ring.saturating_add(ring_amount);
+			});
+			Self::update_totals(LegacyMap::KtonAccounts, from, |t| {
+				t.kton = t.kton.saturating_add(kton_amount);
+			});
+			<Accounts<T>>::insert(from, account);
+
+			if let Some(a) = kton {
+				<KtonAccounts<T>>::insert(from, a);
+			}
+			if let Some(ds) = <SweptDeposits<T>>::take(from) {
+				Self::update_totals(LegacyMap::Deposits, from, |t| {
+					t.deposits = t.deposits.saturating_add(ds.len() as _);
+					t.deposit_ring =
+						t.deposit_ring.saturating_add(ds.iter().map(|d| d.value).sum());
+				});
+				<Deposits<T>>::insert(from, ds);
+			}
+
 			Ok(())
 		}
+
+		fn convert_balance(from: &AccountId32, to: &AccountId20, legacy_reserved: Balance) {
+			let reserves = <Reserves<T>>::take(from).unwrap_or_default();
+			let locks = <Locks<T>>::take(from).unwrap_or_default();
+			let vestings = <Vestings<T>>::take(from).unwrap_or_default();
+
+			if legacy_reserved == 0
+				&& reserves.is_empty()
+				&& locks.is_empty()
+				&& vestings.is_empty()
+			{
+				return;
+			}
+
+			let mut held = Vec::new();
+			let mut frozen = Vec::new();
+			let mut released = legacy_reserved;
+
+			for (id, amount) in reserves {
+				let amount = amount.min(released);
+
+				if amount == 0 {
+					continue;
+				}
+				if Self::convert(T::LegacyBalanceConverter::convert_reserve(&id), to, amount) {
+					released -= amount;
+
+					held.push((id, amount));
+				}
+			}
+			for (id, amount) in locks {
+				if amount == 0 {
+					continue;
+				}
+				if Self::convert(T::LegacyBalanceConverter::convert_lock(&id), to, amount) {
+					frozen.push((id, amount));
+				}
+			}
+
+			let vesting = VestingSchedule::total_locked_at(&vestings, Self::now());
+
+			if vesting != 0 {
+				// Only fails if the maximum number of freezes is exceeded, which is a config error.
+				let _ = <pallet_balances::Pallet<T> as fungible::MutateFreeze<_>>::extend_freeze(
+					&FreezeReason::LegacyVesting.into(),
+					to,
+					vesting,
+				);
+
+				<VestingSchedules<T>>::insert(to, vestings);
+			}
+
+			Self::deposit_event(Event::BalanceConverted {
+				to: *to,
+				held,
+				frozen,
+				vesting,
+				released,
+			});
+		}
+
+		// Hold or freeze the amount according to the conversion.
+		//
+		// Return `false` if the amount is left in the free balance.
+		fn convert(
+			conversion: Conversion<
+				<T as pallet_balances::Config>::RuntimeHoldReason,
+				<T as pallet_balances::Config>::FreezeIdentifier,
+			>,
+			to: &AccountId20,
+			amount: Balance,
+		) -> bool {
+			match conversion {
+				Conversion::Hold(reason) => <pallet_balances::Pallet<T> as fungible::MutateHold<
+					_,
+				>>::hold(&reason, to, amount)
+				.is_ok(),
+				// Freezes overlap like the legacy locks.
+				Conversion::Freeze(id) => <pallet_balances::Pallet<T> as fungible::MutateFreeze<
+					_,
+				>>::extend_freeze(&id, to, amount)
+				.is_ok(),
+				Conversion::Release => false,
+			}
+		}
+	}
+	impl<T> Pallet<T>
+	where
+		T: Config,
+	{
+		/// Start counting the legacy data into the [`Totals`].
+		///
+		/// The counting is done step by step in `on_idle`.
+		/// This is a one-time operation, do nothing if it was already started.
+		pub fn initialize_totals() -> Weight {
+			if <Totals<T>>::exists() {
+				return T::DbWeight::get().reads(1);
+			}
+
+			<Totals<T>>::put(MigrationTotals::default());
+			<TotalsCursor<T>>::put(LegacyCursor { map: LegacyMap::Accounts, last_key: None });
+
+			T::DbWeight::get().reads_writes(1, 2)
+		}
+
+		/// Migration status of the legacy account.
+		pub fn account_status(from: &AccountId32) -> AccountStatus {
+			if let Some(a) = <Accounts<T>>::get(from) {
+				AccountStatus::Pending {
+					ring: a.data.free + a.data.reserved,
+					kton: <KtonAccounts<T>>::get(from).map(|a| a.balance).unwrap_or_default(),
+					deposits: <Deposits<T>>::decode_len(from).unwrap_or_default() as _,
+				}
+			} else if let Some(SweptAccount { account, kton }) = <SweptAccounts<T>>::get(from) {
+				AccountStatus::Swept {
+					ring: account.data.free + account.data.reserved,
+					// Including the unswept KTON.
+					kton: kton
+						.or_else(|| <KtonAccounts<T>>::get(from))
+						.map(|a| a.balance)
+						.unwrap_or_default(),
+					deposits: <SweptDeposits<T>>::decode_len(from).unwrap_or_default() as _,
+				}
+			} else if let Some(to) = <MigratedTo<T>>::get(from) {
+				AccountStatus::Migrated { to }
+			} else {
+				AccountStatus::Unknown
+			}
+		}
+
+		/// Whether the `from` could be migrated to `to`.
+		pub fn is_destination_usable(from: &AccountId32, to: &AccountId20) -> bool {
+			Self::pre_check_existing(from, to).is_ok()
+		}
 	}
 }
 pub use pallet::*;
 
+/// The account holding the swept RING and KTON until they are reclaimed.
+pub fn account_id() -> AccountId20 {
+	PalletId(*b"da/acmig").into_account_truncating()
+}
+
 /// Raw signature.
 pub(crate) type Signature = [u8; 64];
+/// Raw ECDSA signature.
+pub(crate) type EcdsaSignature = [u8; 65];
+
+/// Aggregates of the legacy data waiting to be migrated.
+#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct MigrationTotals {
+	/// Number of the accounts.
+	pub accounts: u32,
+	/// RING of the accounts, including the reserved and the deposited.
+	pub ring: Balance,
+	/// KTON of the accounts.
+	pub kton: Balance,
+	/// Number of the deposits.
+	pub deposits: u32,
+	/// RING of the deposits.
+	pub deposit_ring: Balance,
+}
+
+/// Legacy data map counted into the [`Totals`].
+#[derive(
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+)]
+pub enum LegacyMap {
+	/// [`Accounts`].
+	Accounts,
+	/// [`KtonAccounts`].
+	KtonAccounts,
+	/// [`Deposits`].
+	Deposits,
+}
+impl LegacyMap {
+	fn next(self) -> Option<Self> {
+		match self {
+			Self::Accounts => Some(Self::KtonAccounts),
+			Self::KtonAccounts => Some(Self::Deposits),
+			Self::Deposits => None,
+		}
+	}
+
+	fn hashed_key_for<T>(self, who: &AccountId32) -> Vec<u8>
+	where
+		T: Config,
+	{
+		match self {
+			Self::Accounts => <Accounts<T>>::hashed_key_for(who),
+			Self::KtonAccounts => <KtonAccounts<T>>::hashed_key_for(who),
+			Self::Deposits => <Deposits<T>>::hashed_key_for(who),
+		}
+	}
+}
+
+/// Progress of counting the legacy data, see [`TotalsCursor`].
+#[derive(Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct LegacyCursor {
+	/// The map being counted.
+	pub map: LegacyMap,
+	/// Raw key of the last counted entry in the map.
+	pub last_key: Option<BoundedVec<u8, ConstU32<MAX_RAW_KEY_LEN>>>,
+}
+
+/// Migration status of a legacy account.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum AccountStatus {
+	/// Neither waiting to be migrated nor recorded as migrated.
+	Unknown,
+	/// Waiting to be migrated.
+	Pending {
+		/// RING of the account, including the reserved and the deposited.
+		ring: Balance,
+		/// KTON of the account.
+		kton: Balance,
+		/// Number of the deposits.
+		deposits: u32,
+	},
+	/// Migrated to the new account.
+	Migrated {
+		/// The new account.
+		to: AccountId20,
+	},
+	/// Swept into the pallet account, could still be reclaimed through the migration.
+	Swept {
+		/// RING of the account, including the reserved and the deposited.
+		ring: Balance,
+		/// KTON of the account.
+		kton: Balance,
+		/// Number of the deposits.
+		deposits: u32,
+	},
+}
+
+/// Record of a swept account.
+#[derive(PartialEq, Eq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct SweptAccount {
+	account: AccountInfo<Nonce, AccountData<Balance>>,
+	kton: Option<AssetAccount>,
+}
+
+/// Identifier of a legacy reserve or lock.
+pub type LegacyId = [u8; 8];
+
+/// How a legacy reserve or lock is carried into the new chain.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Conversion<H, F> {
+	/// Release the amount into the free balance.
+	Release,
+	/// Hold the amount for the given reason.
+	Hold(H),
+	/// Freeze the amount for the given reason.
+	Freeze(F),
+}
+
+/// Map the legacy reserve and lock identifiers to their [`Conversion`]s.
+pub trait ConvertLegacyBalance<H, F> {
+	/// Convert a legacy named reserve.
+	fn convert_reserve(id: &LegacyId) -> Conversion<H, F>;
+
+	/// Convert a legacy lock.
+	fn convert_lock(id: &LegacyId) -> Conversion<H, F>;
+}
+impl<H, F> ConvertLegacyBalance<H, F> for () {
+	fn convert_reserve(_: &LegacyId) -> Conversion<H, F> {
+		Conversion::Release
+	}
+
+	fn convert_lock(_: &LegacyId) -> Conversion<H, F> {
+		Conversion::Release
+	}
+}
+
+/// A legacy vesting schedule, converted from the Darwinia1 block numbers to Unix time.
+///
+/// `locked` is released linearly from `start` to `end`, in milliseconds.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct VestingSchedule {
+	/// Amount locked at `start`.
+	pub locked: Balance,
+	/// Start of the release.
+	pub start: Moment,
+	/// End of the release.
+	pub end: Moment,
+}
+impl VestingSchedule {
+	/// Amount still locked at `now`.
+	pub fn locked_at(&self, now: Moment) -> Balance {
+		if now <= self.start {
+			self.locked
+		} else if now >= self.end {
+			0
+		} else {
+			Perquintill::from_rational(self.end - now, self.end - self.start).mul_ceil(self.locked)
+		}
+	}
+
+	/// Sum of the amounts still locked at `now`.
+	pub fn total_locked_at(schedules: &[Self], now: Moment) -> Balance {
+		schedules.iter().fold(0, |acc, s| acc.saturating_add(s.locked_at(now)))
+	}
+}
 
 // Copy from <https://github.dev/paritytech/substrate/blob/polkadot-v0.9.30/frame/assets/src/types.rs#L115>.
 // Due to its visibility.
@@ -321,24 +1434,95 @@ pub(crate) enum AssetStatus {
 	Destroying,
 }
 
+/// Kind of the account to migrate.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum MigrationKind {
+	/// An account with its own key, which signs the message itself.
+	Key,
+	/// A multisig account, whose signatories sign the message.
+	Multisig,
+	/// A pure proxy account, whose spawner signs the message.
+	PureProxy,
+}
+
 /// Build a Darwinia account migration message.
-pub fn signable_message(spec_name: &[u8], account_id_20: &AccountId20) -> Vec<u8> {
+///
+/// A [`MigrationKind::Key`] account can only be migrated with its own signature, so its message
+/// stays as it was. The other messages name the account and its kind, so an approval can not be
+/// replayed to migrate any other account.
+pub fn signable_message(
+	spec_name: &[u8],
+	kind: MigrationKind,
+	from: &AccountId32,
+	account_id_20: &AccountId20,
+) -> Vec<u8> {
+	// Ignore the EIP-55 here.
+	//
+	// Must call the `to_lowercase` on front end.
+	let to = account_id_20.0.hexify_prefixed();
+	let chain = &spec_name[..spec_name.len() - 1];
+	let (kind, signer) = match kind {
+		MigrationKind::Key =>
+			return [
+				// https://github.com/polkadot-js/common/issues/1710
+				b"<Bytes>I authorize the migration to ",
+				to.as_bytes(),
+				b", an unused address on ",
+				spec_name,
+				b". Sign this message to authorize using the Substrate key associated with the account on ",
+				chain,
+				b" that you wish to migrate.</Bytes>",
+			]
+			.concat(),
+		MigrationKind::Multisig => (&b"multisig"[..], &b"a signatory"[..]),
+		MigrationKind::PureProxy => (&b"pure proxy"[..], &b"the spawner"[..]),
+	};
+
 	[
-		// https://github.com/polkadot-js/common/issues/1710
-		b"<Bytes>I authorize the migration to ",
-		// Ignore the EIP-55 here.
-		//
-		// Must call the `to_lowercase` on front end.
-		account_id_20.0.hexify_prefixed().as_bytes(),
+		&b"<Bytes>I authorize the migration of the "[..],
+		kind,
+		b" account ",
+		<AccountId32 as AsRef<[u8; 32]>>::as_ref(from).hexify_prefixed().as_bytes(),
+		b" to ",
+		to.as_bytes(),
 		b", an unused address on ",
 		spec_name,
-		b". Sign this message to authorize using the Substrate key associated with the account on ",
-		&spec_name[..spec_name.len() - 1],
-		b" that you wish to migrate.</Bytes>",
+		b". Sign this message to authorize as ",
+		signer,
+		b" of the account on ",
+		chain,
+		b".</Bytes>",
 	]
 	.concat()
 }
 
+/// Derive a Darwinia1 multisig account from the sorted signatories and the threshold.
+///
+/// <https://github.com/paritytech/substrate/blob/polkadot-v0.9.30/frame/multisig/src/lib.rs#L628>
+pub fn multisig_of(signatories: &[AccountId32], threshold: u16) -> AccountId32 {
+	let entropy = (b"modlpy/utilisuba", signatories, threshold).using_encoded(blake2_256);
+
+	AccountId32::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+		.expect("infinite length input; no invalid inputs for type; qed")
+}
+
+/// Derive a Darwinia1 pure proxy account from its spawner and creation parameters.
+///
+/// <https://github.com/paritytech/substrate/blob/polkadot-v0.9.30/frame/proxy/src/lib.rs#L636>
+pub fn pure_proxy_of(
+	spawner: &AccountId32,
+	proxy_type: u8,
+	index: u16,
+	height: u32,
+	ext_index: u32,
+) -> AccountId32 {
+	let entropy = (b"modlpy/proxy____", spawner, height, ext_index, proxy_type, index)
+		.using_encoded(blake2_256);
+
+	AccountId32::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+		.expect("infinite length input; no invalid inputs for type; qed")
+}
+
 /// Verify the curve 25519 signatures.
 pub(crate) fn verify_curve_25519_signature(
 	public_key: &AccountId32,
@@ -352,3 +1536,14 @@ pub(crate) fn verify_curve_25519_signature(
 			&Ep::from(<AccountId32 as AsRef<[u8; 32]>>::as_ref(public_key).to_owned()),
 		)
 }
+
+/// Verify the ECDSA signature.
+///
+/// Recover the public key from the signature and check that its BLAKE2 hash matches `account_id`.
+pub(crate) fn verify_ecdsa_signature(
+	account_id: &AccountId32,
+	message: &[u8],
+	signature: &EcdsaSignature,
+) -> bool {
+	MultiSignature::Ecdsa(Ecs::from_raw(signature.to_owned())).verify(message, account_id)
+}
@@ -57,6 +57,8 @@ impl pallet_balances::Config for Runtime {
 	type AccountStore = System;
 	type Balance = Balance;
 	type ExistentialDeposit = ();
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxFreezes = frame_support::traits::VariantCountOf<RuntimeFreezeReason>;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -100,6 +102,7 @@ frame_support::parameter_types! {
 
 impl darwinia_deposit::Config for Runtime {
 	type DepositMigrator = ();
+	type MigrationGasPerDeposit = ();
 	type Ring = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type Treasury = ();
@@ -107,6 +110,8 @@ impl darwinia_deposit::Config for Runtime {
 }
 
 impl crate::Config for Runtime {
+	type LegacyBalanceConverter = ();
+	type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 }
@@ -0,0 +1,46 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the account migration pallet.
+
+// darwinia
+use crate::{AccountStatus, MigrationTotals};
+use dc_primitives::AccountId as AccountId20;
+// polkadot-sdk
+use sp_runtime::AccountId32;
+
+sp_api::decl_runtime_apis! {
+	/// Account migration introspection.
+	///
+	/// Version 2 added [`AccountStatus::Swept`].
+	#[api_version(2)]
+	pub trait AccountMigrationApi {
+		/// Migration status of the legacy account.
+		fn account_status(from: AccountId32) -> AccountStatus;
+
+		/// Whether the `from` could be migrated to `to`.
+		///
+		/// The `from` must be waiting to be migrated and the `to` must be unused.
+		fn is_destination_usable(from: AccountId32, to: AccountId20) -> bool;
+
+		/// Aggregates of the legacy data waiting to be migrated.
+		///
+		/// Partial while the legacy data is being counted, see [`crate::TotalsCursor`].
+		fn totals() -> MigrationTotals;
+	}
+}
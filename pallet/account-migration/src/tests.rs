@@ -17,15 +17,73 @@
 // along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
 
 // darwinia
-use crate::*;
+use crate::{mock::*, *};
 // polkadot-sdk
+use frame_support::{assert_ok, traits::UnfilteredDispatchable};
+use sp_core::{blake2_256, ecdsa::Pair as EcdsaPair, Pair};
 use sp_keyring::{ed25519::Keyring as Ek, sr25519::Keyring as Sk};
+use sp_runtime::transaction_validity::{
+	InvalidTransaction, TransactionSource, TransactionValidity,
+};
+
+fn preset_account(who: &AccountId32) {
+	<Accounts<Runtime>>::insert(
+		who,
+		AccountInfo {
+			providers: 1,
+			data: AccountData { free: 100, ..Default::default() },
+			..Default::default()
+		},
+	);
+}
+
+fn invalid(e: u8) -> TransactionValidity {
+	InvalidTransaction::Custom(e).into()
+}
+
+fn validate(call: &Call<Runtime>) -> TransactionValidity {
+	<AccountMigration as ValidateUnsigned>::validate_unsigned(TransactionSource::External, call)
+}
+
+// Same as applying an unsigned extrinsic.
+fn dispatch(call: Call<Runtime>) -> DispatchResult {
+	<AccountMigration as ValidateUnsigned>::pre_dispatch(&call).map_err(<&'static str>::from)?;
+
+	call.dispatch_bypass_filter(RuntimeOrigin::none()).map(|_| ()).map_err(|e| e.error)
+}
+
+fn multisig_call(
+	signatories: &[Sk],
+	threshold: u16,
+	to: AccountId20,
+	approvals: &[bool],
+) -> Call<Runtime> {
+	let account_ids = signatories.iter().map(|s| s.to_account_id()).collect::<Vec<_>>();
+	let message = signable_message(
+		b"Darwinia2",
+		MigrationKind::Multisig,
+		&multisig_of(&account_ids, threshold),
+		&to,
+	);
+	let signatures =
+		signatories.iter().zip(approvals).map(|(s, a)| a.then(|| s.sign(&message).0)).collect();
+
+	Call::migrate_multisig { signatories: account_ids, threshold, to, signatures }
+}
+
+fn sorted_keyring() -> Vec<Sk> {
+	let mut signatories = vec![Sk::Alice, Sk::Bob, Sk::Charlie];
+
+	signatories.sort_by_key(|s| s.to_account_id());
+
+	signatories
+}
 
 #[test]
 fn signable_message_should_work() {
 	["Darwinia2", "Crab2"].iter().for_each(|s| {
 		assert_eq!(
-			signable_message(s.as_bytes(), &Default::default()),
+			signable_message(s.as_bytes(), MigrationKind::Key, &[0; 32].into(), &Default::default()),
 			format!(
 				"<Bytes>I authorize the migration to {}, an unused address on {}. Sign this message to authorize using the Substrate key associated with the account on {} that you wish to migrate.</Bytes>",
 				"0x0000000000000000000000000000000000000000",
@@ -40,16 +98,263 @@ fn signable_message_should_work() {
 fn verify_curve_25519_signature_should_work() {
 	Sk::iter().enumerate().for_each(|(i, from)| {
 		let to = [i as _; 20];
-		let message = signable_message(b"Darwinia2", &to.into());
+		let message =
+			signable_message(b"Darwinia2", MigrationKind::Key, &from.to_account_id(), &to.into());
 		let signature = from.sign(&message);
 
 		assert!(verify_curve_25519_signature(&from.public().0.into(), &message, &signature.0));
 	});
 	Ek::iter().enumerate().for_each(|(i, from)| {
 		let to = [i as _; 20];
-		let message = signable_message(b"Darwinia2", &to.into());
+		let message =
+			signable_message(b"Darwinia2", MigrationKind::Key, &from.to_account_id(), &to.into());
 		let signature = from.sign(&message);
 
 		assert!(verify_curve_25519_signature(&from.public().0.into(), &message, &signature.0));
 	});
 }
+
+#[test]
+fn verify_ecdsa_signature_should_work() {
+	["//Alice", "//Bob", "//Charlie"].iter().enumerate().for_each(|(i, seed)| {
+		let from = EcdsaPair::from_string(seed, None).unwrap();
+		let from_account_id = AccountId32::from(blake2_256(from.public().as_ref()));
+		let to = [i as _; 20];
+		let message =
+			signable_message(b"Darwinia2", MigrationKind::Key, &from_account_id, &to.into());
+		let signature = from.sign(&message);
+
+		assert!(verify_ecdsa_signature(&from_account_id, &message, &signature.0));
+		// Recovered public key doesn't match.
+		assert!(!verify_ecdsa_signature(&[0; 32].into(), &message, &signature.0));
+		// Signed another message.
+		assert!(!verify_ecdsa_signature(
+			&from_account_id,
+			&signable_message(b"Crab2", MigrationKind::Key, &from_account_id, &to.into()),
+			&signature.0
+		));
+	});
+}
+
+#[test]
+fn multisig_of_should_work() {
+	assert_eq!(
+		multisig_of(
+			&[Sk::Bob.to_account_id(), Sk::Charlie.to_account_id(), Sk::Alice.to_account_id()],
+			2
+		),
+		array_bytes::dehexify_array_then_into::<_, AccountId32, 32>(
+			"0x49daa32c7287890f38b7e1a8cd2961723d36d20baa0bf3b82e0c4bdda93b1c0a"
+		)
+		.unwrap()
+	);
+}
+
+#[test]
+fn pure_proxy_of_should_work() {
+	assert_eq!(
+		pure_proxy_of(&Sk::Alice.to_account_id(), 0, 0, 0, 0),
+		array_bytes::dehexify_array_then_into::<_, AccountId32, 32>(
+			"0xaccf1bce3b5daf6735e65a8d3bbf1266b5f6893771ab4ab790c7ffb64aab92df"
+		)
+		.unwrap()
+	);
+}
+
+#[test]
+fn signable_message_should_bind_the_account() {
+	let from = [1; 32].into();
+
+	assert_eq!(
+		signable_message(b"Darwinia2", MigrationKind::Multisig, &from, &Default::default()),
+		format!(
+			"<Bytes>I authorize the migration of the multisig account {} to {}, an unused address on Darwinia2. Sign this message to authorize as a signatory of the account on Darwinia.</Bytes>",
+			"0x0101010101010101010101010101010101010101010101010101010101010101",
+			"0x0000000000000000000000000000000000000000",
+		).as_bytes()
+	);
+	assert_eq!(
+		signable_message(b"Darwinia2", MigrationKind::PureProxy, &from, &Default::default()),
+		format!(
+			"<Bytes>I authorize the migration of the pure proxy account {} to {}, an unused address on Darwinia2. Sign this message to authorize as the spawner of the account on Darwinia.</Bytes>",
+			"0x0101010101010101010101010101010101010101010101010101010101010101",
+			"0x0000000000000000000000000000000000000000",
+		).as_bytes()
+	);
+	assert_ne!(
+		signable_message(b"Darwinia2", MigrationKind::Multisig, &from, &Default::default()),
+		signable_message(
+			b"Darwinia2",
+			MigrationKind::Multisig,
+			&[2; 32].into(),
+			&Default::default()
+		)
+	);
+}
+
+#[test]
+fn migrate_multisig_should_work() {
+	new_test_ext().execute_with(|| {
+		let signatories = sorted_keyring();
+		let to = AccountId20::from([1; 20]);
+		let call = multisig_call(&signatories, 2, to, &[true, false, true]);
+		let Call::migrate_multisig { signatories: account_ids, .. } = &call else {
+			unreachable!();
+		};
+		let from = multisig_of(account_ids, 2);
+
+		preset_account(&from);
+
+		assert!(validate(&call).is_ok());
+		assert_ok!(dispatch(call));
+		assert!(<Accounts<Runtime>>::get(&from).is_none());
+		assert_eq!(System::account(to).data.free, 100);
+	});
+}
+
+#[test]
+fn migrate_multisig_should_check_the_threshold() {
+	new_test_ext().execute_with(|| {
+		let signatories = sorted_keyring();
+		let account_ids = signatories.iter().map(|s| s.to_account_id()).collect::<Vec<_>>();
+		let to = AccountId20::from([1; 20]);
+
+		(0..=4).for_each(|t| preset_account(&multisig_of(&account_ids, t)));
+
+		// Not enough approvals.
+		let call = multisig_call(&signatories, 2, to, &[false, true, false]);
+
+		assert_eq!(validate(&call), invalid(E_INVALID_SIGNATURE));
+		assert!(dispatch(call).is_err());
+
+		// The threshold is out of range.
+		for t in [0, 4] {
+			let call = multisig_call(&signatories, t, to, &[true, true, true]);
+
+			assert_eq!(validate(&call), invalid(E_INVALID_MULTISIG_PARAMS));
+			assert!(dispatch(call).is_err());
+		}
+
+		// An approval for another threshold is bound to another account.
+		let Call::migrate_multisig { signatures, .. } =
+			multisig_call(&signatories, 3, to, &[true, true, true])
+		else {
+			unreachable!();
+		};
+		let call = Call::migrate_multisig {
+			signatories: account_ids.clone(),
+			threshold: 2,
+			to,
+			signatures,
+		};
+
+		assert_eq!(validate(&call), invalid(E_INVALID_SIGNATURE));
+		assert!(dispatch(call).is_err());
+		assert!(!<frame_system::Account<Runtime>>::contains_key(to));
+	});
+}
+
+#[test]
+fn migrate_multisig_should_check_the_signatories() {
+	new_test_ext().execute_with(|| {
+		let signatories = sorted_keyring();
+		let to = AccountId20::from([1; 20]);
+		let unsorted = [signatories[1], signatories[0], signatories[2]];
+		let duplicated = [signatories[0], signatories[0], signatories[1]];
+
+		for signatories in [&unsorted[..], &duplicated, &signatories[..1]] {
+			let account_ids = signatories.iter().map(|s| s.to_account_id()).collect::<Vec<_>>();
+
+			preset_account(&multisig_of(&account_ids, 1));
+
+			let call = multisig_call(signatories, 1, to, &[true; 3][..signatories.len()]);
+
+			assert_eq!(validate(&call), invalid(E_INVALID_MULTISIG_PARAMS));
+			assert!(dispatch(call).is_err());
+		}
+
+		// The signatures must match the signatories one by one.
+		let account_ids = signatories.iter().map(|s| s.to_account_id()).collect::<Vec<_>>();
+
+		preset_account(&multisig_of(&account_ids, 1));
+
+		let Call::migrate_multisig { mut signatures, .. } =
+			multisig_call(&signatories, 1, to, &[true, false, false])
+		else {
+			unreachable!();
+		};
+
+		signatures.pop();
+
+		let call =
+			Call::migrate_multisig { signatories: account_ids, threshold: 1, to, signatures };
+
+		assert_eq!(validate(&call), invalid(E_INVALID_MULTISIG_PARAMS));
+		assert!(dispatch(call).is_err());
+		assert!(!<frame_system::Account<Runtime>>::contains_key(to));
+	});
+}
+
+#[test]
+fn migrate_should_reject_the_replayed_approval() {
+	new_test_ext().execute_with(|| {
+		let signatories = sorted_keyring();
+		let account_ids = signatories.iter().map(|s| s.to_account_id()).collect::<Vec<_>>();
+		let to = AccountId20::from([1; 20]);
+		let multisig = multisig_of(&account_ids, 1);
+		let pure_proxy = pure_proxy_of(&account_ids[0], 0, 0, 0, 0);
+
+		preset_account(&multisig);
+		preset_account(&pure_proxy);
+
+		// The signatory approved the migration of their own account.
+		let signature = signatories[0]
+			.sign(&signable_message(b"Darwinia2", MigrationKind::Key, &account_ids[0], &to))
+			.0;
+		let call = Call::migrate_multisig {
+			signatories: account_ids.clone(),
+			threshold: 1,
+			to,
+			signatures: vec![Some(signature), None, None],
+		};
+
+		assert_eq!(validate(&call), invalid(E_INVALID_SIGNATURE));
+		assert!(dispatch(call).is_err());
+
+		// The spawner approved the migration of a multisig account.
+		let signature = signatories[0]
+			.sign(&signable_message(b"Darwinia2", MigrationKind::Multisig, &multisig, &to))
+			.0;
+		let call = Call::migrate_pure_proxy {
+			spawner: account_ids[0].clone(),
+			proxy_type: 0,
+			index: 0,
+			height: 0,
+			ext_index: 0,
+			to,
+			signature,
+		};
+
+		assert_eq!(validate(&call), invalid(E_INVALID_SIGNATURE));
+		assert!(dispatch(call).is_err());
+		assert!(!<frame_system::Account<Runtime>>::contains_key(to));
+
+		// The spawner approved the migration of the pure proxy account.
+		let signature = signatories[0]
+			.sign(&signable_message(b"Darwinia2", MigrationKind::PureProxy, &pure_proxy, &to))
+			.0;
+		let call = Call::migrate_pure_proxy {
+			spawner: account_ids[0].clone(),
+			proxy_type: 0,
+			index: 0,
+			height: 0,
+			ext_index: 0,
+			to,
+			signature,
+		};
+
+		assert!(validate(&call).is_ok());
+		assert_ok!(dispatch(call));
+		assert!(<Accounts<Runtime>>::get(&pure_proxy).is_none());
+	});
+}
@@ -0,0 +1,146 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// darwinia
+use crate::*;
+// polkadot-sdk
+use frame_benchmarking::v2::*;
+use frame_support::traits::EnsureOrigin;
+use xcm::latest::prelude::*;
+
+fn register_asset<T>() -> Result<T::ForeignAssetType, BenchmarkError>
+where
+	T: Config,
+{
+	let asset_type = T::ForeignAssetType::default();
+
+	<pallet_asset_manager::Pallet<T>>::register_foreign_asset(
+		T::ForeignAssetModifierOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?,
+		asset_type.clone(),
+		Default::default(),
+		1_u32.into(),
+		true,
+	)
+	.map_err(|_| BenchmarkError::Stop("failed to register the asset"))?;
+
+	Ok(asset_type)
+}
+
+fn trap<T>(asset_type: T::ForeignAssetType) -> u64
+where
+	T: Config,
+{
+	let id = <NextTrappedDepositId<T>>::get();
+
+	<Pallet<T>>::trap_deposit(
+		asset_type,
+		account("beneficiary", 0, 0),
+		1_000,
+		Some(Location::new(1, [Parachain(2_000)]).into()),
+		(TrapReason::AssetLimit, 0),
+	);
+
+	id
+}
+
+#[v2::benchmarks]
+mod benchmarks {
+	// darwinia
+	use super::*;
+
+	#[benchmark]
+	fn set_foreign_asset_limit() -> Result<(), BenchmarkError> {
+		let asset_type = register_asset::<T>()?;
+		let origin = T::ForeignAssetModifierOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		// Worst-case scenario:
+		//
+		// Set the limit of an existing asset.
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, asset_type, u128::MAX);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn release_trapped_deposit() -> Result<(), BenchmarkError> {
+		let asset_type = register_asset::<T>()?;
+		let id = trap::<T>(asset_type.clone());
+		let origin = T::ForeignAssetModifierOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		<ForeignAssetLimit<T>>::insert(&asset_type, u128::MAX);
+		[None, Some(2_000)].into_iter().for_each(|k| {
+			<InflowRateLimits<T>>::insert(
+				&asset_type,
+				k,
+				InflowRateLimit { units: u128::MAX, period: 1_u32.into() },
+			)
+		});
+
+		// Worst-case scenario:
+		//
+		// - The asset has a limit.
+		// - The deposit is subject to both the inflow rate limit of any origin and the one of its
+		//   origin parachain.
+		// - The beneficiary account is created by the release.
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, id);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn refund_trapped_deposit() -> Result<(), BenchmarkError> {
+		let asset_type = register_asset::<T>()?;
+		let id = trap::<T>(asset_type);
+		let origin = T::ForeignAssetModifierOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		// Worst-case scenario:
+		//
+		// The sovereign account of the origin is created by the refund.
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, id);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_inflow_rate_limit() -> Result<(), BenchmarkError> {
+		let asset_type = register_asset::<T>()?;
+		let origin = T::ForeignAssetModifierOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		// Worst-case scenario:
+		//
+		// Remove a limit, which also clears its window.
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, asset_type, Some(2_000), None);
+
+		Ok(())
+	}
+
+	frame_benchmarking::impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Runtime
+	);
+}
@@ -18,17 +18,91 @@
 
 //! # Darwinia asset limit pallet
 //! Please note that this pallet is only for foreign assets.
-//! This pallet only stores the asset limit and does not actually prevent asset deposits.
-//! Currently, we use the app to limit excessive amounts of deposits.
+//!
+//! The limits are enforced on the XCM deposits through [`LimitedTransactor`]:
+//! - [`ForeignAssetLimit`] caps the total issuance of an asset.
+//! - [`InflowRateLimits`] caps the inflow of an asset within a rolling window, either from any
+//!   origin or from a specific origin parachain.
+//!
+//! A deposit which exceeds any of them is not minted.
+//! Instead, it is trapped in this pallet and can be released or refunded by the governance.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod runtime_api;
+pub use runtime_api::*;
+
+mod transactor;
+pub use transactor::*;
+
+mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
+	// darwinia
+	use crate::WeightInfo;
 	// polkadot-sdk
-	use frame_support::pallet_prelude::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::fungibles::{Inspect, Mutate},
+	};
 	use frame_system::pallet_prelude::*;
+	use pallet_asset_manager::AssetTypeId;
+	use sp_runtime::traits::{Saturating, UniqueSaturatedInto, Zero};
+	use xcm::{latest::Location, VersionedLocation};
+	use xcm_executor::traits::ConvertLocation;
+
+	/// Why a deposit was trapped.
+	#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub enum TrapReason {
+		/// The deposit exceeded [`ForeignAssetLimit`].
+		AssetLimit,
+		/// The deposit exceeded [`InflowRateLimits`].
+		InflowRateLimit,
+	}
+
+	/// Deposit trapped for exceeding a limit.
+	#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct TrappedDeposit<AccountId, AssetType> {
+		/// Foreign asset type.
+		pub asset_type: AssetType,
+		/// Account which should receive the deposit.
+		pub beneficiary: AccountId,
+		/// Amount of the deposit.
+		pub amount: u128,
+		/// Origin of the XCM message, used for the refund.
+		pub origin: Option<VersionedLocation>,
+		/// Why the deposit was trapped.
+		pub reason: TrapReason,
+	}
+
+	/// Allow at most `units` to flow in within `period` blocks.
+	#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct InflowRateLimit<BlockNumber> {
+		/// Maximum units within the window.
+		pub units: u128,
+		/// Length of the window in blocks.
+		pub period: BlockNumber,
+	}
+
+	/// Inflow accounting of a window.
+	#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct InflowWindow<BlockNumber> {
+		/// Units which flowed in, as of `updated_at`.
+		pub used: u128,
+		/// Block number of the last update.
+		pub updated_at: BlockNumber,
+	}
 
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
@@ -38,6 +112,19 @@ pub mod pallet {
 	pub trait Config: frame_system::Config + pallet_asset_manager::Config {
 		/// Override the [`frame_system::Config::RuntimeEvent`].
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Foreign assets implementation.
+		type Fungibles: Mutate<
+			Self::AccountId,
+			AssetId = <Self as pallet_asset_manager::Config>::AssetId,
+			Balance = u128,
+		>;
+
+		/// Convert the beneficiary location into an account.
+		type LocationToAccountId: ConvertLocation<Self::AccountId>;
+
+		/// Weight information for extrinsic in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[allow(missing_docs)]
@@ -46,6 +133,35 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// New limit is set or old limit is updated.
 		AssetLimitChanged { asset_type: T::ForeignAssetType, units_limit: u128 },
+		/// A deposit exceeded the asset limit and was trapped.
+		DepositTrapped {
+			id: u64,
+			asset_type: T::ForeignAssetType,
+			beneficiary: T::AccountId,
+			amount: u128,
+			headroom: u128,
+			reason: TrapReason,
+		},
+		/// A trapped deposit was released to its beneficiary.
+		TrappedDepositReleased {
+			id: u64,
+			asset_type: T::ForeignAssetType,
+			beneficiary: T::AccountId,
+			amount: u128,
+		},
+		/// A trapped deposit was refunded to the sovereign account of its origin.
+		TrappedDepositRefunded {
+			id: u64,
+			asset_type: T::ForeignAssetType,
+			who: T::AccountId,
+			amount: u128,
+		},
+		/// New inflow rate limit is set or old limit is updated or removed.
+		InflowRateLimitChanged {
+			asset_type: T::ForeignAssetType,
+			origin_para: Option<u32>,
+			limit: Option<InflowRateLimit<BlockNumberFor<T>>>,
+		},
 	}
 
 	#[allow(missing_docs)]
@@ -53,17 +169,61 @@ pub mod pallet {
 	pub enum Error<T> {
 		/// Asset does not exist.
 		AssetDoesNotExist,
+		/// Trapped deposit does not exist.
+		TrappedDepositNotFound,
+		/// Asset limit would be exceeded.
+		ForeignAssetLimitExceeded,
+		/// Inflow rate limit period must not be zero.
+		InvalidInflowRateLimit,
+		/// Origin of the trapped deposit can not be converted into an account.
+		RefundDestinationUnknown,
+		/// Inflow rate limit would be exceeded.
+		InflowRateLimitExceeded,
 	}
 
 	/// Stores the asset limit for foreign assets.
 	#[pallet::storage]
 	pub type ForeignAssetLimit<T: Config> = StorageMap<_, Twox128, T::ForeignAssetType, u128>;
 
+	/// Deposits trapped for exceeding the asset limit.
+	#[pallet::storage]
+	pub type TrappedDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, u64, TrappedDeposit<T::AccountId, T::ForeignAssetType>>;
+
+	/// Inflow rate limits of the foreign assets.
+	///
+	/// The second key is the origin parachain id, `None` limits the inflow from any origin.
+	#[pallet::storage]
+	pub type InflowRateLimits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::ForeignAssetType,
+		Twox64Concat,
+		Option<u32>,
+		InflowRateLimit<BlockNumberFor<T>>,
+	>;
+
+	/// Inflow accounting of the rate limited foreign assets.
+	#[pallet::storage]
+	pub type InflowWindows<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::ForeignAssetType,
+		Twox64Concat,
+		Option<u32>,
+		InflowWindow<BlockNumberFor<T>>,
+		ValueQuery,
+	>;
+
+	/// Id of the next trapped deposit.
+	#[pallet::storage]
+	pub type NextTrappedDepositId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Change the asset limit for a given foreign asset type.
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		#[pallet::weight(<T as Config>::WeightInfo::set_foreign_asset_limit())]
 		pub fn set_foreign_asset_limit(
 			origin: OriginFor<T>,
 			asset_type: T::ForeignAssetType,
@@ -81,6 +241,222 @@ pub mod pallet {
 			Self::deposit_event(Event::AssetLimitChanged { asset_type, units_limit });
 			Ok(())
 		}
+
+		/// Release a trapped deposit to its beneficiary.
+		///
+		/// The deposit must fit into the current headroom and the inflow headroom of the asset.
+		/// The released amount is accounted into the inflow windows, as if it was just deposited.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::release_trapped_deposit())]
+		pub fn release_trapped_deposit(origin: OriginFor<T>, id: u64) -> DispatchResult {
+			T::ForeignAssetModifierOrigin::ensure_origin(origin)?;
+
+			let TrappedDeposit { asset_type, beneficiary, amount, origin, .. } =
+				<TrappedDeposits<T>>::get(id).ok_or(<Error<T>>::TrappedDepositNotFound)?;
+			let asset_id =
+				<AssetTypeId<T>>::get(&asset_type).ok_or(<Error<T>>::AssetDoesNotExist)?;
+			let origin_para = origin
+				.and_then(|o| Location::try_from(o).ok())
+				.and_then(|o| crate::origin_para(&o));
+
+			match Self::check_deposit(&asset_type, origin_para, amount) {
+				Some((TrapReason::AssetLimit, _)) => Err(<Error<T>>::ForeignAssetLimitExceeded)?,
+				Some((TrapReason::InflowRateLimit, _)) => Err(<Error<T>>::InflowRateLimitExceeded)?,
+				None => (),
+			}
+
+			T::Fungibles::mint_into(asset_id, &beneficiary, amount)?;
+			Self::record_inflow(&asset_type, origin_para, amount);
+			<TrappedDeposits<T>>::remove(id);
+
+			Self::deposit_event(Event::TrappedDepositReleased {
+				id,
+				asset_type,
+				beneficiary,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Refund a trapped deposit to the sovereign account of its origin.
+		///
+		/// The origin chain can then send it back through XCM.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::refund_trapped_deposit())]
+		pub fn refund_trapped_deposit(origin: OriginFor<T>, id: u64) -> DispatchResult {
+			T::ForeignAssetModifierOrigin::ensure_origin(origin)?;
+
+			let TrappedDeposit { asset_type, amount, origin, .. } =
+				<TrappedDeposits<T>>::get(id).ok_or(<Error<T>>::TrappedDepositNotFound)?;
+			let asset_id =
+				<AssetTypeId<T>>::get(&asset_type).ok_or(<Error<T>>::AssetDoesNotExist)?;
+			let who = origin
+				.and_then(|o| Location::try_from(o).ok())
+				.and_then(|o| T::LocationToAccountId::convert_location(&o))
+				.ok_or(<Error<T>>::RefundDestinationUnknown)?;
+
+			T::Fungibles::mint_into(asset_id, &who, amount)?;
+			<TrappedDeposits<T>>::remove(id);
+
+			Self::deposit_event(Event::TrappedDepositRefunded { id, asset_type, who, amount });
+
+			Ok(())
+		}
+
+		/// Set or remove the inflow rate limit for a given foreign asset type.
+		///
+		/// `origin_para` limits the inflow from a specific parachain, `None` limits the inflow
+		/// from any origin.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_inflow_rate_limit())]
+		pub fn set_inflow_rate_limit(
+			origin: OriginFor<T>,
+			asset_type: T::ForeignAssetType,
+			origin_para: Option<u32>,
+			limit: Option<InflowRateLimit<BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			T::ForeignAssetModifierOrigin::ensure_origin(origin)?;
+
+			ensure!(<AssetTypeId<T>>::get(&asset_type).is_some(), <Error<T>>::AssetDoesNotExist);
+
+			match &limit {
+				Some(l) => {
+					ensure!(!l.period.is_zero(), <Error<T>>::InvalidInflowRateLimit);
+
+					<InflowRateLimits<T>>::insert(&asset_type, origin_para, l);
+				},
+				None => {
+					<InflowRateLimits<T>>::remove(&asset_type, origin_para);
+					<InflowWindows<T>>::remove(&asset_type, origin_para);
+				},
+			}
+
+			Self::deposit_event(Event::InflowRateLimitChanged { asset_type, origin_para, limit });
+
+			Ok(())
+		}
+	}
+	impl<T> Pallet<T>
+	where
+		T: Config,
+	{
+		/// Units of the asset which can still be deposited before reaching its limit.
+		///
+		/// Return `None` if the asset has no limit.
+		pub fn headroom(asset_type: &T::ForeignAssetType) -> Option<u128> {
+			let limit = <ForeignAssetLimit<T>>::get(asset_type)?;
+			let issuance =
+				<AssetTypeId<T>>::get(asset_type).map_or(0, T::Fungibles::total_issuance);
+
+			Some(limit.saturating_sub(issuance))
+		}
+
+		/// Units of the asset which can still flow in from the origin parachain within the current
+		/// window.
+		///
+		/// The window is rolling, the used units decay linearly over the period.
+		/// Return `None` if there is no such inflow rate limit.
+		pub fn inflow_headroom(
+			asset_type: &T::ForeignAssetType,
+			origin_para: Option<u32>,
+		) -> Option<u128> {
+			let limit = <InflowRateLimits<T>>::get(asset_type, origin_para)?;
+			let window = <InflowWindows<T>>::get(asset_type, origin_para);
+
+			Some(limit.units.saturating_sub(Self::decayed_inflow(&limit, &window)))
+		}
+
+		fn decayed_inflow(
+			limit: &InflowRateLimit<BlockNumberFor<T>>,
+			window: &InflowWindow<BlockNumberFor<T>>,
+		) -> u128 {
+			let elapsed = <frame_system::Pallet<T>>::block_number()
+				.saturating_sub(window.updated_at)
+				.min(limit.period);
+			let elapsed = UniqueSaturatedInto::<u128>::unique_saturated_into(elapsed);
+			let period = UniqueSaturatedInto::<u128>::unique_saturated_into(limit.period).max(1);
+
+			window.used.saturating_sub(limit.units.saturating_mul(elapsed) / period)
+		}
+
+		/// Inflow rate limits which apply to a deposit from the origin parachain.
+		fn inflow_keys(origin_para: Option<u32>) -> impl Iterator<Item = Option<u32>> {
+			[None].into_iter().chain(origin_para.map(Some))
+		}
+
+		/// Return the reason and the headroom if the deposit exceeds any limit.
+		pub(crate) fn check_deposit(
+			asset_type: &T::ForeignAssetType,
+			origin_para: Option<u32>,
+			amount: u128,
+		) -> Option<(TrapReason, u128)> {
+			if let Some(headroom) = Self::headroom(asset_type) {
+				if amount > headroom {
+					return Some((TrapReason::AssetLimit, headroom));
+				}
+			}
+
+			Self::inflow_keys(origin_para)
+				.filter_map(|k| Self::inflow_headroom(asset_type, k))
+				.find(|h| amount > *h)
+				.map(|h| (TrapReason::InflowRateLimit, h))
+		}
+
+		/// Account the deposit into the inflow windows.
+		pub(crate) fn record_inflow(
+			asset_type: &T::ForeignAssetType,
+			origin_para: Option<u32>,
+			amount: u128,
+		) {
+			let now = <frame_system::Pallet<T>>::block_number();
+
+			for k in Self::inflow_keys(origin_para) {
+				let Some(limit) = <InflowRateLimits<T>>::get(asset_type, k) else { continue };
+
+				<InflowWindows<T>>::mutate(asset_type, k, |w| {
+					w.used = Self::decayed_inflow(&limit, w).saturating_add(amount);
+					w.updated_at = now;
+				});
+			}
+		}
+
+		/// Trap the deposit for exceeding a limit.
+		pub(crate) fn trap_deposit(
+			asset_type: T::ForeignAssetType,
+			beneficiary: T::AccountId,
+			amount: u128,
+			origin: Option<VersionedLocation>,
+			(reason, headroom): (TrapReason, u128),
+		) {
+			let id = <NextTrappedDepositId<T>>::mutate(|id| {
+				let i = *id;
+
+				*id = id.wrapping_add(1);
+
+				i
+			});
+
+			<TrappedDeposits<T>>::insert(
+				id,
+				TrappedDeposit {
+					asset_type: asset_type.clone(),
+					beneficiary: beneficiary.clone(),
+					amount,
+					origin,
+					reason,
+				},
+			);
+
+			Self::deposit_event(Event::DepositTrapped {
+				id,
+				asset_type,
+				beneficiary,
+				amount,
+				headroom,
+				reason,
+			});
+		}
 	}
 }
 pub use pallet::*;
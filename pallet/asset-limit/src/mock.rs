@@ -0,0 +1,201 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// crates.io
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+// polkadot-sdk
+use frame_support::{derive_impl, weights::Weight};
+use sp_io::TestExternalities;
+use sp_runtime::{BuildStorage, DispatchResult, RuntimeDebug};
+use xcm::latest::prelude::*;
+use xcm_executor::traits::{ConvertLocation, Error as MatchError, MatchesFungibles};
+
+pub type AccountId = u64;
+pub type AssetId = u64;
+pub type Balance = u128;
+
+/// Id of the registered foreign asset.
+pub const ASSET: AssetId = 1;
+/// Sovereign accounts of the sibling parachains start from here.
+pub const SIBLING_BASE: AccountId = 1_000_000;
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type Block = frame_system::mocking::MockBlock<Self>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig as pallet_balances::DefaultConfig)]
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+	type Balance = Balance;
+	type ExistentialDeposit = frame_support::traits::ConstU128<1>;
+}
+
+impl pallet_assets::Config for Runtime {
+	type ApprovalDeposit = ();
+	type AssetAccountDeposit = ();
+	type AssetDeposit = ();
+	type AssetId = AssetId;
+	type AssetIdParameter = AssetId;
+	type Balance = Balance;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+	type CallbackHandle = ();
+	type CreateOrigin =
+		frame_support::traits::AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+	type Currency = Balances;
+	type Extra = ();
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type Freezer = ();
+	type MetadataDepositBase = ();
+	type MetadataDepositPerByte = ();
+	type RemoveItemsLimit = ();
+	type RuntimeEvent = RuntimeEvent;
+	type StringLimit = frame_support::traits::ConstU32<4>;
+	type WeightInfo = ();
+}
+
+#[derive(
+	Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Encode, Decode, RuntimeDebug, TypeInfo,
+)]
+pub struct AssetType(pub AssetId);
+impl From<AssetType> for AssetId {
+	fn from(asset_type: AssetType) -> Self {
+		asset_type.0
+	}
+}
+
+pub struct AssetRegistrar;
+impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
+	fn create_foreign_asset(
+		asset: AssetId,
+		min_balance: Balance,
+		_: (),
+		is_sufficient: bool,
+	) -> DispatchResult {
+		Assets::force_create(
+			RuntimeOrigin::root(),
+			asset,
+			AssetManager::account_id(),
+			is_sufficient,
+			min_balance,
+		)
+	}
+
+	fn destroy_foreign_asset(asset: AssetId) -> DispatchResult {
+		Assets::start_destroy(RuntimeOrigin::root(), asset)
+	}
+
+	fn destroy_asset_dispatch_info_weight(_: AssetId) -> Weight {
+		Weight::zero()
+	}
+}
+impl pallet_asset_manager::Config for Runtime {
+	type AssetId = AssetId;
+	type AssetRegistrar = AssetRegistrar;
+	type AssetRegistrarMetadata = ();
+	type Balance = Balance;
+	type ForeignAssetModifierOrigin = frame_system::EnsureRoot<AccountId>;
+	type ForeignAssetType = AssetType;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+}
+
+/// Match `(0, [GeneralIndex(asset_id)])`.
+pub struct AssetMatcher;
+impl MatchesFungibles<AssetId, Balance> for AssetMatcher {
+	fn matches_fungibles(asset: &Asset) -> Result<(AssetId, Balance), MatchError> {
+		match (asset.id.0.unpack(), &asset.fun) {
+			((0, [GeneralIndex(id)]), Fungible(amount)) => Ok((*id as AssetId, *amount)),
+			_ => Err(MatchError::AssetNotHandled),
+		}
+	}
+}
+
+/// Convert `(0, [AccountIndex64])` into the local account and `(1, [Parachain])` into the
+/// sovereign account of the sibling parachain.
+pub struct LocationToAccountId;
+impl ConvertLocation<AccountId> for LocationToAccountId {
+	fn convert_location(location: &Location) -> Option<AccountId> {
+		match location.unpack() {
+			(0, [AccountIndex64 { index, .. }]) => Some(*index),
+			(1, [Parachain(id)]) => Some(SIBLING_BASE + *id as AccountId),
+			_ => None,
+		}
+	}
+}
+
+pub type ForeignFungiblesTransactor = xcm_builder::FungiblesAdapter<
+	Assets,
+	AssetMatcher,
+	LocationToAccountId,
+	AccountId,
+	xcm_builder::NoChecking,
+	(),
+>;
+pub type Transactor = crate::LimitedTransactor<Runtime, ForeignFungiblesTransactor, AssetMatcher>;
+
+impl crate::Config for Runtime {
+	type Fungibles = Assets;
+	type LocationToAccountId = LocationToAccountId;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+}
+
+frame_support::construct_runtime! {
+	pub enum Runtime {
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		AssetManager: pallet_asset_manager,
+		AssetLimit: crate,
+	}
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let storage = <frame_system::GenesisConfig<Runtime>>::default().build_storage().unwrap();
+	let mut ext = TestExternalities::new(storage);
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+
+		AssetManager::register_foreign_asset(RuntimeOrigin::root(), AssetType(ASSET), (), 1, true)
+			.unwrap();
+	});
+
+	ext
+}
+
+pub fn events() -> Vec<crate::Event<Runtime>> {
+	System::read_events_for_pallet()
+}
+
+/// Deposit the asset to the local account through XCM, sent by the sibling parachain if any.
+pub fn deposit(who: AccountId, amount: Balance, origin_para: Option<u32>) -> XcmResult {
+	let what = (Location::new(0, [GeneralIndex(ASSET.into())]), amount).into();
+	let who = Location::new(0, [AccountIndex64 { network: None, index: who }]);
+	let context = XcmContext {
+		origin: origin_para.map(|id| Location::new(1, [Parachain(id)])),
+		message_id: [0; 32],
+		topic: None,
+	};
+
+	<Transactor as xcm_executor::traits::TransactAsset>::deposit_asset(&what, &who, Some(&context))
+}
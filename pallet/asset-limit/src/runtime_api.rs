@@ -0,0 +1,41 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the asset limit pallet.
+
+// crates.io
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// Foreign asset limit introspection.
+	pub trait AssetLimitApi<AssetType>
+	where
+		AssetType: Codec,
+	{
+		/// Units of the asset which can still be deposited before reaching its limit.
+		///
+		/// Return `None` if the asset has no limit.
+		fn headroom(asset_type: AssetType) -> Option<u128>;
+
+		/// Units of the asset which can still flow in from the origin parachain within the current
+		/// window.
+		///
+		/// Return `None` if there is no such inflow rate limit.
+		fn inflow_headroom(asset_type: AssetType, origin_para: Option<u32>) -> Option<u128>;
+	}
+}
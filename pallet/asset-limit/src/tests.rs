@@ -0,0 +1,307 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// darwinia
+use crate::{mock::*, *};
+// polkadot-sdk
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+fn inflow_limit(origin_para: Option<u32>, units: u128, period: u64) {
+	assert_ok!(AssetLimit::set_inflow_rate_limit(
+		RuntimeOrigin::root(),
+		AssetType(ASSET),
+		origin_para,
+		Some(InflowRateLimit { units, period })
+	));
+}
+
+fn limit(units_limit: u128) {
+	assert_ok!(AssetLimit::set_foreign_asset_limit(
+		RuntimeOrigin::root(),
+		AssetType(ASSET),
+		units_limit
+	));
+}
+
+#[test]
+fn set_foreign_asset_limit_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetLimit::set_foreign_asset_limit(RuntimeOrigin::signed(1), AssetType(ASSET), 100),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetLimit::set_foreign_asset_limit(RuntimeOrigin::root(), AssetType(9), 100),
+			<Error<Runtime>>::AssetDoesNotExist
+		);
+
+		limit(100);
+
+		assert_eq!(AssetLimit::headroom(&AssetType(ASSET)), Some(100));
+		assert_eq!(
+			events(),
+			vec![Event::AssetLimitChanged { asset_type: AssetType(ASSET), units_limit: 100 }]
+		);
+	});
+}
+
+#[test]
+fn deposit_under_limit_should_work() {
+	new_test_ext().execute_with(|| {
+		// No limit.
+		assert_ok!(deposit(1, 1_000, None));
+		assert_eq!(Assets::balance(ASSET, 1), 1_000);
+
+		limit(1_100);
+
+		assert_ok!(deposit(1, 60, None));
+		assert_eq!(Assets::balance(ASSET, 1), 1_060);
+		assert_eq!(AssetLimit::headroom(&AssetType(ASSET)), Some(40));
+		assert!(<TrappedDeposits<Runtime>>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn deposit_at_limit_should_work() {
+	new_test_ext().execute_with(|| {
+		limit(100);
+
+		assert_ok!(deposit(1, 100, None));
+		assert_eq!(Assets::balance(ASSET, 1), 100);
+		assert_eq!(AssetLimit::headroom(&AssetType(ASSET)), Some(0));
+		assert!(<TrappedDeposits<Runtime>>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn deposit_over_limit_should_be_trapped() {
+	new_test_ext().execute_with(|| {
+		limit(100);
+		System::reset_events();
+
+		assert_ok!(deposit(1, 60, None));
+		// The trapped deposit is not minted, but the XCM execution still succeeds.
+		assert_ok!(deposit(2, 50, Some(2_000)));
+		assert_eq!(Assets::balance(ASSET, 2), 0);
+		assert_eq!(AssetLimit::headroom(&AssetType(ASSET)), Some(40));
+		assert_eq!(
+			<TrappedDeposits<Runtime>>::get(0),
+			Some(TrappedDeposit {
+				asset_type: AssetType(ASSET),
+				beneficiary: 2,
+				amount: 50,
+				origin: Some(
+					xcm::latest::Location::new(1, [xcm::latest::Junction::Parachain(2_000)]).into()
+				),
+				reason: TrapReason::AssetLimit,
+			})
+		);
+		assert_eq!(
+			events(),
+			vec![Event::DepositTrapped {
+				id: 0,
+				asset_type: AssetType(ASSET),
+				beneficiary: 2,
+				amount: 50,
+				headroom: 40,
+				reason: TrapReason::AssetLimit,
+			}]
+		);
+		assert_eq!(<NextTrappedDepositId<Runtime>>::get(), 1);
+	});
+}
+
+#[test]
+fn release_trapped_deposit_should_work() {
+	new_test_ext().execute_with(|| {
+		limit(100);
+		assert_ok!(deposit(1, 60, None));
+		assert_ok!(deposit(2, 50, None));
+
+		assert_noop!(
+			AssetLimit::release_trapped_deposit(RuntimeOrigin::signed(1), 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetLimit::release_trapped_deposit(RuntimeOrigin::root(), 1),
+			<Error<Runtime>>::TrappedDepositNotFound
+		);
+		// Still over the limit.
+		assert_noop!(
+			AssetLimit::release_trapped_deposit(RuntimeOrigin::root(), 0),
+			<Error<Runtime>>::ForeignAssetLimitExceeded
+		);
+
+		limit(110);
+		System::reset_events();
+
+		assert_ok!(AssetLimit::release_trapped_deposit(RuntimeOrigin::root(), 0));
+		assert_eq!(Assets::balance(ASSET, 2), 50);
+		assert_eq!(AssetLimit::headroom(&AssetType(ASSET)), Some(0));
+		assert!(<TrappedDeposits<Runtime>>::get(0).is_none());
+		assert_eq!(
+			events(),
+			vec![Event::TrappedDepositReleased {
+				id: 0,
+				asset_type: AssetType(ASSET),
+				beneficiary: 2,
+				amount: 50,
+			}]
+		);
+	});
+}
+
+#[test]
+fn refund_trapped_deposit_should_work() {
+	new_test_ext().execute_with(|| {
+		limit(0);
+		assert_ok!(deposit(1, 50, None));
+		assert_ok!(deposit(1, 30, Some(2_000)));
+
+		// The origin is unknown.
+		assert_noop!(
+			AssetLimit::refund_trapped_deposit(RuntimeOrigin::root(), 0),
+			<Error<Runtime>>::RefundDestinationUnknown
+		);
+
+		System::reset_events();
+
+		// The refund is not subject to the limit.
+		assert_ok!(AssetLimit::refund_trapped_deposit(RuntimeOrigin::root(), 1));
+		assert_eq!(Assets::balance(ASSET, SIBLING_BASE + 2_000), 30);
+		assert_eq!(Assets::balance(ASSET, 1), 0);
+		assert!(<TrappedDeposits<Runtime>>::get(1).is_none());
+		assert_eq!(
+			events(),
+			vec![Event::TrappedDepositRefunded {
+				id: 1,
+				asset_type: AssetType(ASSET),
+				who: SIBLING_BASE + 2_000,
+				amount: 30,
+			}]
+		);
+	});
+}
+
+#[test]
+fn inflow_window_should_roll_over() {
+	new_test_ext().execute_with(|| {
+		inflow_limit(None, 100, 10);
+
+		assert_ok!(deposit(1, 100, None));
+		assert_eq!(AssetLimit::inflow_headroom(&AssetType(ASSET), None), Some(0));
+
+		System::reset_events();
+
+		assert_ok!(deposit(1, 1, None));
+		assert_eq!(Assets::balance(ASSET, 1), 100);
+		assert_eq!(
+			events(),
+			vec![Event::DepositTrapped {
+				id: 0,
+				asset_type: AssetType(ASSET),
+				beneficiary: 1,
+				amount: 1,
+				headroom: 0,
+				reason: TrapReason::InflowRateLimit,
+			}]
+		);
+
+		// Half of the window has passed.
+		System::set_block_number(6);
+		assert_eq!(AssetLimit::inflow_headroom(&AssetType(ASSET), None), Some(50));
+		assert_ok!(deposit(1, 50, None));
+		assert_eq!(Assets::balance(ASSET, 1), 150);
+		assert_eq!(AssetLimit::inflow_headroom(&AssetType(ASSET), None), Some(0));
+
+		// A whole window has passed.
+		System::set_block_number(16);
+		assert_eq!(AssetLimit::inflow_headroom(&AssetType(ASSET), None), Some(100));
+		assert_ok!(deposit(1, 100, None));
+		assert_eq!(Assets::balance(ASSET, 1), 250);
+		assert_eq!(<TrappedDeposits<Runtime>>::iter().count(), 1);
+	});
+}
+
+#[test]
+fn inflow_rate_limit_should_apply_per_parachain() {
+	new_test_ext().execute_with(|| {
+		inflow_limit(Some(2_000), 50, 10);
+		inflow_limit(None, 100, 10);
+
+		// Over the limit of the parachain.
+		assert_ok!(deposit(1, 60, Some(2_000)));
+		assert_eq!(Assets::balance(ASSET, 1), 0);
+		assert_eq!(<TrappedDeposits<Runtime>>::get(0).unwrap().reason, TrapReason::InflowRateLimit);
+
+		// Other origins are only subject to the limit of any origin.
+		assert_ok!(deposit(1, 60, Some(3_000)));
+		assert_eq!(Assets::balance(ASSET, 1), 60);
+		assert_eq!(AssetLimit::inflow_headroom(&AssetType(ASSET), Some(2_000)), Some(50));
+		assert_eq!(AssetLimit::inflow_headroom(&AssetType(ASSET), Some(3_000)), None);
+		assert_eq!(AssetLimit::inflow_headroom(&AssetType(ASSET), None), Some(40));
+
+		// The deposit from the parachain is accounted into both windows.
+		assert_ok!(deposit(1, 30, Some(2_000)));
+		assert_eq!(Assets::balance(ASSET, 1), 90);
+		assert_eq!(AssetLimit::inflow_headroom(&AssetType(ASSET), Some(2_000)), Some(20));
+		assert_eq!(AssetLimit::inflow_headroom(&AssetType(ASSET), None), Some(10));
+
+		// Within the limit of the parachain, but over the limit of any origin.
+		assert_ok!(deposit(1, 20, Some(2_000)));
+		assert_eq!(Assets::balance(ASSET, 1), 90);
+		assert_eq!(<TrappedDeposits<Runtime>>::iter().count(), 2);
+
+		// Removing the limit clears its window.
+		assert_ok!(AssetLimit::set_inflow_rate_limit(
+			RuntimeOrigin::root(),
+			AssetType(ASSET),
+			Some(2_000),
+			None
+		));
+		assert_eq!(AssetLimit::inflow_headroom(&AssetType(ASSET), Some(2_000)), None);
+		assert!(!<InflowWindows<Runtime>>::contains_key(AssetType(ASSET), Some(2_000)));
+	});
+}
+
+#[test]
+fn release_trapped_deposit_should_debit_inflow_window() {
+	new_test_ext().execute_with(|| {
+		inflow_limit(Some(2_000), 50, 10);
+
+		assert_ok!(deposit(1, 60, Some(2_000)));
+		// Still over the inflow rate limit.
+		assert_noop!(
+			AssetLimit::release_trapped_deposit(RuntimeOrigin::root(), 0),
+			<Error<Runtime>>::InflowRateLimitExceeded
+		);
+
+		inflow_limit(Some(2_000), 100, 10);
+		assert_ok!(deposit(1, 30, Some(2_000)));
+
+		// The released amount is accounted.
+		assert_ok!(AssetLimit::release_trapped_deposit(RuntimeOrigin::root(), 0));
+		assert_eq!(Assets::balance(ASSET, 1), 90);
+		assert_eq!(AssetLimit::inflow_headroom(&AssetType(ASSET), Some(2_000)), Some(10));
+
+		assert_ok!(deposit(1, 20, Some(2_000)));
+		assert_eq!(Assets::balance(ASSET, 1), 90);
+		assert_eq!(<TrappedDeposits<Runtime>>::iter().count(), 1);
+	});
+}
@@ -0,0 +1,117 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! XCM asset transactor enforcing the foreign asset limit.
+
+// core
+use core::marker::PhantomData;
+// darwinia
+use crate::*;
+// polkadot-sdk
+use pallet_asset_manager::AssetIdType;
+use xcm::latest::prelude::*;
+use xcm_executor::{
+	traits::{ConvertLocation, Error as MatchError, MatchesFungibles, TransactAsset},
+	AssetsInHolding,
+};
+
+/// Wrap the foreign asset transactor and enforce [`ForeignAssetLimit`] and [`InflowRateLimits`]
+/// on deposits.
+///
+/// A deposit which exceeds any of them is trapped in [`TrappedDeposits`] instead of being minted.
+/// Everything else is delegated to `Inner`.
+pub struct LimitedTransactor<T, Inner, Matcher>(PhantomData<(T, Inner, Matcher)>);
+impl<T, Inner, Matcher> TransactAsset for LimitedTransactor<T, Inner, Matcher>
+where
+	T: Config,
+	Inner: TransactAsset,
+	Matcher: MatchesFungibles<<T as pallet_asset_manager::Config>::AssetId, u128>,
+{
+	fn can_check_in(origin: &Location, what: &Asset, context: &XcmContext) -> XcmResult {
+		Inner::can_check_in(origin, what, context)
+	}
+
+	fn check_in(origin: &Location, what: &Asset, context: &XcmContext) {
+		Inner::check_in(origin, what, context)
+	}
+
+	fn can_check_out(dest: &Location, what: &Asset, context: &XcmContext) -> XcmResult {
+		Inner::can_check_out(dest, what, context)
+	}
+
+	fn check_out(dest: &Location, what: &Asset, context: &XcmContext) {
+		Inner::check_out(dest, what, context)
+	}
+
+	fn deposit_asset(what: &Asset, who: &Location, context: Option<&XcmContext>) -> XcmResult {
+		let Ok((asset_id, amount)) = Matcher::matches_fungibles(what) else {
+			return Inner::deposit_asset(what, who, context);
+		};
+		let Some(asset_type) = <AssetIdType<T>>::get(asset_id) else {
+			return Inner::deposit_asset(what, who, context);
+		};
+
+		let origin = context.and_then(|c| c.origin.as_ref());
+		let origin_para = origin.and_then(origin_para);
+
+		if let Some(trap) = <Pallet<T>>::check_deposit(&asset_type, origin_para, amount) {
+			let beneficiary = T::LocationToAccountId::convert_location(who)
+				.ok_or(MatchError::AccountIdConversionFailed)?;
+
+			<Pallet<T>>::trap_deposit(
+				asset_type,
+				beneficiary,
+				amount,
+				origin.map(|o| o.clone().into()),
+				trap,
+			);
+
+			return Ok(());
+		}
+
+		Inner::deposit_asset(what, who, context)?;
+		<Pallet<T>>::record_inflow(&asset_type, origin_para, amount);
+
+		Ok(())
+	}
+
+	fn withdraw_asset(
+		what: &Asset,
+		who: &Location,
+		context: Option<&XcmContext>,
+	) -> Result<AssetsInHolding, XcmError> {
+		Inner::withdraw_asset(what, who, context)
+	}
+
+	fn internal_transfer_asset(
+		what: &Asset,
+		from: &Location,
+		to: &Location,
+		context: &XcmContext,
+	) -> Result<AssetsInHolding, XcmError> {
+		Inner::internal_transfer_asset(what, from, to, context)
+	}
+}
+
+/// Id of the parachain which the origin belongs to.
+pub(crate) fn origin_para(origin: &Location) -> Option<u32> {
+	match origin.unpack() {
+		(1, [Parachain(id), ..]) => Some(*id),
+		_ => None,
+	}
+}
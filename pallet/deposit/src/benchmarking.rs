@@ -72,6 +72,42 @@ mod benchmarks {
 		_(RawOrigin::Root, a);
 	}
 
+	#[benchmark]
+	fn retry_failed_migration() {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+		let a_ = a.clone();
+
+		<Pallet<T>>::set_deposit_contract(RawOrigin::Root.into(), a.clone()).unwrap();
+
+		T::Ring::make_free_balance_be(&T::Treasury::get(), 2 << 126);
+
+		// Worst-case scenario:
+		//
+		// Max failed deposit items to be retried.
+		<MigrationFailures<T>>::insert(&a, {
+			let mut v = BoundedVec::new();
+
+			(0..512).for_each(|id| {
+				v.try_push((
+					Deposit {
+						id,
+						value: 1,
+						start_time: 0,
+						expired_time: Moment::MAX,
+						in_use: false,
+					},
+					MigrationFailure::Unknown,
+				))
+				.unwrap();
+			});
+
+			v
+		});
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a), a_);
+	}
+
 	frame_benchmarking::impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(),
@@ -26,6 +26,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 
+extern crate alloc;
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -34,14 +36,19 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migration;
+
+mod runtime_api;
+pub use runtime_api::*;
+
 mod weights;
 pub use weights::WeightInfo;
 
 // core
 use core::marker::PhantomData;
-// crates.io
-use ethabi::{Function, Param, ParamType, StateMutability, Token};
 // darwinia
+use darwinia_ethtx_forwarder::GasLimit;
+use dc_abi::deposit_migrator::MigrateCall;
 use dc_types::{Balance, Moment};
 // frontier
 use fp_evm::{CallOrCreateInfo, ExitReason};
@@ -59,6 +66,8 @@ pub mod pallet {
 	// darwinia
 	use crate::*;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_timestamp::Config {
 		/// Override the [`frame_system::Config::RuntimeEvent`].
@@ -76,6 +85,10 @@ pub mod pallet {
 		/// Treasury account.
 		#[pallet::constant]
 		type Treasury: Get<Self::AccountId>;
+
+		/// Gas budget of migrating a deposit to the deposit contract.
+		#[pallet::constant]
+		type MigrationGasPerDeposit: Get<u64>;
 	}
 
 	#[allow(missing_docs)]
@@ -86,6 +99,12 @@ pub mod pallet {
 		DepositsClaimed { owner: T::AccountId, deposits: Vec<DepositId> },
 		/// Deposits have been migrated.
 		DepositsMigrated { owner: T::AccountId, deposits: Vec<DepositId> },
+		/// Failed to migrate the deposits, they have been recorded in the [`MigrationFailures`].
+		DepositsMigrationFailed {
+			owner: T::AccountId,
+			deposits: Vec<DepositId>,
+			failure: MigrationFailure,
+		},
 	}
 
 	#[pallet::error]
@@ -96,6 +115,12 @@ pub mod pallet {
 		InvalidDepositContract,
 		/// Migration interaction with deposit contract failed.
 		MigrationFailedOnContract,
+		/// Failed to claim the expired deposits.
+		ClaimFailed,
+		/// No failed migration.
+		NoFailure,
+		/// Exceed the maximum number of the pending migration requests.
+		TooManyMigrationRequests,
 	}
 
 	/// All deposits.
@@ -107,17 +132,28 @@ pub mod pallet {
 
 	/// Failures of migration.
 	///
-	/// The first value is the deposits that failed to migrate,
-	/// the second value is the type of failure.
+	/// Each deposit that failed to migrate along with its failure.
 	#[pallet::storage]
-	pub type MigrationFailures<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, (BoundedVec<Deposit, ConstU32<512>>, u8)>;
+	pub type MigrationFailures<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(Deposit, MigrationFailure), ConstU32<512>>,
+	>;
 
 	// Deposit contract address.
 	#[pallet::storage]
 	pub type DepositContract<T: Config> = StorageValue<_, T::AccountId>;
 
+	/// Accounts whose deposits are requested to be migrated, served first in `on_idle`.
+	///
+	/// The requests come from the EVM, which must not be reentered by the migration.
+	#[pallet::storage]
+	pub type MigrationRequests<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, ConstU32<MAX_MIGRATION_REQUESTS>>, ValueQuery>;
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
@@ -125,7 +161,9 @@ pub mod pallet {
 			#[cfg(test)]
 			let wt = Weight::zero().add_ref_time(10);
 			#[cfg(not(test))]
-			let wt = <T as Config>::WeightInfo::migrate_for();
+			let wt = Self::migrate_for_weight()
+				// Read and update the migration requests.
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1));
 
 			if let Some(rw) = remaining_weight.checked_sub(&wt) {
 				remaining_weight = rw;
@@ -133,23 +171,24 @@ pub mod pallet {
 				return remaining_weight;
 			}
 
-			let Some((k, v)) = <Deposits<T>>::iter().drain().next() else {
+			let Some((k, v)) =
+				Self::take_requested().or_else(|| <Deposits<T>>::iter().drain().next())
+			else {
 				// There is nothing to do; add the weight back.
 				remaining_weight += wt;
 
 				return remaining_weight;
 			};
 
-			match Self::migrate_for_inner(&k, v.clone()) {
-				Ok(remaining_deposits) if !remaining_deposits.is_empty() => {
-					// There are still some deposits left for this account.
-					<Deposits<T>>::insert(&k, remaining_deposits);
-				},
-				Err((_, ty)) => {
-					// Migration failed, record the failures.
-					<MigrationFailures<T>>::insert(&k, (v, ty));
-				},
-				_ => (),
+			let (remaining_deposits, failures) = Self::migrate_for_inner(&k, v);
+
+			if !remaining_deposits.is_empty() {
+				// There are still some deposits left for this account.
+				<Deposits<T>>::insert(&k, remaining_deposits);
+			}
+			if !failures.is_empty() {
+				// Migration failed, record the failures.
+				Self::record_failures(&k, failures);
 			}
 
 			remaining_weight
@@ -159,12 +198,16 @@ pub mod pallet {
 	impl<T: Config> Pallet<T> {
 		/// Migrate the specified account's data to deposit contract.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::migrate_for())]
+		#[pallet::weight(Pallet::<T>::migrate_for_weight())]
 		pub fn migrate_for(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
 			ensure_signed(origin)?;
 
 			let deposits = <Deposits<T>>::take(&who).ok_or(<Error<T>>::NoDeposit)?;
-			let deposits = Self::migrate_for_inner(&who, deposits).map_err(|(e, _)| e)?;
+			let (deposits, failures) = Self::migrate_for_inner(&who, deposits);
+
+			if let Some((_, f)) = failures.first() {
+				return Err(f.as_error::<T>().into());
+			}
 
 			// Put the rest deposits back.
 			if !deposits.is_empty() {
@@ -187,19 +230,87 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Retry the failed migration of the specified account.
+		///
+		/// Process 0~10 deposits at a time, the rest will stay in the [`MigrationFailures`].
+		/// The deposits failed again are recorded with their new failures, after the rest ones.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::retry_failed_migration().saturating_add(
+			T::DepositMigrator::estimation_weight(MAX_DEPOSITS_PER_MIGRATION)
+		))]
+		pub fn retry_failed_migration(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let mut failures =
+				<MigrationFailures<T>>::take(&who).ok_or(<Error<T>>::NoFailure)?.into_inner();
+			let rest_failures =
+				failures.split_off(failures.len().min(MAX_DEPOSITS_PER_MIGRATION as _));
+			let (_, failures) = Self::migrate_for_inner(&who, failures.into_iter().map(|(d, _)| d));
+
+			// Put the rest failures back, followed by the new ones.
+			if !rest_failures.is_empty() {
+				<MigrationFailures<T>>::insert(&who, BoundedVec::truncate_from(rest_failures));
+			}
+			if !failures.is_empty() {
+				Self::record_failures(&who, failures);
+			}
+
+			Ok(())
+		}
 	}
 	impl<T> Pallet<T>
 	where
 		T: Config,
 	{
+		/// Request to migrate the deposits of an account in `on_idle`.
+		///
+		/// For the callers which must not reenter the EVM, e.g. a precompile.
+		pub fn request_migration(who: T::AccountId) -> DispatchResult {
+			ensure!(<Deposits<T>>::contains_key(&who), <Error<T>>::NoDeposit);
+
+			<MigrationRequests<T>>::try_mutate(|requests| -> DispatchResult {
+				if !requests.contains(&who) {
+					requests.try_push(who).map_err(|_| <Error<T>>::TooManyMigrationRequests)?;
+				}
+
+				Ok(())
+			})
+		}
+
+		// Take the deposits of the first requested account.
+		//
+		// The request is kept until all its deposits have been migrated.
+		#[allow(clippy::type_complexity)]
+		fn take_requested() -> Option<(T::AccountId, BoundedVec<Deposit, ConstU32<512>>)> {
+			<MigrationRequests<T>>::mutate(|requests| loop {
+				let who = requests.first()?.clone();
+
+				if let Some(deposits) = <Deposits<T>>::take(&who) {
+					return Some((who, deposits));
+				}
+
+				requests.remove(0);
+			})
+		}
+
+		fn migrate_for_weight() -> Weight {
+			<T as Config>::WeightInfo::migrate_for()
+				.saturating_add(T::DepositMigrator::estimation_weight(MAX_DEPOSITS_PER_MIGRATION))
+		}
+
 		fn now() -> Moment {
 			<pallet_timestamp::Pallet<T> as UnixTime>::now().as_millis()
 		}
 
+		// Migrate 0~10 deposits of the account.
+		//
+		// Return the rest deposits and the failed ones along with their failures.
+		#[allow(clippy::type_complexity)]
 		fn migrate_for_inner<I>(
 			who: &T::AccountId,
 			deposits: I,
-		) -> Result<BoundedVec<Deposit, ConstU32<512>>, (DispatchError, u8)>
+		) -> (BoundedVec<Deposit, ConstU32<512>>, Vec<(Deposit, MigrationFailure)>)
 		where
 			I: IntoIterator<Item = Deposit>,
 		{
@@ -207,37 +318,76 @@ pub mod pallet {
 			let mut deposits = deposits.into_iter();
 			let mut to_claim = (0, Vec::new());
 			let mut to_migrate = (0, Vec::new(), Vec::new());
+			let mut failures = Vec::new();
 
 			// Take 0~10 deposits to migrate.
-			for d in deposits.by_ref().take(10).filter(|d| d.value != 0) {
+			for d in
+				deposits.by_ref().take(MAX_DEPOSITS_PER_MIGRATION as _).filter(|d| d.value != 0)
+			{
 				if d.expired_time <= now {
 					to_claim.0 += d.value;
-					to_claim.1.push(d.id);
+					to_claim.1.push(d);
 				} else {
 					to_migrate.0 += d.value;
-					to_migrate.1.push(d.id);
 					to_migrate.2.push((d.value, d.start_time / 1_000, d.expired_time / 1_000));
+					to_migrate.1.push(d);
 				}
 			}
 
 			if to_claim.0 != 0 {
-				T::Ring::transfer(&T::Treasury::get(), who, to_claim.0, AllowDeath)
-					.map_err(|e| (e, 0))?;
-				Self::deposit_event(Event::DepositsClaimed {
-					owner: who.clone(),
-					deposits: to_claim.1,
-				});
+				match T::Ring::transfer(&T::Treasury::get(), who, to_claim.0, AllowDeath) {
+					Ok(_) => Self::deposit_event(Event::DepositsClaimed {
+						owner: who.clone(),
+						deposits: to_claim.1.iter().map(|d| d.id).collect(),
+					}),
+					Err(e) => {
+						log::error!("failed to claim deposits due to {e:?}");
+
+						Self::note_failures(
+							who,
+							to_claim.1,
+							MigrationFailure::Claim,
+							&mut failures,
+						);
+					},
+				}
 			}
 			if to_migrate.0 != 0 {
-				T::DepositMigrator::migrate(who.clone(), to_migrate.0, to_migrate.2)
-					.map_err(|e| (e, 1))?;
-				Self::deposit_event(Event::DepositsMigrated {
-					owner: who.clone(),
-					deposits: to_migrate.1,
-				});
+				match T::DepositMigrator::migrate(who.clone(), to_migrate.0, to_migrate.2) {
+					Ok(_) => Self::deposit_event(Event::DepositsMigrated {
+						owner: who.clone(),
+						deposits: to_migrate.1.iter().map(|d| d.id).collect(),
+					}),
+					Err(f) => Self::note_failures(who, to_migrate.1, f, &mut failures),
+				}
 			}
 
-			Ok(BoundedVec::truncate_from(deposits.collect()))
+			(BoundedVec::truncate_from(deposits.collect()), failures)
+		}
+
+		fn note_failures(
+			who: &T::AccountId,
+			deposits: Vec<Deposit>,
+			failure: MigrationFailure,
+			failures: &mut Vec<(Deposit, MigrationFailure)>,
+		) {
+			Self::deposit_event(Event::DepositsMigrationFailed {
+				owner: who.clone(),
+				deposits: deposits.iter().map(|d| d.id).collect(),
+				failure: failure.clone(),
+			});
+
+			failures.extend(deposits.into_iter().map(|d| (d, failure.clone())));
+		}
+
+		fn record_failures(who: &T::AccountId, failures: Vec<(Deposit, MigrationFailure)>) {
+			<MigrationFailures<T>>::mutate(who, |maybe_failures| {
+				let mut all_failures = maybe_failures.take().unwrap_or_default().into_inner();
+
+				all_failures.extend(failures);
+
+				*maybe_failures = Some(BoundedVec::truncate_from(all_failures));
+			});
 		}
 	}
 }
@@ -252,12 +402,89 @@ where
 	T: Config,
 {
 	/// Migrate to contract.
-	fn migrate(_: T::AccountId, _: Balance, _: Vec<(Balance, Moment, Moment)>) -> DispatchResult {
+	fn migrate(
+		_: T::AccountId,
+		_: Balance,
+		_: Vec<(Balance, Moment, Moment)>,
+	) -> Result<(), MigrationFailure> {
 		Ok(())
 	}
+
+	/// Weight of estimating the gas of migrating this many deposits.
+	fn estimation_weight(_: u32) -> Weight {
+		Weight::zero()
+	}
 }
 impl<T> MigrateToContract<T> for () where T: Config {}
 
+/// Max number of deposits migrated at a time.
+pub const MAX_DEPOSITS_PER_MIGRATION: u32 = 10;
+/// Max number of the pending [`MigrationRequests`].
+pub const MAX_MIGRATION_REQUESTS: u32 = 64;
+
+/// Max length of the revert data kept in a [`MigrationFailure`].
+///
+/// Keep it short, it is duplicated for each deposit.
+pub const MAX_REVERT_DATA_LEN: u32 = 256;
+/// Max length of the error message kept in an [`EvmExitReason`].
+pub const MAX_EXIT_MESSAGE_LEN: u32 = 64;
+
+/// Failure of the deposit migration.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub enum MigrationFailure {
+	/// Recorded before the failures were typed, the reason is unknown.
+	Unknown,
+	/// Failed to transfer the expired deposits to the owner.
+	Claim,
+	/// The deposit contract has not been set.
+	InvalidDepositContract,
+	/// Failed to dispatch the transaction to the deposit contract.
+	Dispatch,
+	/// The deposit contract call did not succeed.
+	Evm {
+		/// EVM exit reason.
+		exit_reason: EvmExitReason,
+		/// Revert data, truncated to [`MAX_REVERT_DATA_LEN`] bytes.
+		revert_data: BoundedVec<u8, ConstU32<MAX_REVERT_DATA_LEN>>,
+	},
+}
+impl MigrationFailure {
+	/// Convert the failure into the pallet error.
+	pub fn as_error<T>(&self) -> Error<T> {
+		match self {
+			Self::Claim => <Error<T>>::ClaimFailed,
+			Self::InvalidDepositContract => <Error<T>>::InvalidDepositContract,
+			_ => <Error<T>>::MigrationFailedOnContract,
+		}
+	}
+}
+
+/// Exit reason of a failed deposit contract call.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub enum EvmExitReason {
+	/// Reverted by the contract, see the revert data.
+	Revert,
+	/// Stopped by an EVM error, e.g. out of gas.
+	Error(BoundedVec<u8, ConstU32<MAX_EXIT_MESSAGE_LEN>>),
+	/// Stopped by a fatal EVM error.
+	Fatal(BoundedVec<u8, ConstU32<MAX_EXIT_MESSAGE_LEN>>),
+}
+impl EvmExitReason {
+	/// Convert the failed [`ExitReason`], the message is truncated to [`MAX_EXIT_MESSAGE_LEN`].
+	pub fn from_exit_reason(exit_reason: &ExitReason) -> Self {
+		fn message(e: impl core::fmt::Debug) -> BoundedVec<u8, ConstU32<MAX_EXIT_MESSAGE_LEN>> {
+			BoundedVec::truncate_from(alloc::format!("{e:?}").into_bytes())
+		}
+
+		match exit_reason {
+			ExitReason::Error(e) => Self::Error(message(e)),
+			ExitReason::Fatal(e) => Self::Fatal(message(e)),
+			// `Succeed` is never recorded.
+			_ => Self::Revert,
+		}
+	}
+}
+
 /// Deposit.
 #[derive(Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
 pub struct Deposit {
@@ -284,63 +511,47 @@ where
 		who: T::AccountId,
 		total: Balance,
 		deposits: Vec<(Balance, Moment, Moment)>,
-	) -> DispatchResult {
+	) -> Result<(), MigrationFailure> {
 		let cnt = deposits.len();
-		let dc = <DepositContract<T>>::get().ok_or(<Error<T>>::InvalidDepositContract)?.into();
-		#[allow(deprecated)]
-		let exit_reason = match darwinia_ethtx_forwarder::quick_forward_transact::<T>(
+		let dc =
+			<DepositContract<T>>::get().ok_or(MigrationFailure::InvalidDepositContract)?.into();
+		let outcome = match darwinia_ethtx_forwarder::quick_forward_transact::<T, _>(
 			T::Treasury::get().into(),
-			Function {
-				name: "migrate".into(),
-				inputs: vec![
-					Param {
-						name: "address".to_owned(),
-						kind: ParamType::Address,
-						internal_type: None,
-					},
-					Param {
-						name: "deposits".to_owned(),
-						kind: ParamType::Array(Box::new(ParamType::Tuple(vec![
-							ParamType::Uint(128),
-							ParamType::Uint(64),
-							ParamType::Uint(64),
-						]))),
-						internal_type: None,
-					},
-				],
-				outputs: Vec::new(),
-				constant: None,
-				state_mutability: StateMutability::Payable,
+			MigrateCall {
+				account: who.into(),
+				// The timestamps are in seconds, which always fit in `uint64`.
+				deposits: deposits.into_iter().map(|(v, s, e)| (v, s as _, e as _)).collect(),
 			},
-			&[
-				Token::Address(who.into()),
-				Token::Array(
-					deposits
-						.into_iter()
-						.map(|(v, s, e)| {
-							Token::Tuple(vec![
-								Token::Uint(v.into()),
-								Token::Uint(s.into()),
-								Token::Uint(e.into()),
-							])
-						})
-						.collect(),
-				),
-			],
 			dc,
 			total.into(),
-			// Approximately consume 160,000 gas per deposit on Koi testnet.
-			(200_000 * cnt as u64).into(),
-		)?
+			GasLimit::Estimated {
+				budget: T::MigrationGasPerDeposit::get().saturating_mul(cnt as _).into(),
+			},
+		)
+		.map_err(|e| {
+			log::error!("failed to forward the migration due to {e:?}");
+
+			MigrationFailure::Dispatch
+		})?
 		.1
 		{
-			CallOrCreateInfo::Call(i) => i.exit_reason,
-			CallOrCreateInfo::Create(i) => i.exit_reason,
+			CallOrCreateInfo::Call(i) => (i.exit_reason, i.value),
+			CallOrCreateInfo::Create(i) => (i.exit_reason, Vec::new()),
 		};
+		let (exit_reason, revert_data) = outcome;
 
 		match exit_reason {
 			ExitReason::Succeed(_) => Ok(()),
-			_ => Err(<Error<T>>::MigrationFailedOnContract)?,
+			exit_reason => Err(MigrationFailure::Evm {
+				exit_reason: EvmExitReason::from_exit_reason(&exit_reason),
+				revert_data: BoundedVec::truncate_from(revert_data),
+			}),
 		}
 	}
+
+	fn estimation_weight(deposits: u32) -> Weight {
+		<darwinia_ethtx_forwarder::Pallet<T>>::estimation_weight(
+			T::MigrationGasPerDeposit::get().saturating_mul(deposits as _).into(),
+		)
+	}
 }
@@ -0,0 +1,60 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the deposit pallet.
+
+// darwinia
+use crate::*;
+// polkadot-sdk
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+
+/// Translate the [`MigrationFailures`] into the typed per-deposit failures.
+pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+impl<T> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T>
+where
+	T: Config,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let mut count = 0;
+
+		<MigrationFailures<T>>::translate::<(BoundedVec<Deposit, ConstU32<512>>, u8), _>(
+			|_, (deposits, ty)| {
+				count += 1;
+
+				// `0` stood for the claim failure, `1` stood for the contract failure.
+				let failure =
+					if ty == 0 { MigrationFailure::Claim } else { MigrationFailure::Unknown };
+
+				Some(BoundedVec::truncate_from(
+					deposits.into_iter().map(|d| (d, failure.clone())).collect(),
+				))
+			},
+		);
+
+		T::DbWeight::get().reads_writes(count, count)
+	}
+}
+
+/// [`InnerMigrateV0ToV1`] guarded by the storage version.
+pub type MigrateV0ToV1<T> = VersionedMigration<
+	0,
+	1,
+	InnerMigrateV0ToV1<T>,
+	Pallet<T>,
+	<T as frame_system::Config>::DbWeight,
+>;
@@ -43,8 +43,30 @@ impl pallet_balances::Config for Runtime {
 	type ExistentialDeposit = ();
 }
 
+frame_support::parameter_types! {
+	pub static ContractFailure: bool = false;
+}
+pub enum DepositMigrator {}
+impl crate::MigrateToContract<Runtime> for DepositMigrator {
+	fn migrate(
+		_: u64,
+		_: Balance,
+		_: Vec<(Balance, Moment, Moment)>,
+	) -> Result<(), MigrationFailure> {
+		if ContractFailure::get() {
+			Err(MigrationFailure::Evm {
+				exit_reason: EvmExitReason::Revert,
+				revert_data: BoundedVec::truncate_from(vec![1; 512]),
+			})
+		} else {
+			Ok(())
+		}
+	}
+}
+
 impl crate::Config for Runtime {
-	type DepositMigrator = ();
+	type DepositMigrator = DepositMigrator;
+	type MigrationGasPerDeposit = ();
 	type Ring = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type Treasury = ();
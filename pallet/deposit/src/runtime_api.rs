@@ -0,0 +1,40 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the deposit pallet.
+
+// crates.io
+use codec::Codec;
+// darwinia
+use crate::{Deposit, MigrationFailure};
+// polkadot-sdk
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	/// Deposit migration introspection.
+	pub trait DepositApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// Deposits of the account which are waiting to be migrated.
+		fn outstanding_deposits(who: AccountId) -> Vec<Deposit>;
+
+		/// Deposits of the account which failed to migrate, along with their failures.
+		fn failed_deposits(who: AccountId) -> Vec<(Deposit, MigrationFailure)>;
+	}
+}
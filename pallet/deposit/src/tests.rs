@@ -22,7 +22,10 @@ use crate::{
 	Deposit as DepositS, *,
 };
 // polkadot-sdk
-use frame_support::{assert_ok, traits::OnIdle};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{OnIdle, UncheckedOnRuntimeUpgrade},
+};
 
 #[test]
 fn migrate_should_work() {
@@ -128,7 +131,213 @@ fn on_idle_should_work() {
 		assert_eq!(<Deposits<Runtime>>::get(1).unwrap().len(), 512);
 
 		<Deposit as OnIdle<_>>::on_idle(0, Weight::MAX);
+		assert_eq!(<Deposits<Runtime>>::get(1).unwrap().len(), 502);
+		assert_eq!(<MigrationFailures<Runtime>>::get(1).unwrap().len(), 10);
+
+		(0..51).for_each(|_| {
+			<Deposit as OnIdle<_>>::on_idle(0, Weight::MAX);
+		});
 		assert!(<Deposits<Runtime>>::get(1).is_none());
-		assert_eq!(<MigrationFailures<Runtime>>::get(1).unwrap().0.into_iter().count(), 512);
+		assert_eq!(<MigrationFailures<Runtime>>::get(1).unwrap().len(), 512);
+		assert!(<MigrationFailures<Runtime>>::get(1)
+			.unwrap()
+			.into_iter()
+			.all(|(_, f)| f == MigrationFailure::Claim));
+	});
+}
+
+#[test]
+fn requested_migrations_should_be_served_first() {
+	fn mock_deposits(count: u16) -> BoundedVec<DepositS, ConstU32<512>> {
+		BoundedVec::truncate_from(
+			(0..count)
+				.map(|id| DepositS { id, value: 1, start_time: 0, expired_time: 0, in_use: false })
+				.collect(),
+		)
+	}
+
+	new_test_ext().execute_with(|| {
+		let _ = Balances::deposit_creating(&0, 10_000);
+
+		<Deposits<Runtime>>::insert(1, mock_deposits(12));
+		<Deposits<Runtime>>::insert(2, mock_deposits(12));
+
+		assert_noop!(Deposit::request_migration(3), <Error<Runtime>>::NoDeposit);
+		assert_ok!(Deposit::request_migration(2));
+		assert_ok!(Deposit::request_migration(2));
+		assert_eq!(<MigrationRequests<Runtime>>::get().into_inner(), [2]);
+
+		// The request is kept until all the deposits have been migrated.
+		<Deposit as OnIdle<_>>::on_idle(0, Weight::MAX);
+		assert_eq!(<Deposits<Runtime>>::get(1).unwrap().len(), 12);
+		assert_eq!(<Deposits<Runtime>>::get(2).unwrap().len(), 2);
+		assert_eq!(<MigrationRequests<Runtime>>::get().into_inner(), [2]);
+
+		<Deposit as OnIdle<_>>::on_idle(0, Weight::MAX);
+		assert_eq!(<Deposits<Runtime>>::get(1).unwrap().len(), 12);
+		assert!(<Deposits<Runtime>>::get(2).is_none());
+
+		<Deposit as OnIdle<_>>::on_idle(0, Weight::MAX);
+		assert_eq!(<Deposits<Runtime>>::get(1).unwrap().len(), 2);
+		assert!(<MigrationRequests<Runtime>>::get().is_empty());
+	});
+}
+
+#[test]
+fn migration_failures_should_be_tracked_per_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let _ = Balances::deposit_creating(&0, 2);
+
+		<Deposits<Runtime>>::insert(
+			1,
+			BoundedVec::truncate_from(vec![
+				DepositS { id: 0, value: 1, start_time: 0, expired_time: 0, in_use: false },
+				DepositS { id: 1, value: 1, start_time: 0, expired_time: 1_000, in_use: false },
+				DepositS { id: 2, value: 1, start_time: 0, expired_time: 1_000, in_use: false },
+			]),
+		);
+		ContractFailure::set(true);
+
+		assert_noop!(
+			Deposit::migrate_for(RuntimeOrigin::signed(1), 1),
+			<Error<Runtime>>::MigrationFailedOnContract
+		);
+
+		let failure = MigrationFailure::Evm {
+			exit_reason: EvmExitReason::Revert,
+			revert_data: BoundedVec::truncate_from(vec![1; 512]),
+		};
+
+		<Deposit as OnIdle<_>>::on_idle(0, Weight::MAX);
+		assert_eq!(
+			events(),
+			[
+				Event::DepositsClaimed { owner: 1, deposits: vec![0] },
+				Event::DepositsMigrationFailed {
+					owner: 1,
+					deposits: vec![1, 2],
+					failure: failure.clone()
+				}
+			]
+		);
+		assert!(<Deposits<Runtime>>::get(1).is_none());
+		assert_eq!(
+			<MigrationFailures<Runtime>>::get(1).unwrap().into_inner(),
+			[
+				(
+					DepositS { id: 1, value: 1, start_time: 0, expired_time: 1_000, in_use: false },
+					failure.clone()
+				),
+				(
+					DepositS { id: 2, value: 1, start_time: 0, expired_time: 1_000, in_use: false },
+					failure
+				)
+			]
+		);
+	});
+}
+
+#[test]
+fn retry_failed_migration_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			Deposit::retry_failed_migration(RuntimeOrigin::signed(1), 1),
+			<Error<Runtime>>::NoFailure
+		);
+
+		<MigrationFailures<Runtime>>::insert(
+			1,
+			BoundedVec::truncate_from(
+				(0..12)
+					.map(|id| {
+						(
+							DepositS {
+								id,
+								value: 1,
+								start_time: 0,
+								expired_time: 1_000,
+								in_use: false,
+							},
+							MigrationFailure::Unknown,
+						)
+					})
+					.collect(),
+			),
+		);
+		ContractFailure::set(true);
+
+		// The failed ones are recorded again after the rest ones.
+		assert_ok!(Deposit::retry_failed_migration(RuntimeOrigin::signed(1), 1));
+		let failure = MigrationFailure::Evm {
+			exit_reason: EvmExitReason::Revert,
+			revert_data: BoundedVec::truncate_from(vec![1; 512]),
+		};
+		assert_eq!(
+			events(),
+			[Event::DepositsMigrationFailed {
+				owner: 1,
+				deposits: (0..10).collect(),
+				failure: failure.clone()
+			}]
+		);
+		let failures = <MigrationFailures<Runtime>>::get(1).unwrap().into_inner();
+		assert_eq!(
+			failures.iter().map(|(d, _)| d.id).collect::<Vec<_>>(),
+			[10, 11, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
+		);
+		assert_eq!(failures[0].1, MigrationFailure::Unknown);
+		assert!(failures[2..].iter().all(|(_, f)| f == &failure));
+
+		ContractFailure::set(false);
+		System::reset_events();
+
+		assert_ok!(Deposit::retry_failed_migration(RuntimeOrigin::signed(1), 1));
+		assert_eq!(
+			events(),
+			[Event::DepositsMigrated {
+				owner: 1,
+				deposits: [10, 11, 0, 1, 2, 3, 4, 5, 6, 7].to_vec()
+			}]
+		);
+		assert_eq!(
+			<MigrationFailures<Runtime>>::get(1)
+				.unwrap()
+				.into_iter()
+				.map(|(d, _)| d.id)
+				.collect::<Vec<_>>(),
+			[8, 9]
+		);
+
+		assert_ok!(Deposit::retry_failed_migration(RuntimeOrigin::signed(1), 1));
+		assert!(<MigrationFailures<Runtime>>::get(1).is_none());
+	});
+}
+
+#[test]
+fn migrate_v0_to_v1_should_work() {
+	new_test_ext().execute_with(|| {
+		let deposit = DepositS { id: 0, value: 1, start_time: 0, expired_time: 0, in_use: false };
+
+		[(1_u64, 0_u8), (2, 1)].into_iter().for_each(|(who, ty)| {
+			frame_support::storage::unhashed::put(
+				&<MigrationFailures<Runtime>>::hashed_key_for(who),
+				&(BoundedVec::<_, ConstU32<512>>::truncate_from(vec![deposit.clone()]), ty),
+			);
+		});
+
+		<migration::InnerMigrateV0ToV1<Runtime> as UncheckedOnRuntimeUpgrade>::on_runtime_upgrade();
+
+		assert_eq!(
+			<MigrationFailures<Runtime>>::get(1).unwrap().into_inner(),
+			[(deposit.clone(), MigrationFailure::Claim)]
+		);
+		assert_eq!(
+			<MigrationFailures<Runtime>>::get(2).unwrap().into_inner(),
+			[(deposit, MigrationFailure::Unknown)]
+		);
 	});
 }
@@ -22,6 +22,9 @@
 //! DATE: 2024-10-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: Some("koi-dev"), DB CACHE: 1024
+//!
+//! PLACEHOLDERS: the weights of `retry_failed_migration` are estimated by hand from their storage
+//! accesses, not measured. Regenerate this file with the benchmark CLI before the release.
 
 // Executed Command:
 // target/release/darwinia
@@ -54,6 +57,7 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn migrate_for() -> Weight;
 	fn set_deposit_contract() -> Weight;
+	fn retry_failed_migration() -> Weight;
 }
 
 /// Weights for darwinia_deposit using the Substrate node and recommended hardware.
@@ -96,6 +100,32 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Deposit::MigrationFailures` (r:1 w:1)
+	/// Proof: `Deposit::MigrationFailures` (`max_values`: None, `max_size`: Some(192550), added: 195025, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Deposit::DepositContract` (r:1 w:0)
+	/// Proof: `Deposit::DepositContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+	/// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:2 w:0)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Digest` (r:1 w:0)
+	/// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Ethereum::Pending` (r:1 w:1)
+	/// Proof: `Ethereum::Pending` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn retry_failed_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `28540`
+		//  Estimated: `199856`
+		// Minimum execution time: 228_000 nanoseconds.
+		Weight::from_parts(234_000_000, 0)
+			.saturating_add(Weight::from_parts(199856, 0))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -137,4 +167,30 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Deposit::MigrationFailures` (r:1 w:1)
+	/// Proof: `Deposit::MigrationFailures` (`max_values`: None, `max_size`: Some(192550), added: 195025, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Deposit::DepositContract` (r:1 w:0)
+	/// Proof: `Deposit::DepositContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `TransactionPayment::NextFeeMultiplier` (r:1 w:0)
+	/// Proof: `TransactionPayment::NextFeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:2 w:0)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Digest` (r:1 w:0)
+	/// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Ethereum::Pending` (r:1 w:1)
+	/// Proof: `Ethereum::Pending` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn retry_failed_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `28540`
+		//  Estimated: `199856`
+		// Minimum execution time: 228_000 nanoseconds.
+		Weight::from_parts(234_000_000, 0)
+			.saturating_add(Weight::from_parts(199856, 0))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
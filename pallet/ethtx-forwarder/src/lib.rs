@@ -25,28 +25,34 @@ mod test;
 
 // crates.io
 use codec::{Decode, Encode, MaxEncodedLen};
-use ethabi::{Function, Token};
 use ethereum::{
-	EIP1559Transaction, EIP2930Transaction, LegacyTransaction, TransactionAction,
+	AccessListItem, EIP1559Transaction, EIP2930Transaction, LegacyTransaction, TransactionAction,
 	TransactionSignature, TransactionV2 as Transaction,
 };
 use scale_info::TypeInfo;
+// darwinia
+use dc_abi::{Revert, SolCall};
 // frontier
 use fp_ethereum::{TransactionData, ValidatedTransaction};
 use fp_evm::{
-	CallOrCreateInfo, CheckEvmTransaction, CheckEvmTransactionConfig, TransactionValidationError,
+	CallOrCreateInfo, CheckEvmTransaction, CheckEvmTransactionConfig, ExitError, ExitReason,
+	TransactionValidationError,
 };
 use pallet_evm::{FeeCalculator, GasWeightMapping, Runner};
 // polkadot-sdk
 use frame_support::{
-	dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo, PostDispatchInfo},
-	traits::EnsureOrigin,
-	PalletError,
+	dispatch::{
+		DispatchErrorWithPostInfo, DispatchResult, DispatchResultWithPostInfo, PostDispatchInfo,
+	},
+	storage::{self, TransactionOutcome},
+	traits::{ConstU32, EnsureOrigin},
+	weights::Weight,
+	BoundedVec, PalletError,
 };
-use sp_core::{Get, H160, H256, U256};
+use sp_core::{hashing::keccak_256, Get, H160, H256, U256};
 use sp_runtime::{
 	traits::{BadOrigin, UniqueSaturatedInto},
-	DispatchError, RuntimeDebug,
+	DispatchError, Percent, RuntimeDebug,
 };
 use sp_std::vec::Vec;
 
@@ -99,16 +105,48 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_evm::Config {
+		/// Override the [`frame_system::Config::RuntimeEvent`].
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
 		/// Handler for applying an already validated transaction
 		type ValidatedTransaction: ValidatedTransaction;
 		/// Origin for the forward eth transaction
 		type ForwardEthOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = H160>;
+		/// Safety margin added on top of the estimated gas.
+		#[pallet::constant]
+		type GasEstimationMargin: Get<Percent>;
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Transaction validation errors.
 		ValidationError(TxErrorWrapper),
+		/// The dry run of the transaction failed.
+		GasEstimationFailed,
+		/// The estimated gas exceeds the budget of the caller.
+		GasBudgetExceeded,
+		/// The EVM execution reverted.
+		Reverted(RevertKind),
+		/// The EVM execution failed.
+		ExecutionFailed,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A transaction has been forwarded.
+		Forwarded {
+			source: H160,
+			/// The called contract or the created contract.
+			target: H160,
+			tx_hash: H256,
+			exit_reason: ExitReason,
+			used_gas: U256,
+			/// Keccak-256 hash of the return data or the revert data.
+			return_data_hash: H256,
+			/// The decoded revert reason, if the execution reverted.
+			revert_reason: Option<RevertReason>,
+		},
 	}
 
 	#[pallet::pallet]
@@ -124,6 +162,19 @@ pub mod pallet {
 			<T as pallet_evm::Config>::GasWeightMapping::gas_to_weight(request.gas_limit.unique_saturated_into(), true)
 		})]
 		pub fn forward_transact(
+			origin: OriginFor<T>,
+			request: ForwardRequestV0,
+		) -> DispatchResultWithPostInfo {
+			Self::forward_transact_inner(ensure_forward_transact(origin)?, request.into())
+				.map(|(r, _)| r)
+		}
+
+		/// Same as [`Pallet::forward_transact`], with the access list, the priority fee and the nonce.
+		#[pallet::call_index(1)]
+		#[pallet::weight({
+			<T as pallet_evm::Config>::GasWeightMapping::gas_to_weight(request.gas_limit.unique_saturated_into(), true)
+		})]
+		pub fn forward_transact_v1(
 			origin: OriginFor<T>,
 			request: ForwardRequest,
 		) -> DispatchResultWithPostInfo {
@@ -132,14 +183,110 @@ pub mod pallet {
 	}
 }
 impl<T: Config> Pallet<T> {
-	pub fn forward_call(
+	pub fn forward_call<C>(
+		from: H160,
+		to: H160,
+		call: C,
+		value: U256,
+		gas_limit: U256,
+	) -> Result<C::Return, DispatchError>
+	where
+		C: SolCall,
+	{
+		log::info!("calling {} on {to:?} with {call:?}", C::SIGNATURE);
+
+		let info = Self::call_inner(from, to, call.encode(), value, gas_limit)?;
+
+		Self::ensure_succeed(&info.exit_reason, &info.value)?;
+
+		C::decode_output(&info.value).map_err(|e| {
+			log::error!("failed to decode output due to {e:?}");
+
+			DispatchError::from("failed to decode output")
+		})
+	}
+
+	/// Ensure the EVM execution succeeded.
+	///
+	/// The revert reason is decoded from the revert data.
+	pub fn ensure_succeed(exit_reason: &ExitReason, data: &[u8]) -> DispatchResult {
+		match exit_reason {
+			ExitReason::Succeed(_) => Ok(()),
+			ExitReason::Revert(_) => {
+				let revert = Revert::decode(data);
+
+				log::error!("execution reverted due to {revert:?}");
+
+				Err(<Error<T>>::Reverted(RevertReason::from(revert).kind()).into())
+			},
+			exit_reason => {
+				log::error!("execution failed due to {exit_reason:?}");
+
+				Err(<Error<T>>::ExecutionFailed.into())
+			},
+		}
+	}
+
+	/// Estimate the gas limit of a transaction.
+	///
+	/// The transaction is dry-run with the `budget` as its gas limit and all its state changes
+	/// are discarded. Return the effective used gas, which includes the proof size at the
+	/// `GasLimitPovSizeRatio`, plus the [`Config::GasEstimationMargin`]. It must not exceed the
+	/// `budget`.
+	pub fn estimate_gas(
+		from: H160,
+		to: H160,
+		data: Vec<u8>,
+		value: U256,
+		budget: U256,
+	) -> Result<U256, DispatchError> {
+		let info = storage::with_transaction(|| {
+			TransactionOutcome::Rollback(Self::call_inner(from, to, data, value, budget))
+		})?;
+
+		match info.exit_reason {
+			ExitReason::Succeed(_) => (),
+			ExitReason::Error(ExitError::OutOfGas) =>
+				return Err(<Error<T>>::GasBudgetExceeded.into()),
+			exit_reason => {
+				log::error!("failed to estimate gas due to {exit_reason:?}");
+
+				return Err(<Error<T>>::GasEstimationFailed.into());
+			},
+		}
+
+		let used_gas: u64 = info.used_gas.effective.unique_saturated_into();
+		let gas_limit =
+			U256::from(T::GasEstimationMargin::get().mul_ceil(used_gas).saturating_add(used_gas));
+
+		if gas_limit > budget {
+			log::error!("estimated gas {gas_limit} exceeds the budget {budget}");
+
+			return Err(<Error<T>>::GasBudgetExceeded.into());
+		}
+
+		Ok(gas_limit)
+	}
+
+	/// Weight of the dry run of a [`GasLimit::Estimated`] transaction with this budget.
+	///
+	/// The caller must charge it on top of the weight of the transaction itself.
+	pub fn estimation_weight(budget: U256) -> Weight {
+		<T as pallet_evm::Config>::GasWeightMapping::gas_to_weight(
+			budget.unique_saturated_into(),
+			true,
+		)
+	}
+
+	fn call_inner(
 		from: H160,
 		to: H160,
 		data: Vec<u8>,
 		value: U256,
 		gas_limit: U256,
-	) -> Result<pallet_evm::CallInfo, sp_runtime::DispatchError> {
+	) -> Result<pallet_evm::CallInfo, DispatchError> {
 		let (who, _) = pallet_evm::Pallet::<T>::account_basic(&from);
+
 		<T as pallet_evm::Config>::Runner::call(
 			from,
 			to,
@@ -164,11 +311,40 @@ impl<T: Config> Pallet<T> {
 		request: ForwardRequest,
 	) -> Result<(PostDispatchInfo, CallOrCreateInfo), DispatchErrorWithPostInfo> {
 		let transaction = Self::validated_transaction(source, request)?;
+		let tx_hash = transaction.hash();
+		let target = match transaction_action(&transaction) {
+			TransactionAction::Call(target) => Some(*target),
+			TransactionAction::Create => None,
+		};
 
 		#[cfg(feature = "evm-tracing")]
-		return Self::trace_tx(source, transaction);
+		let r = Self::trace_tx(source, transaction);
 		#[cfg(not(feature = "evm-tracing"))]
-		return T::ValidatedTransaction::apply(source, transaction);
+		let r = T::ValidatedTransaction::apply(source, transaction);
+
+		if let Ok((_, info)) = &r {
+			let (target, exit_reason, used_gas, return_data) = match info {
+				CallOrCreateInfo::Call(i) =>
+					(target.unwrap_or_default(), &i.exit_reason, i.used_gas.standard, &i.value[..]),
+				CallOrCreateInfo::Create(i) =>
+					(i.value, &i.exit_reason, i.used_gas.standard, &[][..]),
+			};
+
+			let revert_reason = matches!(exit_reason, ExitReason::Revert(_))
+				.then(|| Revert::decode(return_data).into());
+
+			Self::deposit_event(Event::Forwarded {
+				source,
+				target,
+				tx_hash,
+				exit_reason: exit_reason.clone(),
+				used_gas,
+				return_data_hash: H256(keccak_256(return_data)),
+				revert_reason,
+			});
+		}
+
+		r
 	}
 
 	fn validated_transaction(
@@ -177,10 +353,13 @@ impl<T: Config> Pallet<T> {
 	) -> Result<Transaction, DispatchError> {
 		let (who, _) = pallet_evm::Pallet::<T>::account_basic(&source);
 		let base_fee = T::FeeCalculator::min_gas_price().0;
+		let chain_id = T::ChainId::get();
+		// Validated against the current nonce of the source below.
+		let nonce = request.nonce.unwrap_or(who.nonce);
 
 		let transaction = match request.tx_type {
 			TxType::LegacyTransaction => Transaction::Legacy(LegacyTransaction {
-				nonce: who.nonce,
+				nonce,
 				gas_price: base_fee,
 				gas_limit: request.gas_limit,
 				action: request.action,
@@ -188,8 +367,10 @@ impl<T: Config> Pallet<T> {
 				input: request.input,
 				// Copied from:
 				// - https://github.com/rust-ethereum/ethereum/blob/24739cc8ba6e9d8ee30ada8ec92161e4c48d578e/src/transaction.rs#L798
+				//
+				// With the EIP-155 `v` of the current chain.
 				signature: TransactionSignature::new(
-					38,
+					chain_id * 2 + 36,
 					H256([
 						190, 103, 224, 160, 125, 182, 125, 168, 212, 70, 247, 106, 221, 89, 14, 84,
 						182, 233, 44, 182, 184, 249, 131, 90, 235, 103, 84, 5, 121, 162, 119, 23,
@@ -203,14 +384,14 @@ impl<T: Config> Pallet<T> {
 			}),
 			TxType::EIP2930Transaction => {
 				Transaction::EIP2930(EIP2930Transaction {
-					chain_id: 0,
-					nonce: who.nonce,
+					chain_id,
+					nonce,
 					gas_price: base_fee,
 					gas_limit: request.gas_limit,
 					action: request.action,
 					value: request.value,
 					input: request.input,
-					access_list: Default::default(),
+					access_list: request.access_list,
 					// Copied from:
 					// - https://github.com/rust-ethereum/ethereum/blob/24739cc8ba6e9d8ee30ada8ec92161e4c48d578e/src/transaction.rs#L873-L875
 					odd_y_parity: false,
@@ -227,16 +408,18 @@ impl<T: Config> Pallet<T> {
 				})
 			},
 			TxType::EIP1559Transaction => {
+				let max_priority_fee_per_gas = request.max_priority_fee_per_gas.unwrap_or_default();
+
 				Transaction::EIP1559(EIP1559Transaction {
-					chain_id: 0,
-					nonce: who.nonce,
-					max_fee_per_gas: base_fee,
-					max_priority_fee_per_gas: U256::zero(),
+					chain_id,
+					nonce,
+					max_fee_per_gas: base_fee.saturating_add(max_priority_fee_per_gas),
+					max_priority_fee_per_gas,
 					gas_limit: request.gas_limit,
 					action: request.action,
 					value: request.value,
 					input: request.input,
-					access_list: Default::default(),
+					access_list: request.access_list,
 					// Copied from:
 					// - https://github.com/rust-ethereum/ethereum/blob/24739cc8ba6e9d8ee30ada8ec92161e4c48d578e/src/transaction.rs#L873-L875
 					odd_y_parity: false,
@@ -270,7 +453,7 @@ impl<T: Config> Pallet<T> {
 				evm_config: T::config(),
 				block_gas_limit: T::BlockGasLimit::get(),
 				base_fee,
-				chain_id: T::ChainId::get(),
+				chain_id,
 				is_transactional: true,
 			},
 			transaction_data.into(),
@@ -394,6 +577,82 @@ impl From<TransactionValidationError> for TxErrorWrapper {
 	}
 }
 
+/// Maximum length of the message kept by [`RevertReason::Error`].
+pub const MAX_REVERT_MESSAGE_LEN: u32 = 192;
+
+/// Structured reason of a reverted EVM execution, carried by [`Event::Forwarded`].
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum RevertReason {
+	/// Reverted with `Error(string)` and this message, truncated to [`MAX_REVERT_MESSAGE_LEN`]
+	/// bytes.
+	Error(BoundedVec<u8, ConstU32<MAX_REVERT_MESSAGE_LEN>>),
+	/// Panicked with `Panic(uint256)` and this code.
+	Panic(u8),
+	/// Reverted with a custom error or without any data.
+	Custom,
+}
+impl RevertReason {
+	/// The kind of the revert reason, without the message.
+	pub fn kind(&self) -> RevertKind {
+		match self {
+			Self::Error(_) => RevertKind::Error,
+			Self::Panic(code) => RevertKind::Panic(*code),
+			Self::Custom => RevertKind::Custom,
+		}
+	}
+}
+impl From<Revert> for RevertReason {
+	fn from(revert: Revert) -> Self {
+		match revert {
+			Revert::Error(message) => Self::Error(BoundedVec::truncate_from(message.into_bytes())),
+			// All the Solidity panic codes fit in `u8`.
+			Revert::Panic(code) if code <= U256::from(u8::MAX) => Self::Panic(code.low_u32() as _),
+			_ => Self::Custom,
+		}
+	}
+}
+
+/// [`RevertReason`] without the message, carried by [`Error::Reverted`].
+///
+/// A pallet error is encoded in at most 4 bytes, the message does not fit in it. It is logged by
+/// [`Pallet::ensure_succeed`].
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, PalletError)]
+pub enum RevertKind {
+	/// Reverted with `Error(string)`.
+	Error,
+	/// Panicked with `Panic(uint256)` and this code.
+	Panic(u8),
+	/// Reverted with a custom error or without any data.
+	Custom,
+}
+
+fn transaction_action(transaction: &Transaction) -> &TransactionAction {
+	match transaction {
+		Transaction::Legacy(t) => &t.action,
+		Transaction::EIP2930(t) => &t.action,
+		Transaction::EIP1559(t) => &t.action,
+	}
+}
+
+/// Gas limit of a runtime-originated transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GasLimit {
+	/// Use the given gas limit as is.
+	Fixed(U256),
+	/// Dry-run the transaction first and use the estimated gas limit.
+	///
+	/// See [`Pallet::estimate_gas`].
+	Estimated {
+		/// The maximum gas limit the caller is willing to pay for.
+		budget: U256,
+	},
+}
+impl From<U256> for GasLimit {
+	fn from(gas_limit: U256) -> Self {
+		Self::Fixed(gas_limit)
+	}
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
 pub struct ForwardRequest {
 	pub tx_type: TxType,
@@ -401,32 +660,75 @@ pub struct ForwardRequest {
 	pub action: TransactionAction,
 	pub value: U256,
 	pub input: Vec<u8>,
+	/// EIP-2930 access list, ignored by the legacy transaction.
+	pub access_list: Vec<AccessListItem>,
+	/// EIP-1559 max priority fee per gas, ignored by the other transactions.
+	///
+	/// It is paid on top of the base fee.
+	pub max_priority_fee_per_gas: Option<U256>,
+	/// Use this nonce instead of the current nonce of the source.
+	///
+	/// The request is rejected unless it equals the current nonce of the source.
+	pub nonce: Option<U256>,
+}
+impl From<ForwardRequestV0> for ForwardRequest {
+	fn from(request: ForwardRequestV0) -> Self {
+		Self {
+			tx_type: request.tx_type,
+			gas_limit: request.gas_limit,
+			action: request.action,
+			value: request.value,
+			input: request.input,
+			access_list: Vec::new(),
+			max_priority_fee_per_gas: None,
+			nonce: None,
+		}
+	}
+}
+
+/// The initial [`ForwardRequest`] format, taken by [`Pallet::forward_transact`].
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct ForwardRequestV0 {
+	pub tx_type: TxType,
+	pub gas_limit: U256,
+	pub action: TransactionAction,
+	pub value: U256,
+	pub input: Vec<u8>,
 }
 
-pub fn quick_forward_transact<T>(
+pub fn quick_forward_transact<T, C>(
 	source: H160,
-	function: Function,
-	input: &[Token],
-	call: H160,
+	call: C,
+	to: H160,
 	value: U256,
-	gas_limit: U256,
+	gas_limit: GasLimit,
 ) -> Result<(PostDispatchInfo, CallOrCreateInfo), DispatchError>
 where
 	T: Config,
+	C: SolCall,
 {
-	log::info!("forwarding {function:?} to {call:?} with {{ input: {input:?}, value: {value:?} }}");
+	log::info!("forwarding {} to {to:?} with {{ call: {call:?}, value: {value:?} }}", C::SIGNATURE);
 
-	let input = function.encode_input(input).map_err(|e| {
-		log::error!("failed to encode input due to {e:?}");
+	let input = call.encode();
+	let gas_limit = match gas_limit {
+		GasLimit::Fixed(gas_limit) => gas_limit,
+		GasLimit::Estimated { budget } => {
+			let gas_limit = <Pallet<T>>::estimate_gas(source, to, input.clone(), value, budget)?;
 
-		DispatchError::from("failed to encode input")
-	})?;
+			log::info!("estimated gas limit {gas_limit} within the budget {budget}");
+
+			gas_limit
+		},
+	};
 	let req = ForwardRequest {
 		tx_type: TxType::LegacyTransaction,
-		action: TransactionAction::Call(call),
+		action: TransactionAction::Call(to),
 		value,
 		input,
 		gas_limit,
+		access_list: Vec::new(),
+		max_priority_fee_per_gas: None,
+		nonce: None,
 	};
 
 	<Pallet<T>>::forward_transact_inner(source, req).map_err(|e| e.error)
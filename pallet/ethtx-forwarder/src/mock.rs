@@ -94,8 +94,13 @@ impl pallet_ethereum::Config for Runtime {
 	type StateRoot = pallet_ethereum::IntermediateStateRoot<Self::Version>;
 }
 
+frame_support::parameter_types! {
+	pub const GasEstimationMargin: sp_runtime::Percent = sp_runtime::Percent::from_percent(20);
+}
 impl darwinia_ethtx_forwarder::Config for Runtime {
 	type ForwardEthOrigin = crate::EnsureRuntimeEthOrigin;
+	type GasEstimationMargin = GasEstimationMargin;
+	type RuntimeEvent = RuntimeEvent;
 	type ValidatedTransaction = pallet_ethereum::ValidatedTransaction<Self>;
 }
 
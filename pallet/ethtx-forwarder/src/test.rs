@@ -18,16 +18,24 @@
 
 // crates.io
 use array_bytes::Dehexify;
-use ethereum::TransactionSignature;
-use fp_evm::ExecutionInfoV2;
+use codec::{Decode, Encode};
+use ethereum::{AccessListItem, TransactionSignature, TransactionV2};
 // darwinia
 use crate::{
 	mock::{self, *},
-	ForwardEthOrigin, ForwardRequest, TxType,
+	quick_forward_transact, Error, Event, ForwardEthOrigin, ForwardRequest, ForwardRequestV0,
+	GasLimit, RevertKind, RevertReason, TxErrorWrapper, TxType,
 };
+use dc_abi::{
+	asset::{BalanceOfCall, TransferCall},
+	kton_dao_vault::DistributeRewardsCall,
+	Revert, SolCall,
+};
+// frontier
+use fp_evm::ExitReason;
 // polkadot-sdk
-use frame_support::{assert_err, assert_ok, traits::Currency};
-use sp_core::{H256, U256};
+use frame_support::{assert_err, assert_ok, traits::Currency, BoundedVec};
+use sp_core::{hashing::keccak_256, H256, U256};
 use sp_runtime::{DispatchError, ModuleError};
 
 // This ERC-20 contract mints the maximum amount of tokens to the contract creator.
@@ -38,8 +46,8 @@ use sp_runtime::{DispatchError, ModuleError};
 // }
 const ERC20_CONTRACT_BYTECODE: &str = "608060405234801561001057600080fd5b5061002433620f424061002960201b60201c565b610274565b600073ffffffffffffffffffffffffffffffffffffffff168273ffffffffffffffffffffffffffffffffffffffff1614156100cc576040517f08c379a000000000000000000000000000000000000000000000000000000000815260040180806020018281038252601f8152602001807f45524332303a206d696e7420746f20746865207a65726f20616464726573730081525060200191505060405180910390fd5b6100e5816002546101ec60201b610c7c1790919060201c565b600281905550610140816000808573ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff168152602001908152602001600020546101ec60201b610c7c1790919060201c565b6000808473ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff168152602001908152602001600020819055508173ffffffffffffffffffffffffffffffffffffffff16600073ffffffffffffffffffffffffffffffffffffffff167fddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef836040518082815260200191505060405180910390a35050565b60008082840190508381101561026a576040517f08c379a000000000000000000000000000000000000000000000000000000000815260040180806020018281038252601b8152602001807f536166654d6174683a206164646974696f6e206f766572666c6f77000000000081525060200191505060405180910390fd5b8091505092915050565b610e3a806102836000396000f3fe608060405234801561001057600080fd5b50600436106100885760003560e01c806370a082311161005b57806370a08231146101fd578063a457c2d714610255578063a9059cbb146102bb578063dd62ed3e1461032157610088565b8063095ea7b31461008d57806318160ddd146100f357806323b872dd146101115780633950935114610197575b600080fd5b6100d9600480360360408110156100a357600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff16906020019092919080359060200190929190505050610399565b604051808215151515815260200191505060405180910390f35b6100fb6103b7565b6040518082815260200191505060405180910390f35b61017d6004803603606081101561012757600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff169060200190929190803573ffffffffffffffffffffffffffffffffffffffff169060200190929190803590602001909291905050506103c1565b604051808215151515815260200191505060405180910390f35b6101e3600480360360408110156101ad57600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff1690602001909291908035906020019092919050505061049a565b604051808215151515815260200191505060405180910390f35b61023f6004803603602081101561021357600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff16906020019092919050505061054d565b6040518082815260200191505060405180910390f35b6102a16004803603604081101561026b57600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff16906020019092919080359060200190929190505050610595565b604051808215151515815260200191505060405180910390f35b610307600480360360408110156102d157600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff16906020019092919080359060200190929190505050610662565b604051808215151515815260200191505060405180910390f35b6103836004803603604081101561033757600080fd5b81019080803573ffffffffffffffffffffffffffffffffffffffff169060200190929190803573ffffffffffffffffffffffffffffffffffffffff169060200190929190505050610680565b6040518082815260200191505060405180910390f35b60006103ad6103a6610707565b848461070f565b6001905092915050565b6000600254905090565b60006103ce848484610906565b61048f846103da610707565b61048a85604051806060016040528060288152602001610d7060289139600160008b73ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff1681526020019081526020016000206000610440610707565b73ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002054610bbc9092919063ffffffff16565b61070f565b600190509392505050565b60006105436104a7610707565b8461053e85600160006104b8610707565b73ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002060008973ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002054610c7c90919063ffffffff16565b61070f565b6001905092915050565b60008060008373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff168152602001908152602001600020549050919050565b60006106586105a2610707565b8461065385604051806060016040528060258152602001610de160259139600160006105cc610707565b73ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002060008a73ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002054610bbc9092919063ffffffff16565b61070f565b6001905092915050565b600061067661066f610707565b8484610906565b6001905092915050565b6000600160008473ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002060008373ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002054905092915050565b600033905090565b600073ffffffffffffffffffffffffffffffffffffffff168373ffffffffffffffffffffffffffffffffffffffff161415610795576040517f08c379a0000000000000000000000000000000000000000000000000000000008152600401808060200182810382526024815260200180610dbd6024913960400191505060405180910390fd5b600073ffffffffffffffffffffffffffffffffffffffff168273ffffffffffffffffffffffffffffffffffffffff16141561081b576040517f08c379a0000000000000000000000000000000000000000000000000000000008152600401808060200182810382526022815260200180610d286022913960400191505060405180910390fd5b80600160008573ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002060008473ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff168152602001908152602001600020819055508173ffffffffffffffffffffffffffffffffffffffff168373ffffffffffffffffffffffffffffffffffffffff167f8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925836040518082815260200191505060405180910390a3505050565b600073ffffffffffffffffffffffffffffffffffffffff168373ffffffffffffffffffffffffffffffffffffffff16141561098c576040517f08c379a0000000000000000000000000000000000000000000000000000000008152600401808060200182810382526025815260200180610d986025913960400191505060405180910390fd5b600073ffffffffffffffffffffffffffffffffffffffff168273ffffffffffffffffffffffffffffffffffffffff161415610a12576040517f08c379a0000000000000000000000000000000000000000000000000000000008152600401808060200182810382526023815260200180610d056023913960400191505060405180910390fd5b610a7d81604051806060016040528060268152602001610d4a602691396000808773ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002054610bbc9092919063ffffffff16565b6000808573ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002081905550610b10816000808573ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff16815260200190815260200160002054610c7c90919063ffffffff16565b6000808473ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff168152602001908152602001600020819055508173ffffffffffffffffffffffffffffffffffffffff168373ffffffffffffffffffffffffffffffffffffffff167fddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef836040518082815260200191505060405180910390a3505050565b6000838311158290610c69576040517f08c379a00000000000000000000000000000000000000000000000000000000081526004018080602001828103825283818151815260200191508051906020019080838360005b83811015610c2e578082015181840152602081019050610c13565b50505050905090810190601f168015610c5b5780820380516001836020036101000a031916815260200191505b509250505060405180910390fd5b5060008385039050809150509392505050565b600080828401905083811015610cfa576040517f08c379a000000000000000000000000000000000000000000000000000000000815260040180806020018281038252601b8152602001807f536166654d6174683a206164646974696f6e206f766572666c6f77000000000081525060200191505060405180910390fd5b809150509291505056fe45524332303a207472616e7366657220746f20746865207a65726f206164647265737345524332303a20617070726f766520746f20746865207a65726f206164647265737345524332303a207472616e7366657220616d6f756e7420657863656564732062616c616e636545524332303a207472616e7366657220616d6f756e74206578636565647320616c6c6f77616e636545524332303a207472616e736665722066726f6d20746865207a65726f206164647265737345524332303a20617070726f76652066726f6d20746865207a65726f206164647265737345524332303a2064656372656173656420616c6c6f77616e63652062656c6f77207a65726fa265627a7a723158204b72fba02adebd751a8e173005e094f50807c69fde771436ef2cb081650def3c64736f6c63430005110032";
 
-fn mocked_request() -> ForwardRequest {
-	ForwardRequest {
+fn mocked_request() -> ForwardRequestV0 {
+	ForwardRequestV0 {
 		tx_type: TxType::default(),
 		gas_limit: U256::from(1_000_000),
 		action: ethereum::TransactionAction::Create,
@@ -48,6 +56,10 @@ fn mocked_request() -> ForwardRequest {
 	}
 }
 
+fn mocked_request_v1() -> ForwardRequest {
+	mocked_request().into()
+}
+
 #[test]
 fn forward_request_works() {
 	let alice = address_build(1);
@@ -76,6 +88,34 @@ fn forward_request_works() {
 		});
 }
 
+#[test]
+fn forward_request_v1_works() {
+	let alice = address_build(1);
+	ExtBuilder::default()
+		.with_balances(vec![(alice.address, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			macro_rules! test_tx_types {
+				($tx_type:expr) => {
+					let mut request = mocked_request_v1();
+					request.tx_type = $tx_type;
+
+					assert_ok!(EthTxForwarder::forward_transact_v1(
+						ForwardEthOrigin::ForwardEth(alice.address).into(),
+						request,
+					));
+					assert!(System::events()
+						.iter()
+						.any(|record| matches!(record.event, RuntimeEvent::Ethereum(..))));
+				};
+			}
+
+			test_tx_types!(TxType::LegacyTransaction);
+			test_tx_types!(TxType::EIP1559Transaction);
+			test_tx_types!(TxType::EIP2930Transaction);
+		});
+}
+
 #[test]
 fn forward_request_sufficient_balance() {
 	let alice = address_build(1);
@@ -97,7 +137,7 @@ fn forward_request_sufficient_balance() {
 					})
 				);
 
-				let fee = mock::total_payment(&request);
+				let fee = mock::total_payment(&request.clone().into());
 				let _ = Balances::deposit_creating(&alice.address, fee.as_u64());
 				assert_ok!(EthTxForwarder::forward_transact(
 					ForwardEthOrigin::ForwardEth(alice.address).into(),
@@ -114,6 +154,44 @@ fn forward_request_sufficient_balance() {
 	});
 }
 
+#[test]
+fn forward_request_v1_sufficient_balance() {
+	let alice = address_build(1);
+	ExtBuilder::default().build().execute_with(|| {
+		macro_rules! test_tx_types {
+			($tx_type:expr) => {
+				let mut request = mocked_request_v1();
+				request.tx_type = $tx_type;
+
+				assert_err!(
+					EthTxForwarder::forward_transact_v1(
+						ForwardEthOrigin::ForwardEth(alice.address).into(),
+						request.clone()
+					),
+					DispatchError::Module(ModuleError {
+						index: 5,
+						error: [0, 4, 0, 0],
+						message: Some("ValidationError",)
+					})
+				);
+
+				let fee = mock::total_payment(&request);
+				let _ = Balances::deposit_creating(&alice.address, fee.as_u64());
+				assert_ok!(EthTxForwarder::forward_transact_v1(
+					ForwardEthOrigin::ForwardEth(alice.address).into(),
+					request
+				));
+				assert!(System::events()
+					.iter()
+					.any(|record| matches!(record.event, RuntimeEvent::Ethereum(..))));
+			};
+		}
+		test_tx_types!(TxType::LegacyTransaction);
+		test_tx_types!(TxType::EIP1559Transaction);
+		test_tx_types!(TxType::EIP2930Transaction);
+	});
+}
+
 #[test]
 fn foraward_call_works() {
 	let alice = address_build(1);
@@ -129,37 +207,295 @@ fn foraward_call_works() {
 			let pallet_ethereum::Event::Executed { to, .. } =
 				System::read_events_for_pallet().into_iter().next().expect("events expected");
 
-			use ethabi::{Function, Param, ParamType, Token};
-			#[allow(deprecated)]
-			let function = Function {
-				name: "balanceOf".to_owned(),
-				inputs: vec![Param {
-					name: "account".to_owned(),
-					kind: ParamType::Address,
-					internal_type: None,
-				}],
-				outputs: vec![Param {
-					name: "balance".to_owned(),
-					kind: ParamType::Uint(256),
-					internal_type: None,
-				}],
-				constant: None,
-				state_mutability: ethabi::StateMutability::View,
-			};
-			let input = function.encode_input(&[Token::Address(alice.address)]).unwrap();
-			let result = EthTxForwarder::forward_call(
+			let balance = EthTxForwarder::forward_call(
 				alice.address,
 				to,
-				input,
+				BalanceOfCall { who: alice.address },
 				Default::default(),
 				U256::from(10_000_000u64),
 			)
 			.expect("call should succeed");
-			let ExecutionInfoV2 { value, .. } = result;
-			assert_eq!(U256::from_big_endian(&value), U256::from(1000000));
+			assert_eq!(balance, U256::from(1000000));
 		});
 }
 
+#[test]
+fn estimated_gas_limit_should_work() {
+	let alice = address_build(1);
+	let bob = address_build(2);
+	ExtBuilder::default()
+		.with_balances(vec![(alice.address, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(EthTxForwarder::forward_transact_v1(
+				ForwardEthOrigin::ForwardEth(alice.address).into(),
+				mocked_request_v1(),
+			));
+			let pallet_ethereum::Event::Executed { to, .. } =
+				System::read_events_for_pallet().into_iter().next().expect("events expected");
+			let balance_of = |who| {
+				EthTxForwarder::forward_call(
+					alice.address,
+					to,
+					BalanceOfCall { who },
+					Default::default(),
+					U256::from(10_000_000u64),
+				)
+				.unwrap()
+			};
+			let transfer = TransferCall { to: bob.address, amount: 1.into() };
+
+			// The dry run does not change the state.
+			let gas_limit = EthTxForwarder::estimate_gas(
+				alice.address,
+				to,
+				transfer.clone().encode(),
+				U256::zero(),
+				U256::from(1_000_000),
+			)
+			.unwrap();
+			assert!(gas_limit > U256::from(21_000));
+			assert_eq!(balance_of(bob.address), U256::zero());
+
+			assert_eq!(
+				quick_forward_transact::<Runtime, _>(
+					alice.address,
+					transfer.clone(),
+					to,
+					U256::zero(),
+					GasLimit::Estimated { budget: U256::from(30_000) },
+				)
+				.map(|_| ()),
+				Err(<Error<Runtime>>::GasBudgetExceeded.into())
+			);
+			assert_eq!(
+				quick_forward_transact::<Runtime, _>(
+					alice.address,
+					transfer.clone(),
+					to,
+					U256::zero(),
+					GasLimit::Estimated { budget: gas_limit - 1 },
+				)
+				.map(|_| ()),
+				Err(<Error<Runtime>>::GasBudgetExceeded.into())
+			);
+			assert_eq!(balance_of(bob.address), U256::zero());
+
+			assert_ok!(quick_forward_transact::<Runtime, _>(
+				alice.address,
+				transfer,
+				to,
+				U256::zero(),
+				GasLimit::Estimated { budget: gas_limit },
+			));
+			assert_eq!(balance_of(bob.address), U256::one());
+		});
+}
+
+#[test]
+fn forwarded_transaction_should_carry_request_fields() {
+	let alice = address_build(1);
+	ExtBuilder::default()
+		.with_balances(vec![(alice.address, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			let access_list = vec![AccessListItem {
+				address: alice.address,
+				storage_keys: vec![H256::repeat_byte(1)],
+			}];
+			let request = ForwardRequest {
+				tx_type: TxType::EIP1559Transaction,
+				access_list: access_list.clone(),
+				max_priority_fee_per_gas: Some(U256::one()),
+				nonce: Some(U256::zero()),
+				..mocked_request_v1()
+			};
+			let Ok(TransactionV2::EIP1559(tx)) =
+				EthTxForwarder::validated_transaction(alice.address, request)
+			else {
+				panic!("EIP-1559 transaction expected");
+			};
+
+			assert_eq!(tx.chain_id, 888);
+			assert_eq!(tx.access_list, access_list);
+			assert_eq!(tx.max_priority_fee_per_gas, U256::one());
+			assert_eq!(tx.max_fee_per_gas, U256::from(6));
+
+			let request = ForwardRequest {
+				tx_type: TxType::EIP2930Transaction,
+				access_list: access_list.clone(),
+				..mocked_request_v1()
+			};
+			let Ok(TransactionV2::EIP2930(tx)) =
+				EthTxForwarder::validated_transaction(alice.address, request)
+			else {
+				panic!("EIP-2930 transaction expected");
+			};
+
+			assert_eq!(tx.chain_id, 888);
+			assert_eq!(tx.access_list, access_list);
+
+			let Ok(TransactionV2::Legacy(tx)) =
+				EthTxForwarder::validated_transaction(alice.address, mocked_request_v1())
+			else {
+				panic!("legacy transaction expected");
+			};
+
+			assert_eq!(tx.signature.chain_id(), Some(888));
+
+			assert_eq!(
+				EthTxForwarder::validated_transaction(
+					alice.address,
+					ForwardRequest { nonce: Some(U256::one()), ..mocked_request_v1() }
+				)
+				.map(|_| ()),
+				Err(<Error<Runtime>>::ValidationError(TxErrorWrapper::TxNonceTooHigh).into())
+			);
+		});
+}
+
+#[test]
+fn forward_transact_should_accept_the_initial_request() {
+	let alice = address_build(1);
+	ExtBuilder::default()
+		.with_balances(vec![(alice.address, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			let request = ForwardRequestV0 {
+				tx_type: TxType::EIP1559Transaction,
+				gas_limit: U256::from(1_000_000),
+				action: ethereum::TransactionAction::Create,
+				value: U256::zero(),
+				input: <Vec<u8>>::dehexify(ERC20_CONTRACT_BYTECODE).unwrap(),
+			};
+			// The call index and the request layout are the ones the initial call used.
+			let encoded = [vec![0], request.encode()].concat();
+
+			assert_eq!(
+				crate::Call::<Runtime>::decode(&mut &*encoded).unwrap(),
+				crate::Call::<Runtime>::forward_transact { request: request.clone() }
+			);
+			assert_eq!(
+				ForwardRequest::from(request.clone()),
+				ForwardRequest {
+					tx_type: request.tx_type,
+					gas_limit: request.gas_limit,
+					action: request.action.clone(),
+					value: request.value,
+					input: request.input.clone(),
+					access_list: Vec::new(),
+					max_priority_fee_per_gas: None,
+					nonce: None,
+				}
+			);
+
+			assert_ok!(EthTxForwarder::forward_transact(
+				ForwardEthOrigin::ForwardEth(alice.address).into(),
+				request,
+			));
+			assert!(System::events()
+				.iter()
+				.any(|record| matches!(record.event, RuntimeEvent::Ethereum(..))));
+		});
+}
+
+#[test]
+fn forwarded_event_and_revert_reason_should_work() {
+	let alice = address_build(1);
+	let bob = address_build(2);
+	ExtBuilder::default()
+		.with_balances(vec![(alice.address, 1_000_000_000_000), (bob.address, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(EthTxForwarder::forward_transact_v1(
+				ForwardEthOrigin::ForwardEth(alice.address).into(),
+				mocked_request_v1(),
+			));
+			let pallet_ethereum::Event::Executed { to, .. } =
+				System::read_events_for_pallet().into_iter().next().expect("events expected");
+			let Some(Event::Forwarded {
+				source,
+				target,
+				exit_reason,
+				return_data_hash,
+				revert_reason,
+				..
+			}) = System::read_events_for_pallet::<Event<Runtime>>().into_iter().next()
+			else {
+				panic!("forwarded event expected");
+			};
+
+			assert_eq!(source, alice.address);
+			assert_eq!(target, to);
+			assert!(matches!(exit_reason, ExitReason::Succeed(_)));
+			assert_eq!(return_data_hash, H256(keccak_256(&[])));
+			assert_eq!(revert_reason, None);
+
+			// The reverted transaction is still included, with the revert reason in the event.
+			System::reset_events();
+			assert_ok!(EthTxForwarder::forward_transact_v1(
+				ForwardEthOrigin::ForwardEth(bob.address).into(),
+				ForwardRequest {
+					action: ethereum::TransactionAction::Call(to),
+					input: TransferCall { to: alice.address, amount: 1.into() }.encode(),
+					..mocked_request_v1()
+				},
+			));
+			let Some(Event::Forwarded { exit_reason, revert_reason, .. }) =
+				System::read_events_for_pallet::<Event<Runtime>>().into_iter().next()
+			else {
+				panic!("forwarded event expected");
+			};
+
+			assert!(matches!(exit_reason, ExitReason::Revert(_)));
+			assert_eq!(
+				revert_reason,
+				Some(RevertReason::Error(BoundedVec::truncate_from(
+					b"ERC20: transfer amount exceeds balance".to_vec()
+				)))
+			);
+
+			// Reverted with `Error(string)`.
+			assert_eq!(
+				EthTxForwarder::forward_call(
+					bob.address,
+					to,
+					TransferCall { to: alice.address, amount: 1.into() },
+					Default::default(),
+					U256::from(10_000_000u64),
+				),
+				Err(<Error<Runtime>>::Reverted(RevertKind::Error).into())
+			);
+			// Reverted without any data.
+			assert_eq!(
+				EthTxForwarder::forward_call(
+					alice.address,
+					to,
+					DistributeRewardsCall,
+					Default::default(),
+					U256::from(10_000_000u64),
+				),
+				Err(<Error<Runtime>>::Reverted(RevertKind::Custom).into())
+			);
+		});
+}
+
+#[test]
+fn revert_reason_should_work() {
+	assert_eq!(
+		RevertReason::from(Revert::Error("reason".into())),
+		RevertReason::Error(BoundedVec::truncate_from(b"reason".to_vec()))
+	);
+	assert_eq!(
+		RevertReason::from(Revert::Error("r".repeat(256))),
+		RevertReason::Error(BoundedVec::truncate_from(vec![b'r'; 192]))
+	);
+	assert_eq!(RevertReason::from(Revert::Error("reason".into())).kind(), RevertKind::Error);
+	assert_eq!(RevertReason::from(Revert::Panic(0x11.into())), RevertReason::Panic(0x11));
+	assert_eq!(RevertReason::from(Revert::Panic(256.into())), RevertReason::Custom);
+	assert_eq!(RevertReason::from(Revert::Custom(Vec::new())), RevertReason::Custom);
+}
+
 #[test]
 fn mock_signature_valid() {
 	assert!(
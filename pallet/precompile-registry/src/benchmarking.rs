@@ -0,0 +1,76 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// darwinia
+use crate::*;
+// polkadot-sdk
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+#[v2::benchmarks]
+mod benchmarks {
+	// darwinia
+	use super::*;
+
+	fn kind<T: Config>() -> T::PrecompileKind {
+		T::DefaultPrecompiles::get().first().expect("at least one default precompile").1
+	}
+
+	#[benchmark]
+	fn register() {
+		let a = H160::from_low_u64_be(u32::MAX as _);
+
+		// Worst-case scenario:
+		//
+		// Register successfully.
+		#[extrinsic_call]
+		_(RawOrigin::Root, a, kind::<T>());
+	}
+
+	#[benchmark]
+	fn unregister() {
+		let a = H160::from_low_u64_be(u32::MAX as _);
+
+		<Pallet<T>>::do_register(a, kind::<T>()).unwrap();
+
+		// Worst-case scenario:
+		//
+		// Unregister successfully.
+		#[extrinsic_call]
+		_(RawOrigin::Root, a);
+	}
+
+	#[benchmark]
+	fn set_enabled() {
+		let a = H160::from_low_u64_be(u32::MAX as _);
+
+		<Pallet<T>>::do_register(a, kind::<T>()).unwrap();
+
+		// Worst-case scenario:
+		//
+		// Disable successfully.
+		#[extrinsic_call]
+		_(RawOrigin::Root, a, false);
+	}
+
+	frame_benchmarking::impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Runtime
+	);
+}
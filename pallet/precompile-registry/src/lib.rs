@@ -0,0 +1,250 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Darwinia precompile registry pallet
+//!
+//! ## Overview
+//!
+//! Map the EVM addresses to the precompile kinds of the runtime, so that the precompiles can be
+//! added, disabled and enabled by the governance without a runtime upgrade.
+//!
+//! The EVM code stubs of the registered precompiles are kept in sync with the registry, which
+//! makes `EXTCODESIZE` of them non-zero and reverts any direct code execution.
+//!
+//! Only the addresses whose first 12 bytes are zero can be registered. The runtimes skip the
+//! registry lookup for any other address.
+//!
+//! [`Config::DefaultPrecompiles`] are registered at genesis. A live chain registers them once
+//! through [`Pallet::register_defaults`] in the runtime upgrade which adds the pallet, and any later
+//! change to the defaults must ship with its own migration.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![deny(missing_docs)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod weights;
+pub use weights::WeightInfo;
+
+// polkadot-sdk
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use sp_core::H160;
+use sp_std::prelude::*;
+
+/// EVM code stub of the registered precompiles.
+///
+/// `PUSH1 0x00 PUSH1 0x00 REVERT`
+pub const REVERT_BYTECODE: [u8; 5] = [0x60, 0x00, 0x60, 0x00, 0xFD];
+
+/// Registered precompile.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct PrecompileEntry<Kind> {
+	/// Kind of the precompile.
+	pub kind: Kind,
+	/// Whether the precompile is served.
+	pub enabled: bool,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	// darwinia
+	use crate::*;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_evm::Config {
+		/// Override the [`frame_system::Config::RuntimeEvent`].
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for extrinsic in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Precompile kinds of the runtime.
+		type PrecompileKind: Parameter + Member + Copy + MaxEncodedLen;
+
+		/// Precompiles registered at genesis or by [`Pallet::register_defaults`].
+		type DefaultPrecompiles: Get<Vec<(H160, Self::PrecompileKind)>>;
+
+		/// Origin allowed to manage the registry.
+		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+	}
+
+	#[allow(missing_docs)]
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A precompile has been registered.
+		PrecompileRegistered { address: H160, kind: T::PrecompileKind },
+		/// A precompile has been unregistered.
+		PrecompileUnregistered { address: H160 },
+		/// A precompile has been enabled or disabled.
+		PrecompileStatusChanged { address: H160, enabled: bool },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Address is out of the precompile address space.
+		InvalidAddress,
+		/// Precompile is already registered.
+		AlreadyRegistered,
+		/// Precompile is not registered.
+		NotRegistered,
+	}
+
+	/// Registered precompiles.
+	#[pallet::storage]
+	pub type Precompiles<T: Config> =
+		StorageMap<_, Twox64Concat, H160, PrecompileEntry<T::PrecompileKind>>;
+
+	#[derive(frame_support::DefaultNoBound)]
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		#[allow(missing_docs)]
+		pub _marker: PhantomData<T>,
+	}
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			<Pallet<T>>::register_defaults();
+		}
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a precompile and install its code stub.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::register())]
+		pub fn register(
+			origin: OriginFor<T>,
+			address: H160,
+			kind: T::PrecompileKind,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			Self::do_register(address, kind)
+		}
+
+		/// Unregister a precompile and remove its code stub.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::unregister())]
+		pub fn unregister(origin: OriginFor<T>, address: H160) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			Self::do_unregister(address)
+		}
+
+		/// Enable or disable a precompile.
+		///
+		/// The code stub of a disabled precompile is kept, so that the address can not be taken
+		/// by anything else.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_enabled())]
+		pub fn set_enabled(origin: OriginFor<T>, address: H160, enabled: bool) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			<Precompiles<T>>::try_mutate(address, |maybe_entry| {
+				let entry = maybe_entry.as_mut().ok_or(<Error<T>>::NotRegistered)?;
+
+				entry.enabled = enabled;
+
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::PrecompileStatusChanged { address, enabled });
+
+			Ok(())
+		}
+	}
+	impl<T: Config> Pallet<T> {
+		/// Whether the address is in the precompile address space.
+		pub fn is_precompile_address(address: &H160) -> bool {
+			address.0[..12] == [0; 12] && !address.is_zero()
+		}
+
+		/// Kind of the enabled precompile at the address.
+		pub fn precompile_of(address: &H160) -> Option<T::PrecompileKind> {
+			if !Self::is_precompile_address(address) {
+				return None;
+			}
+
+			<Precompiles<T>>::get(address).filter(|e| e.enabled).map(|e| e.kind)
+		}
+
+		/// Register a precompile and install its code stub.
+		pub fn do_register(address: H160, kind: T::PrecompileKind) -> DispatchResult {
+			ensure!(Self::is_precompile_address(&address), <Error<T>>::InvalidAddress);
+			ensure!(!<Precompiles<T>>::contains_key(address), <Error<T>>::AlreadyRegistered);
+
+			<Precompiles<T>>::insert(address, PrecompileEntry { kind, enabled: true });
+			<pallet_evm::AccountCodesMetadata<T>>::remove(address);
+			<pallet_evm::AccountCodes<T>>::insert(address, REVERT_BYTECODE.to_vec());
+
+			Self::deposit_event(Event::PrecompileRegistered { address, kind });
+
+			Ok(())
+		}
+
+		/// Unregister a precompile and remove its code stub.
+		pub fn do_unregister(address: H160) -> DispatchResult {
+			<Precompiles<T>>::take(address).ok_or(<Error<T>>::NotRegistered)?;
+			<pallet_evm::AccountCodesMetadata<T>>::remove(address);
+			<pallet_evm::AccountCodes<T>>::remove(address);
+
+			Self::deposit_event(Event::PrecompileUnregistered { address });
+
+			Ok(())
+		}
+
+		/// Register [`Config::DefaultPrecompiles`], skip the registered ones.
+		///
+		/// Return the consumed weight.
+		pub fn register_defaults() -> Weight {
+			let defaults = T::DefaultPrecompiles::get();
+			let count = defaults.len() as u64;
+
+			defaults.into_iter().for_each(|(address, kind)| {
+				if <Precompiles<T>>::contains_key(address) {
+					return;
+				}
+				if let Err(e) = Self::do_register(address, kind) {
+					log::error!("failed to register precompile {address:?} due to {e:?}");
+				}
+			});
+
+			T::DbWeight::get().reads_writes(count, count * 3)
+		}
+
+		/// Max encoded length of a [`Self::precompile_of`] lookup.
+		pub fn lookup_max_encoded_len() -> usize {
+			<PrecompileEntry<T::PrecompileKind>>::max_encoded_len()
+		}
+	}
+}
+pub use pallet::*;
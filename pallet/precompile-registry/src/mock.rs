@@ -0,0 +1,119 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// crates.io
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+// darwinia
+use crate::*;
+// polkadot-sdk
+use frame_support::derive_impl;
+use sp_io::TestExternalities;
+use sp_runtime::BuildStorage;
+
+pub type Balance = u128;
+
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum Kind {
+	Official,
+	Custom,
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type AccountId = H160;
+	type Block = frame_system::mocking::MockBlock<Self>;
+	type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+}
+
+#[derive_impl(pallet_timestamp::config_preludes::TestDefaultConfig as pallet_timestamp::DefaultConfig)]
+impl pallet_timestamp::Config for Runtime {}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig as pallet_balances::DefaultConfig)]
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+	type Balance = Balance;
+	type ExistentialDeposit = ();
+}
+
+frame_support::parameter_types! {
+	pub const BlockGasLimit: sp_core::U256 = sp_core::U256::MAX;
+	pub const WeightPerGas: Weight = Weight::from_parts(20_000, 0);
+}
+impl pallet_evm::Config for Runtime {
+	type AccountProvider = pallet_evm::FrameSystemAccountProvider<Self>;
+	type AddressMapping = pallet_evm::IdentityAddressMapping;
+	type BlockGasLimit = BlockGasLimit;
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type CallOrigin = pallet_evm::EnsureAddressRoot<H160>;
+	type ChainId = frame_support::traits::ConstU64<42>;
+	type Currency = Balances;
+	type FeeCalculator = ();
+	type FindAuthor = ();
+	type GasLimitPovSizeRatio = ();
+	type GasLimitStorageGrowthRatio = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type OnChargeTransaction = ();
+	type OnCreate = ();
+	type PrecompilesType = ();
+	type PrecompilesValue = ();
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type RuntimeEvent = RuntimeEvent;
+	type Timestamp = Timestamp;
+	type WeightInfo = ();
+	type WeightPerGas = WeightPerGas;
+	type WithdrawOrigin = pallet_evm::EnsureAddressNever<H160>;
+}
+
+frame_support::parameter_types! {
+	pub DefaultPrecompiles: Vec<(H160, Kind)> = (1..=9).map(|i| (H160::from_low_u64_be(i), Kind::Official)).collect();
+}
+impl crate::Config for Runtime {
+	type DefaultPrecompiles = DefaultPrecompiles;
+	type ManagerOrigin = frame_system::EnsureRoot<H160>;
+	type PrecompileKind = Kind;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+}
+
+frame_support::construct_runtime! {
+	pub enum Runtime {
+		System: frame_system,
+		Timestamp: pallet_timestamp,
+		Balances: pallet_balances,
+		EVM: pallet_evm,
+		PrecompileRegistry: crate,
+	}
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let mut storage = <frame_system::GenesisConfig<Runtime>>::default().build_storage().unwrap();
+
+	crate::GenesisConfig::<Runtime>::default().assimilate_storage(&mut storage).unwrap();
+
+	let mut ext = TestExternalities::new(storage);
+
+	ext.execute_with(|| System::set_block_number(1));
+
+	ext
+}
+
+pub fn events() -> Vec<Event<Runtime>> {
+	System::read_events_for_pallet()
+}
@@ -0,0 +1,143 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// darwinia
+use crate::{mock::*, *};
+// polkadot-sdk
+use frame_support::{assert_noop, assert_ok, traits::GetStorageVersion};
+use sp_runtime::DispatchError;
+
+fn code_of(address: H160) -> Vec<u8> {
+	<pallet_evm::AccountCodes<Runtime>>::get(address)
+}
+
+#[test]
+fn genesis_should_work() {
+	new_test_ext().execute_with(|| {
+		(1..=9).map(H160::from_low_u64_be).for_each(|a| {
+			assert_eq!(PrecompileRegistry::precompile_of(&a), Some(Kind::Official));
+			assert_eq!(code_of(a), REVERT_BYTECODE.to_vec());
+		});
+
+		assert_eq!(PrecompileRegistry::precompile_of(&H160::from_low_u64_be(10)), None);
+		assert_eq!(PrecompileRegistry::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn register_defaults_should_work() {
+	new_test_ext().execute_with(|| {
+		let a = H160::from_low_u64_be(1);
+
+		let _ = <Precompiles<Runtime>>::clear(u32::MAX, None);
+
+		PrecompileRegistry::register_defaults();
+
+		assert_eq!(<Precompiles<Runtime>>::iter().count(), 9);
+		assert_eq!(PrecompileRegistry::precompile_of(&a), Some(Kind::Official));
+
+		// Idempotent, the registered ones are left as they are.
+		assert_ok!(PrecompileRegistry::set_enabled(RuntimeOrigin::root(), a, false));
+
+		PrecompileRegistry::register_defaults();
+
+		assert_eq!(<Precompiles<Runtime>>::iter().count(), 9);
+		assert_eq!(PrecompileRegistry::precompile_of(&a), None);
+	});
+}
+
+#[test]
+fn register_should_work() {
+	new_test_ext().execute_with(|| {
+		let a = H160::from_low_u64_be(0x402);
+
+		assert_noop!(
+			PrecompileRegistry::register(RuntimeOrigin::signed(a), a, Kind::Custom),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			PrecompileRegistry::register(RuntimeOrigin::root(), H160::zero(), Kind::Custom),
+			<Error<Runtime>>::InvalidAddress
+		);
+		assert_noop!(
+			PrecompileRegistry::register(RuntimeOrigin::root(), H160::repeat_byte(1), Kind::Custom),
+			<Error<Runtime>>::InvalidAddress
+		);
+		assert_noop!(
+			PrecompileRegistry::register(
+				RuntimeOrigin::root(),
+				H160::from_low_u64_be(1),
+				Kind::Custom
+			),
+			<Error<Runtime>>::AlreadyRegistered
+		);
+
+		assert_ok!(PrecompileRegistry::register(RuntimeOrigin::root(), a, Kind::Custom));
+		assert_eq!(PrecompileRegistry::precompile_of(&a), Some(Kind::Custom));
+		assert_eq!(code_of(a), REVERT_BYTECODE.to_vec());
+		assert_eq!(events(), vec![Event::PrecompileRegistered { address: a, kind: Kind::Custom }]);
+	});
+}
+
+#[test]
+fn unregister_should_work() {
+	new_test_ext().execute_with(|| {
+		let a = H160::from_low_u64_be(1);
+
+		assert_noop!(
+			PrecompileRegistry::unregister(RuntimeOrigin::root(), H160::from_low_u64_be(10)),
+			<Error<Runtime>>::NotRegistered
+		);
+
+		assert_ok!(PrecompileRegistry::unregister(RuntimeOrigin::root(), a));
+		assert_eq!(PrecompileRegistry::precompile_of(&a), None);
+		assert!(code_of(a).is_empty());
+		assert_eq!(events(), vec![Event::PrecompileUnregistered { address: a }]);
+	});
+}
+
+#[test]
+fn set_enabled_should_work() {
+	new_test_ext().execute_with(|| {
+		let a = H160::from_low_u64_be(1);
+
+		assert_noop!(
+			PrecompileRegistry::set_enabled(
+				RuntimeOrigin::root(),
+				H160::from_low_u64_be(10),
+				false
+			),
+			<Error<Runtime>>::NotRegistered
+		);
+
+		assert_ok!(PrecompileRegistry::set_enabled(RuntimeOrigin::root(), a, false));
+		assert_eq!(PrecompileRegistry::precompile_of(&a), None);
+		// The code stub is kept.
+		assert_eq!(code_of(a), REVERT_BYTECODE.to_vec());
+
+		assert_ok!(PrecompileRegistry::set_enabled(RuntimeOrigin::root(), a, true));
+		assert_eq!(PrecompileRegistry::precompile_of(&a), Some(Kind::Official));
+		assert_eq!(
+			events(),
+			vec![
+				Event::PrecompileStatusChanged { address: a, enabled: false },
+				Event::PrecompileStatusChanged { address: a, enabled: true }
+			]
+		);
+	});
+}
@@ -0,0 +1,153 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `darwinia_precompile_registry`
+//!
+//! THESE WEIGHTS ARE NOT MEASURED. They are estimated by hand from the storage accesses of the
+//! benchmarks, regenerate this file with the benchmark CLI before the release.
+
+// Regenerate with:
+// target/release/darwinia
+// benchmark
+// pallet
+// --header
+// .maintain/license-header
+// --template
+// .maintain/pallet-weight-template.hbs
+// --heap-pages
+// 4096
+// --chain
+// koi-dev
+// --output
+// pallet/precompile-registry/src/weights.rs
+// --extrinsic
+// *
+// --pallet
+// darwinia-precompile-registry
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(missing_docs)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for darwinia_precompile_registry.
+pub trait WeightInfo {
+	fn register() -> Weight;
+	fn unregister() -> Weight;
+	fn set_enabled() -> Weight;
+}
+
+/// Weights for darwinia_precompile_registry using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `PrecompileRegistry::Precompiles` (r:1 w:1)
+	/// Proof: `PrecompileRegistry::Precompiles` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:0 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn register() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3507`
+		// Minimum execution time: 9_000 nanoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(3507, 0))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PrecompileRegistry::Precompiles` (r:1 w:1)
+	/// Proof: `PrecompileRegistry::Precompiles` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:0 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn unregister() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `123`
+		//  Estimated: `3507`
+		// Minimum execution time: 10_000 nanoseconds.
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(Weight::from_parts(3507, 0))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PrecompileRegistry::Precompiles` (r:1 w:1)
+	/// Proof: `PrecompileRegistry::Precompiles` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn set_enabled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `123`
+		//  Estimated: `3507`
+		// Minimum execution time: 6_000 nanoseconds.
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(Weight::from_parts(3507, 0))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `PrecompileRegistry::Precompiles` (r:1 w:1)
+	/// Proof: `PrecompileRegistry::Precompiles` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:0 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn register() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3507`
+		// Minimum execution time: 9_000 nanoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(3507, 0))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PrecompileRegistry::Precompiles` (r:1 w:1)
+	/// Proof: `PrecompileRegistry::Precompiles` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `EVM::AccountCodes` (r:0 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodesMetadata` (r:0 w:1)
+	/// Proof: `EVM::AccountCodesMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn unregister() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `123`
+		//  Estimated: `3507`
+		// Minimum execution time: 10_000 nanoseconds.
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(Weight::from_parts(3507, 0))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PrecompileRegistry::Precompiles` (r:1 w:1)
+	/// Proof: `PrecompileRegistry::Precompiles` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	fn set_enabled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `123`
+		//  Estimated: `3507`
+		// Minimum execution time: 6_000 nanoseconds.
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(Weight::from_parts(3507, 0))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -66,6 +66,101 @@ mod benchmarks {
 		_(RawOrigin::Root, 1);
 	}
 
+	#[benchmark]
+	fn retry_unallocated_reward() {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+
+		<UnallocatedRingRewards<T>>::insert(&a, 1);
+		<RingRewardRetryStates<T>>::insert(
+			&a,
+			RetryState { attempts: 1, next_attempt: Default::default() },
+		);
+
+		// Worst-case scenario:
+		//
+		// The allocation fails again.
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()), Some(a));
+	}
+
+	#[benchmark]
+	fn force_retry_unallocated_reward() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+
+		<UnallocatedRingRewards<T>>::insert(&a, 1);
+		<RingRewardRetryStates<T>>::insert(
+			&a,
+			RetryState { attempts: u32::MAX, next_attempt: Default::default() },
+		);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Some(a));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_invulnerables(
+		n: Linear<0, { T::MaxInvulnerables::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let invulnerables =
+			(0..n).map(|i| frame_benchmarking::account("", i, i)).collect::<Vec<T::AccountId>>();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, invulnerables);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_election_sources() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			vec![
+				ElectionSource::PreviousSet,
+				ElectionSource::Invulnerables,
+				ElectionSource::Contract,
+			],
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_ring_reward_ratio() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Perbill::from_percent(60));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_emission_schedule(
+		n: Linear<0, { T::MaxEmissionSteps::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let schedule = (0..n)
+			.map(|i| EmissionStep { from_era: i, reward_per_session: (n - i) as _ })
+			.collect::<Vec<_>>();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, schedule);
+
+		Ok(())
+	}
+
 	frame_benchmarking::impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::ExtBuilder.build(),
@@ -35,20 +35,29 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+mod runtime_api;
+pub use runtime_api::*;
+
 mod weights;
 pub use weights::WeightInfo;
 
 // crates.io
 use codec::FullCodec;
-use ethabi::{Function, Param, ParamType, StateMutability, Token};
 // darwinia
+use darwinia_ethtx_forwarder::GasLimit;
+use dc_abi::{
+	collator_staking_hub::{DistributeRewardCall, GetTopCollatorsCall},
+	kton_dao_vault::DistributeRewardsCall,
+};
 use dc_types::Balance;
+// frontier
+use fp_evm::CallOrCreateInfo;
 // polkadot-sdk
 use frame_support::{pallet_prelude::*, PalletId};
 use frame_system::pallet_prelude::*;
 use sp_core::H160;
 use sp_runtime::{
-	traits::{AccountIdConversion, Convert, One},
+	traits::{AccountIdConversion, Convert, One, Saturating, UniqueSaturatedInto, Zero},
 	Perbill,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
@@ -79,8 +88,66 @@ pub mod pallet {
 		type Treasury: Get<Self::AccountId>;
 
 		/// Reward amount per session.
+		///
+		/// Only used if there is no [`EmissionSchedule`].
 		#[pallet::constant]
 		type RewardPerSession: Get<Balance>;
+
+		/// Number of sessions per era.
+		#[pallet::constant]
+		type SessionsPerEra: Get<u32>;
+
+		/// Maximum number of the steps in the emission schedule.
+		#[pallet::constant]
+		type MaxEmissionSteps: Get<u32>;
+
+		/// Current consensus slot.
+		type CurrentSlot: Get<u64>;
+
+		/// Collators of the current session.
+		type Collators: Get<Vec<Self::AccountId>>;
+
+		/// Reward curve which maps the performance of a collator to its reward ratio.
+		type RewardCurve: Convert<Perbill, Perbill>;
+
+		/// Minimum performance of a collator within a session.
+		///
+		/// A collator below this will forfeit its whole session reward and receive an offence.
+		#[pallet::constant]
+		type MinPerformance: Get<Perbill>;
+
+		/// Maximum number of consecutive offences before a collator gets excluded from the next
+		/// election.
+		#[pallet::constant]
+		type MaxOffences: Get<u32>;
+
+		/// Origin for the governance operations of this pallet.
+		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Base delay of the exponential backoff between two retries of an unallocated reward.
+		#[pallet::constant]
+		type RetryBaseDelay: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of failed retries before the retry of an unallocated reward gets
+		/// escalated to the [`Config::ManagerOrigin`].
+		#[pallet::constant]
+		type MaxRetryAttempts: Get<u32>;
+
+		/// Maximum number of the invulnerable collators.
+		#[pallet::constant]
+		type MaxInvulnerables: Get<u32>;
+
+		/// Maximum number of the collators, which bounds the [`CollatorCount`].
+		#[pallet::constant]
+		type MaxCollators: Get<u32>;
+
+		/// Gas budget of allocating a reward to a staking contract.
+		#[pallet::constant]
+		type AllocationGasBudget: Get<u64>;
+
+		/// Gas limit of electing the collators from the RING staking contract.
+		#[pallet::constant]
+		type ElectionGasLimit: Get<u64>;
 	}
 
 	#[allow(missing_docs)]
@@ -89,12 +156,59 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// Reward allocated to the account.
 		RewardAllocated { who: T::AccountId, amount: Balance },
+		/// The collator missed some of its slots within the session.
+		SlotsMissed { who: T::AccountId, expected: u32, authored: u32, penalty: Balance },
+		/// The collator has been excluded from the election due to repeated offences.
+		CollatorExcluded { who: T::AccountId, offences: u32 },
+		/// Failed to allocate the reward, it has been parked for a later retry.
+		///
+		/// `None` stands for the KTON staking contract.
+		/// `attempts` is the number of failed retries.
+		RewardAllocationFailed { who: Option<T::AccountId>, amount: Balance, attempts: u32 },
+		/// Unallocated reward has been allocated by a retry.
+		UnallocatedRewardAllocated { who: Option<T::AccountId>, amount: Balance },
+		/// The retry of the unallocated reward has reached the maximum attempts.
+		///
+		/// Only the [`Config::ManagerOrigin`] can retry it from now on.
+		RetryEscalated { who: Option<T::AccountId>, amount: Balance },
+		/// New collators have been elected from the source.
+		///
+		/// Any source other than [`ElectionSource::Contract`] indicates a degraded election.
+		CollatorsElected { source: ElectionSource, count: u32 },
+		/// The source failed to provide any collator.
+		ElectionSourceFailed { source: ElectionSource },
+		/// The invulnerable collators have been updated.
+		InvulnerablesSet { invulnerables: Vec<T::AccountId> },
+		/// The election sources have been updated.
+		ElectionSourcesSet { sources: Vec<ElectionSource> },
+		/// The RING staking share of the session reward has been updated.
+		RingRewardRatioSet { ratio: Perbill },
+		/// The emission schedule has been updated.
+		EmissionScheduleSet { schedule: Vec<EmissionStep> },
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// No reward to pay for this collator.
 		NoReward,
+		/// Invalid staking contract.
+		InvalidStakingContract,
+		/// Reward allocation failed on the staking contract.
+		AllocationFailedOnContract,
+		/// The retry has been escalated to the governance.
+		RetryEscalated,
+		/// Exceed the maximum number of the invulnerable collators.
+		TooManyInvulnerables,
+		/// The election sources must be non-empty and free of duplicates.
+		InvalidElectionSources,
+		/// The emission steps must be in strictly ascending order of the era.
+		InvalidEmissionSchedule,
+		/// Exceed the maximum number of the emission steps.
+		TooManyEmissionSteps,
+		/// Exceed the maximum number of the collators.
+		TooManyCollators,
+		/// Exceed the maximum number of the pending allocation requests.
+		TooManyAllocationRequests,
 	}
 
 	/// The ideal number of active collators.
@@ -107,10 +221,25 @@ pub mod pallet {
 	pub type AuthoredBlockCount<T: Config> =
 		StorageValue<_, (BlockNumberFor<T>, BTreeMap<T::AccountId, BlockNumberFor<T>>), ValueQuery>;
 
+	/// The slot at which the current session started.
+	#[pallet::storage]
+	pub type SessionStartSlot<T> = StorageValue<_, u64>;
+
+	/// Number of consecutive sessions in which the collator performed below the minimum.
+	#[pallet::storage]
+	pub type Offences<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
 	/// All outstanding rewards since the last payment.
 	#[pallet::storage]
 	pub type PendingRewards<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Balance>;
 
+	/// Collators whose pending rewards are requested to be allocated, served first in `on_idle`.
+	///
+	/// The requests come from the EVM, which must not be reentered by the allocation.
+	#[pallet::storage]
+	pub type AllocationRequests<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, ConstU32<MAX_ALLOCATION_REQUESTS>>, ValueQuery>;
+
 	/// RING staking contract address.
 	#[pallet::storage]
 	pub type RingStakingContract<T: Config> = StorageValue<_, T::AccountId>;
@@ -128,6 +257,70 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type UnallocatedKtonRewards<T> = StorageValue<_, Balance, ValueQuery>;
 
+	/// Retry states of the unallocated collator RING rewards.
+	#[pallet::storage]
+	pub type RingRewardRetryStates<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, RetryState<BlockNumberFor<T>>>;
+
+	/// Retry state of the unallocated collator KTON rewards.
+	#[pallet::storage]
+	pub type KtonRewardRetryState<T: Config> = StorageValue<_, RetryState<BlockNumberFor<T>>>;
+
+	/// The last collator whose unallocated RING reward has been checked in `on_idle`.
+	///
+	/// `None` means starting from the beginning.
+	#[pallet::storage]
+	pub type RetryCursor<T: Config> = StorageValue<_, T::AccountId>;
+
+	/// Governance maintained collators, which serve as an election fallback.
+	#[pallet::storage]
+	pub type Invulnerables<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxInvulnerables>, ValueQuery>;
+
+	#[allow(missing_docs)]
+	#[pallet::type_value]
+	pub fn DefaultElectionSources() -> BoundedVec<ElectionSource, ConstU32<MAX_ELECTION_SOURCES>> {
+		BoundedVec::truncate_from(vec![
+			ElectionSource::Contract,
+			ElectionSource::Invulnerables,
+			ElectionSource::PreviousSet,
+		])
+	}
+	/// Election sources, in the order of the attempts.
+	#[pallet::storage]
+	pub type ElectionSources<T> = StorageValue<
+		_,
+		BoundedVec<ElectionSource, ConstU32<MAX_ELECTION_SOURCES>>,
+		ValueQuery,
+		DefaultElectionSources,
+	>;
+
+	/// The source from which the latest collators were elected.
+	#[pallet::storage]
+	pub type LastElectionSource<T> = StorageValue<_, ElectionSource>;
+
+	#[allow(missing_docs)]
+	#[pallet::type_value]
+	pub fn DefaultRingRewardRatio() -> Perbill {
+		Perbill::from_percent(50)
+	}
+	/// The RING staking share of the session reward.
+	///
+	/// The rest goes to the KTON staking.
+	#[pallet::storage]
+	pub type RingRewardRatio<T> = StorageValue<_, Perbill, ValueQuery, DefaultRingRewardRatio>;
+
+	/// Emission schedule of the session reward, in ascending order of the era.
+	///
+	/// Each step applies from its era until the next step.
+	#[pallet::storage]
+	pub type EmissionSchedule<T: Config> =
+		StorageValue<_, BoundedVec<EmissionStep, T::MaxEmissionSteps>, ValueQuery>;
+
+	/// The current era.
+	#[pallet::storage]
+	pub type CurrentEra<T> = StorageValue<_, u32, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T> {
 		/// Genesis collator count.
@@ -146,7 +339,7 @@ pub mod pallet {
 		T: Config,
 	{
 		fn build(&self) {
-			<CollatorCount<T>>::put(self.collator_count.max(1));
+			<CollatorCount<T>>::put(self.collator_count.max(1).min(T::MaxCollators::get()));
 		}
 	}
 
@@ -157,6 +350,7 @@ pub mod pallet {
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_idle(_: BlockNumberFor<T>, mut remaining_weight: Weight) -> Weight {
 			Self::idle_allocate_ring_staking_reward(&mut remaining_weight);
+			Self::idle_retry_unallocated_reward(&mut remaining_weight);
 
 			remaining_weight
 		}
@@ -166,7 +360,9 @@ pub mod pallet {
 		/// Allocate the RING staking rewards to the designated RING staking contract of a
 		/// particular collator.
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::allocate_ring_staking_reward_of())]
+		#[pallet::weight(<T as Config>::WeightInfo::allocate_ring_staking_reward_of().saturating_add(
+			<T as Config>::RingStaking::estimation_weight()
+		))]
 		pub fn allocate_ring_staking_reward_of(
 			origin: OriginFor<T>,
 			who: T::AccountId,
@@ -189,6 +385,7 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::set_collator_count())]
 		pub fn set_collator_count(origin: OriginFor<T>, count: u32) -> DispatchResult {
 			ensure_root(origin)?;
+			ensure!(count <= T::MaxCollators::get(), <Error<T>>::TooManyCollators);
 
 			<CollatorCount<T>>::put(count.max(1));
 
@@ -226,6 +423,134 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Retry to allocate the unallocated rewards.
+		///
+		/// `None` stands for the KTON staking rewards.
+		///
+		/// This is not subject to the backoff, but it is no longer available once the retry has
+		/// been escalated.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::retry_unallocated_reward().saturating_add(
+			Pallet::<T>::estimation_weight_of(who)
+		))]
+		pub fn retry_unallocated_reward(
+			origin: OriginFor<T>,
+			who: Option<T::AccountId>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(!Self::retry_state_of(&who).is_escalated::<T>(), <Error<T>>::RetryEscalated);
+
+			Self::retry_unallocated_reward_inner(who)
+		}
+
+		/// Reset the retry state and retry to allocate the unallocated rewards.
+		///
+		/// `None` stands for the KTON staking rewards.
+		///
+		/// Require manager origin.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_retry_unallocated_reward().saturating_add(
+			Pallet::<T>::estimation_weight_of(who)
+		))]
+		pub fn force_retry_unallocated_reward(
+			origin: OriginFor<T>,
+			who: Option<T::AccountId>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			Self::set_retry_state(&who, None);
+			Self::retry_unallocated_reward_inner(who)
+		}
+
+		/// Set the invulnerable collators.
+		///
+		/// They will be elected if the RING staking contract fails to provide any collator.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_invulnerables(invulnerables.len() as _))]
+		pub fn set_invulnerables(
+			origin: OriginFor<T>,
+			invulnerables: Vec<T::AccountId>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			let bounded_invulnerables = BoundedVec::try_from(invulnerables.clone())
+				.map_err(|_| <Error<T>>::TooManyInvulnerables)?;
+
+			<Invulnerables<T>>::put(bounded_invulnerables);
+
+			Self::deposit_event(Event::InvulnerablesSet { invulnerables });
+
+			Ok(())
+		}
+
+		/// Set the election sources, in the order of the attempts.
+		///
+		/// This will apply to the incoming session.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_election_sources())]
+		pub fn set_election_sources(
+			origin: OriginFor<T>,
+			sources: Vec<ElectionSource>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!sources.is_empty()
+					&& sources.iter().enumerate().all(|(i, s)| !sources[..i].contains(s)),
+				<Error<T>>::InvalidElectionSources
+			);
+
+			let bounded_sources = BoundedVec::try_from(sources.clone())
+				.map_err(|_| <Error<T>>::InvalidElectionSources)?;
+
+			<ElectionSources<T>>::put(bounded_sources);
+
+			Self::deposit_event(Event::ElectionSourcesSet { sources });
+
+			Ok(())
+		}
+
+		/// Set the RING staking share of the session reward.
+		///
+		/// This will apply to the incoming session reward.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_ring_reward_ratio())]
+		pub fn set_ring_reward_ratio(origin: OriginFor<T>, ratio: Perbill) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			<RingRewardRatio<T>>::put(ratio);
+
+			Self::deposit_event(Event::RingRewardRatioSet { ratio });
+
+			Ok(())
+		}
+
+		/// Set the emission schedule of the session reward.
+		///
+		/// An empty schedule falls back to the [`Config::RewardPerSession`].
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_emission_schedule(schedule.len() as _))]
+		pub fn set_emission_schedule(
+			origin: OriginFor<T>,
+			schedule: Vec<EmissionStep>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				schedule.windows(2).all(|w| w[0].from_era < w[1].from_era),
+				<Error<T>>::InvalidEmissionSchedule
+			);
+
+			let bounded_schedule = BoundedVec::try_from(schedule.clone())
+				.map_err(|_| <Error<T>>::TooManyEmissionSteps)?;
+
+			<EmissionSchedule<T>>::put(bounded_schedule);
+
+			Self::deposit_event(Event::EmissionScheduleSet { schedule });
+
+			Ok(())
+		}
 	}
 	impl<T> Pallet<T>
 	where
@@ -244,16 +569,39 @@ pub mod pallet {
 			});
 		}
 
+		/// Request to allocate the pending rewards of a collator in `on_idle`.
+		///
+		/// For the callers which must not reenter the EVM, e.g. a precompile.
+		pub fn request_ring_staking_reward_allocation(who: T::AccountId) -> DispatchResult {
+			ensure!(<PendingRewards<T>>::contains_key(&who), <Error<T>>::NoReward);
+
+			<AllocationRequests<T>>::try_mutate(|requests| -> DispatchResult {
+				if !requests.contains(&who) {
+					requests.try_push(who).map_err(|_| <Error<T>>::TooManyAllocationRequests)?;
+				}
+
+				Ok(())
+			})
+		}
+
 		/// Allocate the session reward.
 		pub fn allocate_session_reward(amount: Balance) {
-			let reward_to_ring_staking = amount.saturating_div(2);
+			let reward_to_ring_staking = <RingRewardRatio<T>>::get().mul_floor(amount);
 			let reward_to_kton_staking = amount.saturating_sub(reward_to_ring_staking);
-			let (total_block_count, author_map) = <AuthoredBlockCount<T>>::take();
-
-			author_map.into_iter().for_each(|(who, authored_block_count)| {
-				let incoming_reward =
-					Perbill::from_rational(authored_block_count, total_block_count)
-						.mul_floor(reward_to_ring_staking);
+			let (maybe_expected_block_count, shares) =
+				Self::split_session_reward(reward_to_ring_staking, <AuthoredBlockCount<T>>::take());
+
+			shares.into_iter().for_each(|(who, authored_block_count, reward)| {
+				let incoming_reward = Self::assess_performance(
+					&who,
+					authored_block_count,
+					maybe_expected_block_count,
+					reward,
+				);
+
+				if incoming_reward == 0 {
+					return;
+				}
 
 				<PendingRewards<T>>::mutate(who, |maybe_pending_reward| {
 					*maybe_pending_reward = maybe_pending_reward
@@ -262,32 +610,354 @@ pub mod pallet {
 				});
 			});
 
-			T::KtonStaking::allocate(None, reward_to_kton_staking);
+			Self::allocate_reward(None, reward_to_kton_staking, false);
+		}
+
+		/// Session reward of the current era.
+		pub fn reward_per_session() -> Balance {
+			Self::reward_per_session_at(<CurrentEra<T>>::get())
+		}
+
+		/// Session reward of the era, according to the [`EmissionSchedule`].
+		pub fn reward_per_session_at(era: u32) -> Balance {
+			<EmissionSchedule<T>>::get()
+				.into_iter()
+				.rev()
+				.find(|s| s.from_era <= era)
+				.map(|s| s.reward_per_session)
+				.unwrap_or_else(T::RewardPerSession::get)
+		}
+
+		/// Number of blocks each collator is expected to author within the current session.
+		///
+		/// Aura assigns the slots in a round-robin manner, so each collator is expected to author
+		/// an equal share of the elapsed slots.
+		pub fn expected_block_count(collator_count: u32) -> Option<u32> {
+			let start_slot = <SessionStartSlot<T>>::get()?;
+
+			if collator_count == 0 {
+				return None;
+			}
+
+			let elapsed_slot_count = T::CurrentSlot::get().saturating_sub(start_slot);
+
+			Some((elapsed_slot_count / collator_count as u64).unique_saturated_into())
+		}
+
+		// Apply the reward curve and the penalty to the collator's session reward.
+		//
+		// Return the reward after the adjustment.
+		pub(crate) fn assess_performance(
+			who: &T::AccountId,
+			authored: u32,
+			maybe_expected: Option<u32>,
+			reward: Balance,
+		) -> Balance {
+			// Lack of the session start slot, there is nothing to compare with.
+			let Some(expected) = maybe_expected else { return reward };
+			let adjusted_reward =
+				if let Some(r) = Self::apply_reward_curve(authored, expected, reward) {
+					<Offences<T>>::remove(who);
+
+					r
+				} else {
+					<Offences<T>>::mutate(who, |o| *o = o.saturating_add(1));
+
+					0
+				};
+
+			if authored < expected {
+				Self::deposit_event(Event::SlotsMissed {
+					who: who.clone(),
+					expected,
+					authored,
+					penalty: reward.saturating_sub(adjusted_reward),
+				});
+			}
+
+			adjusted_reward
+		}
+
+		// Return `None` if the performance is below the [`Config::MinPerformance`].
+		fn apply_reward_curve(authored: u32, expected: u32, reward: Balance) -> Option<Balance> {
+			let performance = Perbill::from_rational(authored, expected);
+
+			if performance < T::MinPerformance::get() {
+				None
+			} else {
+				Some(T::RewardCurve::convert(performance).mul_floor(reward))
+			}
+		}
+
+		/// Projected RING staking reward of each collator for the current session.
+		///
+		/// Same as [`Self::allocate_session_reward`] but without any side effect.
+		pub fn projected_session_rewards() -> Vec<(T::AccountId, Balance)> {
+			let reward_to_ring_staking =
+				<RingRewardRatio<T>>::get().mul_floor(Self::reward_per_session());
+			let (maybe_expected_block_count, shares) =
+				Self::split_session_reward(reward_to_ring_staking, <AuthoredBlockCount<T>>::get());
+
+			shares
+				.into_iter()
+				.map(|(who, authored_block_count, reward)| {
+					let projected_reward = match maybe_expected_block_count {
+						Some(expected) =>
+							Self::apply_reward_curve(authored_block_count, expected, reward)
+								.unwrap_or_default(),
+						None => reward,
+					};
+
+					(who, projected_reward)
+				})
+				.collect()
+		}
+
+		// Split the RING staking share of the session reward by the authored blocks.
+		//
+		// Return the expected block count and each collator along with its authored block count
+		// and its share, before assessing the performance.
+		#[allow(clippy::type_complexity)]
+		fn split_session_reward(
+			reward_to_ring_staking: Balance,
+			(total_block_count, mut author_map): (
+				BlockNumberFor<T>,
+				BTreeMap<T::AccountId, BlockNumberFor<T>>,
+			),
+		) -> (Option<u32>, Vec<(T::AccountId, u32, Balance)>) {
+			let collators = T::Collators::get();
+			let maybe_expected_block_count = Self::expected_block_count(collators.len() as u32);
+
+			// Collators who did not author any block are still accountable.
+			collators.into_iter().for_each(|who| {
+				author_map.entry(who).or_default();
+			});
+
+			let shares = author_map
+				.into_iter()
+				.map(|(who, authored_block_count)| {
+					let reward = if authored_block_count.is_zero() {
+						0
+					} else {
+						Perbill::from_rational(authored_block_count, total_block_count)
+							.mul_floor(reward_to_ring_staking)
+					};
+
+					(who, authored_block_count.unique_saturated_into(), reward)
+				})
+				.collect();
+
+			(maybe_expected_block_count, shares)
+		}
+
+		/// Pending and unallocated RING staking rewards of the collator.
+		pub fn reward_balances_of(who: &T::AccountId) -> (Balance, Balance) {
+			(
+				<PendingRewards<T>>::get(who).unwrap_or_default(),
+				<UnallocatedRingRewards<T>>::get(who).unwrap_or_default(),
+			)
 		}
 
 		pub(crate) fn allocate_ring_staking_reward_of_inner(
 			who: T::AccountId,
 			amount: Balance,
 		) -> DispatchResult {
-			T::RingStaking::allocate(Some(who.clone()), amount);
+			if Self::allocate_reward(Some(who.clone()), amount, false) {
+				Self::deposit_event(Event::RewardAllocated { who, amount });
+			}
+
+			Ok(())
+		}
+
+		/// Weight of estimating the gas of an allocation.
+		///
+		/// `None` stands for the KTON staking contract.
+		pub(crate) fn estimation_weight_of(who: &Option<T::AccountId>) -> Weight {
+			match who {
+				Some(_) => T::RingStaking::estimation_weight(),
+				None => T::KtonStaking::estimation_weight(),
+			}
+		}
+
+		// Allocate the reward to the RING staking contract of the collator, or to the KTON staking
+		// contract if `who` is `None`.
+		//
+		// Park the reward for a later retry if the allocation failed, `retried` tells whether this
+		// is a retry of a parked reward.
+		fn allocate_reward(who: Option<T::AccountId>, amount: Balance, retried: bool) -> bool {
+			let r = match who {
+				Some(_) => T::RingStaking::allocate(who.clone(), amount),
+				None => T::KtonStaking::allocate(None, amount),
+			};
 
-			Self::deposit_event(Event::RewardAllocated { who, amount });
+			if let Err(e) = r {
+				log::error!("failed to allocate reward due to {e:?}");
+
+				Self::park_unallocated_reward(who, amount, retried);
+
+				false
+			} else {
+				// The contract works again, stop backing off.
+				Self::set_retry_state(&who, None);
+
+				true
+			}
+		}
+
+		fn park_unallocated_reward(who: Option<T::AccountId>, amount: Balance, retried: bool) {
+			let unallocated = match &who {
+				Some(w) => <UnallocatedRingRewards<T>>::mutate(w, |u| {
+					let u = u.get_or_insert(0);
+
+					*u += amount;
+
+					*u
+				}),
+				None => <UnallocatedKtonRewards<T>>::mutate(|u| {
+					*u += amount;
+
+					*u
+				}),
+			};
+			let mut state = Self::retry_state_of(&who);
+			let next_attempt = |attempts: u32| {
+				<frame_system::Pallet<T>>::block_number().saturating_add(
+					T::RetryBaseDelay::get().saturating_mul(
+						BlockNumberFor::<T>::from(2_u32)
+							.saturating_pow(attempts.min(MAX_BACKOFF_EXPONENT) as _),
+					),
+				)
+			};
+
+			// Only the retries count as attempts.
+			// A new failure joins the parked reward and keeps its backoff.
+			if retried {
+				state.attempts = state.attempts.saturating_add(1);
+				state.next_attempt = next_attempt(state.attempts);
+			} else if unallocated == amount {
+				state.next_attempt = next_attempt(0);
+			}
+
+			Self::set_retry_state(&who, Some(state.clone()));
+			Self::deposit_event(Event::RewardAllocationFailed {
+				who: who.clone(),
+				amount,
+				attempts: state.attempts,
+			});
+
+			if retried && state.attempts == T::MaxRetryAttempts::get() {
+				Self::deposit_event(Event::RetryEscalated { who, amount: unallocated });
+			}
+		}
+
+		pub(crate) fn retry_unallocated_reward_inner(who: Option<T::AccountId>) -> DispatchResult {
+			let amount = match &who {
+				Some(w) => <UnallocatedRingRewards<T>>::take(w).unwrap_or_default(),
+				None => <UnallocatedKtonRewards<T>>::take(),
+			};
+
+			ensure!(amount != 0, <Error<T>>::NoReward);
+
+			if Self::allocate_reward(who.clone(), amount, true) {
+				Self::deposit_event(Event::UnallocatedRewardAllocated { who, amount });
+			}
 
 			Ok(())
 		}
 
+		/// Retry state of the unallocated reward.
+		///
+		/// `None` stands for the KTON staking rewards.
+		pub fn retry_state_of(who: &Option<T::AccountId>) -> RetryState<BlockNumberFor<T>> {
+			match who {
+				Some(w) => <RingRewardRetryStates<T>>::get(w),
+				None => <KtonRewardRetryState<T>>::get(),
+			}
+			.unwrap_or_default()
+		}
+
+		fn set_retry_state(
+			who: &Option<T::AccountId>,
+			state: Option<RetryState<BlockNumberFor<T>>>,
+		) {
+			match who {
+				Some(w) => <RingRewardRetryStates<T>>::set(w, state),
+				None => <KtonRewardRetryState<T>>::set(state),
+			}
+		}
+
 		fn prepare_new_session(i: u32) -> Option<Vec<T::AccountId>> {
 			let bn = <frame_system::Pallet<T>>::block_number();
 
 			log::info!("assembling new collators for new session {i} at #{bn:?}");
 
-			let collators = T::RingStaking::elect(<CollatorCount<T>>::get()).unwrap_or_default();
+			let collator_count = <CollatorCount<T>>::get();
+			let max_offences = T::MaxOffences::get();
+			// Offences are only recorded for the collators, so looking them up is enough.
+			let offences_of = |who: &T::AccountId| {
+				Some(<Offences<T>>::get(who)).filter(|o| max_offences != 0 && *o >= max_offences)
+			};
+			let previous_set = T::Collators::get();
+			let offender_count =
+				previous_set.iter().filter(|c| offences_of(c).is_some()).count() as u32;
+			let mut maybe_collators = None;
+
+			for source in <ElectionSources<T>>::get() {
+				let candidates = match source {
+					// Elect extra collators to fill the seats of the offenders.
+					ElectionSource::Contract =>
+						T::RingStaking::elect(collator_count.saturating_add(offender_count))
+							.unwrap_or_default(),
+					ElectionSource::Invulnerables => <Invulnerables<T>>::get().into_inner(),
+					ElectionSource::PreviousSet => previous_set.clone(),
+				};
+				let mut excluded = Vec::new();
+				let collators = candidates
+					.into_iter()
+					.filter(|c| {
+						let Some(offences) = offences_of(c) else { return true };
+
+						excluded.push((c.clone(), offences));
+
+						false
+					})
+					.take(collator_count as _)
+					.collect::<Vec<_>>();
+
+				if collators.is_empty() {
+					log::warn!("election source {source:?} failed to provide any collator");
+
+					Self::deposit_event(Event::ElectionSourceFailed { source });
+
+					continue;
+				}
 
-			if collators.is_empty() {
-				None
-			} else {
-				Some(collators)
+				<LastElectionSource<T>>::put(source);
+
+				// The offenders sit out for one session, then get a fresh start.
+				excluded.into_iter().for_each(|(who, offences)| {
+					<Offences<T>>::remove(&who);
+
+					Self::deposit_event(Event::CollatorExcluded { who, offences });
+				});
+				// The offences must be consecutive, forget the ones of the collators leaving the
+				// set.
+				previous_set
+					.iter()
+					.filter(|c| !collators.contains(c))
+					.for_each(<Offences<T>>::remove);
+
+				Self::deposit_event(Event::CollatorsElected {
+					source,
+					count: collators.len() as _,
+				});
+
+				maybe_collators = Some(collators);
+
+				break;
 			}
+
+			maybe_collators
 		}
 
 		fn idle_allocate_ring_staking_reward(remaining_weight: &mut Weight) {
@@ -296,7 +966,19 @@ pub mod pallet {
 			#[cfg(test)]
 			let wt = Weight::zero().add_ref_time(1);
 			#[cfg(not(test))]
-			let wt = T::WeightInfo::allocate_ring_staking_reward_of();
+			let wt = T::WeightInfo::allocate_ring_staking_reward_of()
+				.saturating_add(T::RingStaking::estimation_weight());
+			// Read and update the allocation requests.
+			let requests_wt = T::DbWeight::get().reads_writes(1, 1);
+
+			if let Some(rw) = remaining_weight.checked_sub(&requests_wt) {
+				*remaining_weight = rw;
+			} else {
+				return;
+			}
+
+			let mut requests = <AllocationRequests<T>>::get().into_inner();
+			let request_count = requests.len();
 			let mut consumer = <PendingRewards<T>>::iter().drain();
 
 			for _ in 0..MAX_TASKS {
@@ -305,7 +987,21 @@ pub mod pallet {
 				} else {
 					break;
 				}
-				if let Some((k, v)) = consumer.next() {
+
+				// Serve the requested ones first, skip the ones allocated in the meantime.
+				let next = loop {
+					if requests.is_empty() {
+						break consumer.next();
+					}
+
+					let who = requests.remove(0);
+
+					if let Some(amount) = <PendingRewards<T>>::take(&who) {
+						break Some((who, amount));
+					}
+				};
+
+				if let Some((k, v)) = next {
 					let _ = Self::allocate_ring_staking_reward_of_inner(k, v);
 				} else {
 					// There is nothing to do; add the weight back.
@@ -314,6 +1010,92 @@ pub mod pallet {
 					break;
 				}
 			}
+
+			if requests.len() != request_count {
+				<AllocationRequests<T>>::put(BoundedVec::truncate_from(requests));
+			}
+		}
+
+		fn idle_retry_unallocated_reward(remaining_weight: &mut Weight) {
+			const MAX_TASKS: usize = 10;
+			const MAX_SCANS: usize = 64;
+
+			#[cfg(test)]
+			let wt = Weight::zero().add_ref_time(1);
+			#[cfg(not(test))]
+			let wt = T::WeightInfo::retry_unallocated_reward();
+			// Read the unallocated reward key and its retry state.
+			let scan_wt = T::DbWeight::get().reads(2);
+			let now = <frame_system::Pallet<T>>::block_number();
+			let is_due = |who: &Option<T::AccountId>| {
+				let state = Self::retry_state_of(who);
+
+				!state.is_escalated::<T>() && state.next_attempt <= now
+			};
+			let mut charge = |w: Weight| {
+				if let Some(rw) = remaining_weight.checked_sub(&w) {
+					*remaining_weight = rw;
+
+					true
+				} else {
+					false
+				}
+			};
+
+			// Read and write the cursor.
+			if !charge(T::DbWeight::get().reads_writes(1, 1)) {
+				return;
+			}
+
+			let mut tasks = Vec::new();
+
+			if charge(scan_wt)
+				&& <UnallocatedKtonRewards<T>>::get() != 0
+				&& is_due(&None)
+				&& charge(wt.saturating_add(Self::estimation_weight_of(&None)))
+			{
+				tasks.push(None);
+			}
+
+			// Resume from the cursor, so every unallocated reward gets checked in turn.
+			let mut cursor = <RetryCursor<T>>::get();
+			let mut keys = match &cursor {
+				Some(w) => <UnallocatedRingRewards<T>>::iter_keys_from(
+					<UnallocatedRingRewards<T>>::hashed_key_for(w),
+				),
+				None => <UnallocatedRingRewards<T>>::iter_keys(),
+			};
+
+			for _ in 0..MAX_SCANS {
+				if tasks.len() == MAX_TASKS || !charge(scan_wt) {
+					break;
+				}
+
+				let Some(who) = keys.next() else {
+					// Reached the end, start over next time.
+					cursor = None;
+
+					break;
+				};
+				let who = Some(who);
+
+				if is_due(&who) {
+					// Out of weight, check it again next time.
+					if !charge(wt.saturating_add(Self::estimation_weight_of(&who))) {
+						break;
+					}
+
+					tasks.push(who.clone());
+				}
+
+				cursor = who;
+			}
+
+			<RetryCursor<T>>::set(cursor);
+
+			for who in tasks {
+				let _ = Self::retry_unallocated_reward_inner(who);
+			}
 		}
 	}
 	impl<T> pallet_authorship::EventHandler<T::AccountId, BlockNumberFor<T>> for Pallet<T>
@@ -329,10 +1111,14 @@ pub mod pallet {
 		T: Config,
 	{
 		fn end_session(_: u32) {
-			Self::allocate_session_reward(T::RewardPerSession::get());
+			Self::allocate_session_reward(Self::reward_per_session());
 		}
 
-		fn start_session(_: u32) {}
+		fn start_session(i: u32) {
+			<CurrentEra<T>>::put(i / T::SessionsPerEra::get().max(1));
+			// The slot is unknown at genesis.
+			<SessionStartSlot<T>>::set(Some(T::CurrentSlot::get()).filter(|s| *s != 0));
+		}
 
 		// No election in genesis.
 		// Since RING contract isn't available at this time.
@@ -367,10 +1153,93 @@ impl<AccountId> Election<AccountId> for () {}
 /// Allocate the reward to a contract.
 pub trait Reward<AccountId> {
 	/// Allocate the reward.
-	fn allocate(_: Option<AccountId>, _: Balance) {}
+	fn allocate(_: Option<AccountId>, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Weight of estimating the gas of an allocation.
+	fn estimation_weight() -> Weight {
+		Weight::zero()
+	}
 }
 impl<AccountId> Reward<AccountId> for () {}
 
+// Contract, invulnerables and the previous set.
+const MAX_ELECTION_SOURCES: u32 = 3;
+
+/// Maximum number of the pending [`AllocationRequests`].
+pub const MAX_ALLOCATION_REQUESTS: u32 = 64;
+
+/// Source of the elected collators.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum ElectionSource {
+	/// The RING staking contract.
+	Contract,
+	/// The governance maintained [`Invulnerables`].
+	Invulnerables,
+	/// Collators of the current session.
+	PreviousSet,
+}
+
+/// A step of the emission schedule.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct EmissionStep {
+	/// The era from which this step applies.
+	pub from_era: u32,
+	/// Reward amount per session.
+	pub reward_per_session: Balance,
+}
+
+// Cap the backoff at `RetryBaseDelay * 2^16`.
+const MAX_BACKOFF_EXPONENT: u32 = 16;
+
+/// Retry state of an unallocated reward.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct RetryState<BlockNumber> {
+	/// Number of failed retries.
+	pub attempts: u32,
+	/// The block from which the next attempt is allowed.
+	pub next_attempt: BlockNumber,
+}
+impl<BlockNumber> RetryState<BlockNumber> {
+	/// Whether the retry has been escalated to the governance.
+	pub fn is_escalated<T>(&self) -> bool
+	where
+		T: Config,
+	{
+		self.attempts >= T::MaxRetryAttempts::get()
+	}
+}
+
+/// Collators of the current session, read from [`pallet_session`].
+pub struct SessionCollators<T>(PhantomData<T>);
+impl<T> Get<Vec<T::AccountId>> for SessionCollators<T>
+where
+	T: Config + pallet_session::Config<ValidatorId = T::AccountId>,
+{
+	fn get() -> Vec<T::AccountId> {
+		<pallet_session::Validators<T>>::get()
+	}
+}
+
+/// Reward the collator proportionally to its performance.
+pub struct LinearRewardCurve;
+impl Convert<Perbill, Perbill> for LinearRewardCurve {
+	fn convert(performance: Perbill) -> Perbill {
+		performance
+	}
+}
+
+/// Reward the collator quadratically to its performance.
+///
+/// This penalizes the missed slots more aggressively than [`LinearRewardCurve`].
+pub struct QuadraticRewardCurve;
+impl Convert<Perbill, Perbill> for QuadraticRewardCurve {
+	fn convert(performance: Perbill) -> Perbill {
+		performance.square()
+	}
+}
+
 /// A convertor from collators id.
 ///
 /// Since this pallet does not have stash/controller, this is just identity.
@@ -400,63 +1269,25 @@ where
 	T::AccountId: From<H160> + Into<H160>,
 {
 	fn elect(x: u32) -> Option<Vec<T::AccountId>> {
-		const ZERO: [u8; 20] = [0; 20];
-
 		let Some(rsc) = <RingStakingContract<T>>::get() else {
 			log::error!("RING staking contract must be some; qed");
 
 			return None;
 		};
-		let rsc = rsc.into();
-		#[allow(deprecated)]
-		let function = Function {
-			name: "getTopCollators".to_owned(),
-			inputs: vec![Param {
-				name: "k".to_owned(),
-				kind: ParamType::Uint(256),
-				internal_type: None,
-			}],
-			outputs: vec![Param {
-				name: "collators".to_owned(),
-				kind: ParamType::Array(Box::new(ParamType::Address)),
-				internal_type: None,
-			}],
-			constant: None,
-			state_mutability: StateMutability::View,
-		};
-		let input = function
-			.encode_input(&[Token::Uint(x.into())])
-			.map_err(|e| log::error!("failed to encode input due to {e:?}"))
-			.ok()?;
 
 		<darwinia_ethtx_forwarder::Pallet<T>>::forward_call(
 			<T as Config>::Treasury::get().into(),
-			rsc,
-			input,
+			rsc.into(),
+			GetTopCollatorsCall { k: x.into() },
 			Default::default(),
-			1_000_000.into(),
+			<T as Config>::ElectionGasLimit::get().into(),
 		)
 		.map_err(|e| log::error!("failed to forward call due to {e:?}"))
 		.ok()
-		.and_then(|i| {
-			log::info!("getTopCollators({x})'s execution info {i:?}");
-
-			function
-				.decode_output(&i.value)
-				.map_err(|e| log::error!("failed to decode output due to {e:?}"))
-				.ok()
-				.map(|o| {
-					let Some(Token::Array(addrs)) = o.into_iter().next() else { return Vec::new() };
-
-					addrs
-						.into_iter()
-						.filter_map(|addr| match addr {
-							Token::Address(addr) if addr.0 != ZERO =>
-								Some(T::AccountId::from(addr)),
-							_ => None,
-						})
-						.collect()
-				})
+		.map(|collators| {
+			log::info!("getTopCollators({x}) returned {collators:?}");
+
+			collators.into_iter().filter(|c| !c.is_zero()).map(T::AccountId::from).collect()
 		})
 	}
 }
@@ -468,42 +1299,29 @@ where
 	T: Config + darwinia_ethtx_forwarder::Config,
 	T::AccountId: Into<H160>,
 {
-	fn allocate(who: Option<T::AccountId>, amount: Balance) {
+	fn allocate(who: Option<T::AccountId>, amount: Balance) -> DispatchResult {
 		let Some(who) = who else {
 			log::error!("who must be some; qed");
 
-			return;
+			return Err("who must be some; qed".into());
 		};
-		let Some(rsc) = <RingStakingContract<T>>::get() else {
-			log::error!("RING staking contract must be some; qed");
-
-			return;
-		};
-		let rsc = rsc.into();
+		let rsc = <RingStakingContract<T>>::get().ok_or(<Error<T>>::InvalidStakingContract)?.into();
 
-		#[allow(deprecated)]
-		if let Err(e) = darwinia_ethtx_forwarder::quick_forward_transact::<T>(
+		let (_, info) = darwinia_ethtx_forwarder::quick_forward_transact::<T, _>(
 			<T as Config>::Treasury::get().into(),
-			Function {
-				name: "distributeReward".into(),
-				inputs: vec![Param {
-					name: "address".to_owned(),
-					kind: ParamType::Address,
-					internal_type: None,
-				}],
-				outputs: Vec::new(),
-				constant: None,
-				state_mutability: StateMutability::Payable,
-			},
-			&[Token::Address(who.clone().into())],
+			DistributeRewardCall { collator: who.into() },
 			rsc,
 			amount.into(),
-			1_000_000.into(),
-		) {
-			log::error!("failed to forward call due to {e:?}");
+			GasLimit::Estimated { budget: <T as Config>::AllocationGasBudget::get().into() },
+		)?;
 
-			<UnallocatedRingRewards<T>>::mutate(who, |u| u.map(|u| u + amount).or(Some(amount)));
-		}
+		ensure_succeed::<T>(info)
+	}
+
+	fn estimation_weight() -> Weight {
+		<darwinia_ethtx_forwarder::Pallet<T>>::estimation_weight(
+			<T as Config>::AllocationGasBudget::get().into(),
+		)
 	}
 }
 
@@ -517,40 +1335,43 @@ where
 	T: Config + darwinia_ethtx_forwarder::Config,
 	T::AccountId: Into<H160>,
 {
-	fn allocate(_: Option<T::AccountId>, amount: Balance) {
-		let Some(ksc) = <KtonStakingContract<T>>::get() else {
-			log::error!("KTON staking contract must be some; qed");
+	fn allocate(_: Option<T::AccountId>, amount: Balance) -> DispatchResult {
+		let ksc = <KtonStakingContract<T>>::get().ok_or(<Error<T>>::InvalidStakingContract)?.into();
 
-			return;
-		};
-		let ksc = ksc.into();
-
-		#[allow(deprecated)]
-		if let Err(e) = darwinia_ethtx_forwarder::quick_forward_transact::<T>(
+		let (_, info) = darwinia_ethtx_forwarder::quick_forward_transact::<T, _>(
 			<T as Config>::Treasury::get().into(),
-			Function {
-				name: "distributeRewards".into(),
-				inputs: Vec::new(),
-				outputs: vec![Param {
-					name: "success or not".into(),
-					kind: ParamType::Bool,
-					internal_type: None,
-				}],
-				constant: None,
-				state_mutability: StateMutability::Payable,
-			},
-			&[],
+			DistributeRewardsCall,
 			ksc,
 			amount.into(),
-			1_000_000.into(),
-		) {
-			log::error!("failed to forward call due to {e:?}");
+			GasLimit::Estimated { budget: <T as Config>::AllocationGasBudget::get().into() },
+		)?;
 
-			<UnallocatedKtonRewards<T>>::mutate(|u| *u += amount);
-		}
+		ensure_succeed::<T>(info)
+	}
+
+	fn estimation_weight() -> Weight {
+		<darwinia_ethtx_forwarder::Pallet<T>>::estimation_weight(
+			<T as Config>::AllocationGasBudget::get().into(),
+		)
 	}
 }
 
+fn ensure_succeed<T>(info: CallOrCreateInfo) -> DispatchResult
+where
+	T: Config + darwinia_ethtx_forwarder::Config,
+{
+	let (exit_reason, data) = match info {
+		CallOrCreateInfo::Call(i) => (i.exit_reason, i.value),
+		CallOrCreateInfo::Create(i) => (i.exit_reason, Vec::new()),
+	};
+
+	<darwinia_ethtx_forwarder::Pallet<T>>::ensure_succeed(&exit_reason, &data).map_err(|e| {
+		log::error!("failed to allocate reward on contract due to {e:?}");
+
+		<Error<T>>::AllocationFailedOnContract.into()
+	})
+}
+
 /// The account of the staking pot.
 pub fn account_id<A>() -> A
 where
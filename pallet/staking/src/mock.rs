@@ -178,10 +178,16 @@ impl pallet_treasury::Config for Runtime {
 
 frame_support::parameter_types! {
 	pub static NextCollatorId: u64 = 1;
+	pub static AllocationFailure: bool = false;
+	pub static ElectionFailure: bool = false;
 }
 pub enum RingStaking {}
 impl crate::Election<AccountId> for RingStaking {
 	fn elect(x: u32) -> Option<Vec<AccountId>> {
+		if ElectionFailure::get() {
+			return None;
+		}
+
 		let start = NextCollatorId::get();
 		let end = start + x as u64;
 
@@ -205,30 +211,65 @@ impl crate::Election<AccountId> for RingStaking {
 	}
 }
 impl crate::Reward<AccountId> for RingStaking {
-	fn allocate(who: Option<AccountId>, amount: Balance) {
-		let Some(who) = who else { return };
+	fn allocate(who: Option<AccountId>, amount: Balance) -> DispatchResult {
+		if AllocationFailure::get() {
+			return Err("allocation failure".into());
+		}
+
+		let Some(who) = who else { return Ok(()) };
 		let _ = Balances::transfer_keep_alive(
 			RuntimeOrigin::signed(Treasury::account_id()),
 			who,
 			amount,
 		);
+
+		Ok(())
 	}
 }
 pub enum KtonStaking {}
 impl crate::Reward<AccountId> for KtonStaking {
-	fn allocate(_: Option<AccountId>, amount: Balance) {
+	fn allocate(_: Option<AccountId>, amount: Balance) -> DispatchResult {
+		if AllocationFailure::get() {
+			return Err("allocation failure".into());
+		}
+
 		let _ = Balances::transfer_keep_alive(
 			RuntimeOrigin::signed(TreasuryAccount::get()),
 			<KtonStakingContract<Runtime>>::get().unwrap(),
 			amount,
 		);
+
+		Ok(())
+	}
+}
+pub struct CurrentSlot;
+impl Get<u64> for CurrentSlot {
+	fn get() -> u64 {
+		System::block_number()
 	}
 }
+frame_support::parameter_types! {
+	pub const MinPerformance: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(50);
+}
 impl crate::Config for Runtime {
+	type AllocationGasBudget = ();
+	type Collators = crate::SessionCollators<Self>;
+	type CurrentSlot = CurrentSlot;
+	type ElectionGasLimit = ();
 	type KtonStaking = KtonStaking;
+	type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxCollators = frame_support::traits::ConstU32<5>;
+	type MaxEmissionSteps = frame_support::traits::ConstU32<2>;
+	type MaxInvulnerables = frame_support::traits::ConstU32<3>;
+	type MaxOffences = frame_support::traits::ConstU32<2>;
+	type MaxRetryAttempts = frame_support::traits::ConstU32<3>;
+	type MinPerformance = MinPerformance;
+	type RetryBaseDelay = frame_support::traits::ConstU64<2>;
+	type RewardCurve = crate::LinearRewardCurve;
 	type RewardPerSession = frame_support::traits::ConstU128<1_000>;
 	type RingStaking = RingStaking;
 	type RuntimeEvent = RuntimeEvent;
+	type SessionsPerEra = frame_support::traits::ConstU32<2>;
 	type Treasury = TreasuryAccount;
 	type WeightInfo = ();
 }
@@ -0,0 +1,48 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the staking pallet.
+
+// crates.io
+use codec::Codec;
+// darwinia
+use dc_types::Balance;
+// polkadot-sdk
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	/// Staking reward and session introspection.
+	pub trait StakingApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// Projected RING staking reward of each collator for the current session.
+		///
+		/// The projection is based on the blocks authored so far.
+		fn projected_session_rewards() -> Vec<(AccountId, Balance)>;
+
+		/// Pending and unallocated RING staking rewards of the collator.
+		fn reward_balances_of(who: AccountId) -> (Balance, Balance);
+
+		/// Unallocated KTON staking reward.
+		fn unallocated_kton_reward() -> Balance;
+
+		/// Collators elected for the next session.
+		fn next_collators() -> Vec<AccountId>;
+	}
+}
@@ -18,14 +18,13 @@
 
 // darwinia
 use crate::{mock::*, *};
+use dc_abi::SolCall;
 // polkadot-sdk
 use frame_support::{assert_noop, assert_ok, traits::OnIdle};
 use sp_runtime::DispatchError;
 
 #[test]
 fn get_top_collators_should_work() {
-	const ZERO: [u8; 20] = [0; 20];
-
 	let data = [
 		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 		0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
@@ -33,36 +32,12 @@ fn get_top_collators_should_work() {
 		254, 164, 111, 40, 130, 165, 178, 97, 83, 167, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 	];
-	#[allow(deprecated)]
-	let function = Function {
-		name: "getTopCollators".to_owned(),
-		inputs: vec![Param {
-			name: "k".to_owned(),
-			kind: ParamType::Uint(256),
-			internal_type: None,
-		}],
-		outputs: vec![Param {
-			name: "collators".to_owned(),
-			kind: ParamType::Array(Box::new(ParamType::Address)),
-			internal_type: None,
-		}],
-		constant: None,
-		state_mutability: StateMutability::View,
-	};
-	let output = function
-		.decode_output(&data)
-		.map(|o| {
-			let Some(Token::Array(addrs)) = o.into_iter().next() else { return Vec::new() };
-
-			addrs
-				.into_iter()
-				.filter_map(|addr| match addr {
-					Token::Address(addr) if addr.0 != ZERO => Some(addr.0),
-					_ => None,
-				})
-				.collect()
-		})
-		.unwrap();
+	let output = GetTopCollatorsCall::decode_output(&data)
+		.unwrap()
+		.into_iter()
+		.filter(|c| !c.is_zero())
+		.map(|c| c.0)
+		.collect::<Vec<_>>();
 
 	assert_eq!(
 		output,
@@ -118,6 +93,40 @@ fn on_idle_allocate_ring_staking_reward_should_work() {
 	});
 }
 
+#[test]
+fn requested_allocations_should_be_served_first() {
+	ExtBuilder.build().execute_with(|| {
+		(1..=512).for_each(|i| <PendingRewards<Runtime>>::insert(AccountId(i), 1));
+
+		assert_noop!(
+			Staking::request_ring_staking_reward_allocation(AccountId(513)),
+			<Error<Runtime>>::NoReward
+		);
+		assert_ok!(Staking::request_ring_staking_reward_allocation(AccountId(500)));
+		assert_ok!(Staking::request_ring_staking_reward_allocation(AccountId(500)));
+		assert_ok!(Staking::request_ring_staking_reward_allocation(AccountId(300)));
+		assert_eq!(
+			<AllocationRequests<Runtime>>::get().into_inner(),
+			[AccountId(500), AccountId(300)]
+		);
+
+		System::reset_events();
+		<Staking as OnIdle<_>>::on_idle(0, Weight::zero().add_ref_time(1));
+		assert_eq!(events(), [Event::RewardAllocated { who: AccountId(500), amount: 1 }]);
+		assert_eq!(<AllocationRequests<Runtime>>::get().into_inner(), [AccountId(300)]);
+
+		// Allocated in the meantime.
+		assert_ok!(Staking::allocate_ring_staking_reward_of(
+			RuntimeOrigin::signed(AccountId(1)),
+			AccountId(300)
+		));
+		System::reset_events();
+		<Staking as OnIdle<_>>::on_idle(0, Weight::zero().add_ref_time(1));
+		assert_eq!(events().len(), 1);
+		assert!(<AllocationRequests<Runtime>>::get().is_empty());
+	});
+}
+
 #[test]
 fn on_new_session_should_work() {
 	ExtBuilder.build().execute_with(|| {
@@ -184,6 +193,130 @@ fn on_new_session_should_work() {
 	});
 }
 
+#[test]
+fn reward_curves_should_work() {
+	assert_eq!(LinearRewardCurve::convert(Perbill::from_percent(80)), Perbill::from_percent(80));
+	assert_eq!(QuadraticRewardCurve::convert(Perbill::from_percent(80)), Perbill::from_percent(64));
+}
+
+#[test]
+fn assess_performance_should_work() {
+	ExtBuilder.build().execute_with(|| {
+		let who = AccountId(1);
+
+		System::reset_events();
+
+		// There is nothing to compare with.
+		assert_eq!(Staking::assess_performance(&who, 0, None, 100), 100);
+		assert_eq!(Staking::assess_performance(&who, 10, Some(10), 100), 100);
+		assert_eq!(Staking::assess_performance(&who, 8, Some(10), 100), 80);
+		assert_eq!(<Offences<Runtime>>::get(who), 0);
+
+		// Below the minimum performance.
+		assert_eq!(Staking::assess_performance(&who, 4, Some(10), 100), 0);
+		assert_eq!(<Offences<Runtime>>::get(who), 1);
+		assert_eq!(
+			events(),
+			[
+				Event::SlotsMissed { who, expected: 10, authored: 8, penalty: 20 },
+				Event::SlotsMissed { who, expected: 10, authored: 4, penalty: 100 }
+			]
+		);
+
+		// Recovered.
+		assert_eq!(Staking::assess_performance(&who, 10, Some(10), 100), 100);
+		assert_eq!(<Offences<Runtime>>::get(who), 0);
+	});
+}
+
+#[test]
+fn missed_slots_should_be_reported() {
+	ExtBuilder.build().execute_with(|| {
+		System::reset_events();
+
+		// Collator 3 misses all of its slots.
+		(0..<Period as Get<BlockNumber>>::get()).zip([1, 2].into_iter().cycle()).for_each(
+			|(_, i)| {
+				Staking::note_authors(&[AccountId(i)]);
+				Efflux::block(1);
+			},
+		);
+
+		assert_eq!(
+			events()
+				.into_iter()
+				.filter(|e| matches!(e, Event::SlotsMissed { .. }))
+				.collect::<Vec<_>>(),
+			[Event::SlotsMissed { who: AccountId(3), expected: 1, authored: 0, penalty: 0 }]
+		);
+		assert_eq!(<Offences<Runtime>>::get(AccountId(3)), 1);
+		assert!(!events()
+			.into_iter()
+			.any(|e| matches!(e, Event::RewardAllocated { who: AccountId(3), .. })));
+	});
+}
+
+#[test]
+fn repeat_offenders_should_be_excluded() {
+	ExtBuilder.build().execute_with(|| {
+		NEXT_COLLATOR_ID.with(|v| *v.borrow_mut() = 4);
+		new_session();
+		new_session();
+
+		assert_eq!(Session::validators(), [AccountId(4), AccountId(5), AccountId(6)]);
+
+		// Collator 4 misses all of its slots for the second time.
+		<Offences<Runtime>>::insert(AccountId(4), 1);
+		// Not elected, nothing to exclude.
+		<Offences<Runtime>>::insert(AccountId(100), 2);
+		System::reset_events();
+		(0..<Period as Get<BlockNumber>>::get()).zip([5, 6].into_iter().cycle()).for_each(
+			|(_, i)| {
+				Staking::note_authors(&[AccountId(i)]);
+				Efflux::block(1);
+			},
+		);
+
+		assert_eq!(
+			events()
+				.into_iter()
+				.filter(|e| matches!(e, Event::CollatorExcluded { .. }))
+				.collect::<Vec<_>>(),
+			[Event::CollatorExcluded { who: AccountId(4), offences: 2 }]
+		);
+		assert!(!<Offences<Runtime>>::contains_key(AccountId(4)));
+		assert_eq!(<Offences<Runtime>>::get(AccountId(100)), 2);
+
+		new_session();
+
+		assert_eq!(Session::validators(), [AccountId(5), AccountId(6), AccountId(7)]);
+	});
+}
+
+#[test]
+fn repeat_offenders_should_be_excluded_from_fallback() {
+	ExtBuilder.build().execute_with(|| {
+		ElectionFailure::set(true);
+		// Collator 1 misses all of its slots for the second time.
+		<Offences<Runtime>>::insert(AccountId(1), 1);
+		System::reset_events();
+		(0..<Period as Get<BlockNumber>>::get()).zip([2, 3].into_iter().cycle()).for_each(
+			|(_, i)| {
+				Staking::note_authors(&[AccountId(i)]);
+				Efflux::block(1);
+			},
+		);
+
+		assert!(events().contains(&Event::CollatorExcluded { who: AccountId(1), offences: 2 }));
+		assert!(events()
+			.contains(&Event::CollatorsElected { source: ElectionSource::PreviousSet, count: 2 }));
+
+		new_session();
+
+		assert_eq!(Session::validators(), [AccountId(2), AccountId(3)]);
+	});
+}
+
 #[test]
 fn allocate_ring_staking_reward_of_should_work() {
 	ExtBuilder.build().execute_with(|| {
@@ -215,6 +348,11 @@ fn set_collator_count_should_work() {
 
 		assert_ok!(Staking::set_collator_count(RuntimeOrigin::root(), 1));
 		assert_eq!(<CollatorCount<Runtime>>::get(), 1);
+
+		assert_noop!(
+			Staking::set_collator_count(RuntimeOrigin::root(), 6),
+			<Error<Runtime>>::TooManyCollators
+		);
 	});
 }
 
@@ -243,3 +381,359 @@ fn set_kton_staking_contract_should_work() {
 		assert_eq!(<KtonStakingContract<Runtime>>::get(), Some(AccountId(1)));
 	});
 }
+
+#[test]
+fn failed_allocation_should_be_parked_and_retried() {
+	ExtBuilder.build().execute_with(|| {
+		let who = AccountId(1);
+
+		AllocationFailure::set(true);
+		<PendingRewards<Runtime>>::insert(who, 100);
+		System::reset_events();
+
+		assert_ok!(Staking::allocate_ring_staking_reward_of(RuntimeOrigin::signed(who), who));
+		assert_eq!(
+			events(),
+			[Event::RewardAllocationFailed { who: Some(who), amount: 100, attempts: 0 }]
+		);
+		assert_eq!(<UnallocatedRingRewards<Runtime>>::get(who), Some(100));
+		assert_eq!(
+			<RingRewardRetryStates<Runtime>>::get(who),
+			Some(RetryState { attempts: 0, next_attempt: 7 })
+		);
+
+		// A new failure is not a retry, it joins the parked reward.
+		<PendingRewards<Runtime>>::insert(who, 100);
+		System::set_block_number(6);
+		assert_ok!(Staking::allocate_ring_staking_reward_of(RuntimeOrigin::signed(who), who));
+		assert_eq!(<UnallocatedRingRewards<Runtime>>::get(who), Some(200));
+		assert_eq!(
+			<RingRewardRetryStates<Runtime>>::get(who),
+			Some(RetryState { attempts: 0, next_attempt: 7 })
+		);
+
+		// Not yet.
+		AllocationFailure::set(false);
+		System::reset_events();
+		<Staking as OnIdle<_>>::on_idle(6, Weight::MAX);
+		assert_eq!(events(), []);
+
+		System::set_block_number(7);
+		<Staking as OnIdle<_>>::on_idle(7, Weight::MAX);
+		assert_eq!(events(), [Event::UnallocatedRewardAllocated { who: Some(who), amount: 200 }]);
+		assert_eq!(<UnallocatedRingRewards<Runtime>>::get(who), None);
+		assert_eq!(<RingRewardRetryStates<Runtime>>::get(who), None);
+	});
+}
+
+#[test]
+fn retry_should_back_off_then_escalate() {
+	ExtBuilder.build().execute_with(|| {
+		let who = AccountId(1);
+
+		AllocationFailure::set(true);
+		<PendingRewards<Runtime>>::insert(who, 100);
+		assert_ok!(Staking::allocate_ring_staking_reward_of(RuntimeOrigin::signed(who), who));
+
+		System::set_block_number(7);
+		<Staking as OnIdle<_>>::on_idle(7, Weight::MAX);
+		assert_eq!(
+			<RingRewardRetryStates<Runtime>>::get(who),
+			Some(RetryState { attempts: 1, next_attempt: 11 })
+		);
+
+		System::set_block_number(11);
+		<Staking as OnIdle<_>>::on_idle(11, Weight::MAX);
+		assert_eq!(
+			<RingRewardRetryStates<Runtime>>::get(who),
+			Some(RetryState { attempts: 2, next_attempt: 19 })
+		);
+
+		System::reset_events();
+		System::set_block_number(19);
+		<Staking as OnIdle<_>>::on_idle(19, Weight::MAX);
+		assert_eq!(
+			events(),
+			[
+				Event::RewardAllocationFailed { who: Some(who), amount: 100, attempts: 3 },
+				Event::RetryEscalated { who: Some(who), amount: 100 }
+			]
+		);
+
+		// Escalated.
+		AllocationFailure::set(false);
+		System::reset_events();
+		System::set_block_number(1_000);
+		<Staking as OnIdle<_>>::on_idle(1_000, Weight::MAX);
+		assert_eq!(events(), []);
+		assert_noop!(
+			Staking::retry_unallocated_reward(RuntimeOrigin::signed(who), Some(who)),
+			<Error<Runtime>>::RetryEscalated
+		);
+		assert_noop!(
+			Staking::force_retry_unallocated_reward(RuntimeOrigin::signed(who), Some(who)),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Staking::force_retry_unallocated_reward(RuntimeOrigin::root(), Some(who)));
+		assert_eq!(events(), [Event::UnallocatedRewardAllocated { who: Some(who), amount: 100 }]);
+		assert_eq!(<UnallocatedRingRewards<Runtime>>::get(who), None);
+		assert_eq!(<RingRewardRetryStates<Runtime>>::get(who), None);
+	});
+}
+
+#[test]
+fn on_idle_retry_should_resume_from_cursor() {
+	ExtBuilder.build().execute_with(|| {
+		(1..=3).for_each(|i| {
+			<UnallocatedRingRewards<Runtime>>::insert(AccountId(i), 100);
+		});
+
+		let keys = <UnallocatedRingRewards<Runtime>>::iter_keys().collect::<Vec<_>>();
+
+		// Only enough weight to check the cursor and the KTON reward, then one entry.
+		<Staking as OnIdle<_>>::on_idle(
+			0,
+			<Runtime as frame_system::Config>::DbWeight::get().reads_writes(5, 1)
+				+ Weight::zero().add_ref_time(1),
+		);
+		assert_eq!(<RetryCursor<Runtime>>::get(), Some(keys[0]));
+		assert_eq!(<UnallocatedRingRewards<Runtime>>::get(keys[0]), None);
+		assert_eq!(<UnallocatedRingRewards<Runtime>>::get(keys[1]), Some(100));
+
+		// The rest, then start over.
+		<Staking as OnIdle<_>>::on_idle(0, Weight::MAX);
+		assert_eq!(<RetryCursor<Runtime>>::get(), None);
+		assert_eq!(<UnallocatedRingRewards<Runtime>>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn retry_unallocated_kton_reward_should_work() {
+	ExtBuilder.build().execute_with(|| {
+		let ksc = <KtonStakingContract<Runtime>>::get().unwrap();
+		let ksc_balance = Balances::free_balance(ksc);
+
+		assert_noop!(
+			Staking::retry_unallocated_reward(RuntimeOrigin::signed(AccountId(1)), None),
+			<Error<Runtime>>::NoReward
+		);
+
+		AllocationFailure::set(true);
+		Staking::allocate_session_reward(1_000);
+		assert_eq!(<UnallocatedKtonRewards<Runtime>>::get(), 500);
+		assert_eq!(<KtonRewardRetryState<Runtime>>::get().unwrap().attempts, 0);
+
+		AllocationFailure::set(false);
+		System::reset_events();
+		assert_ok!(Staking::retry_unallocated_reward(RuntimeOrigin::signed(AccountId(1)), None));
+		assert_eq!(events(), [Event::UnallocatedRewardAllocated { who: None, amount: 500 }]);
+		assert_eq!(<UnallocatedKtonRewards<Runtime>>::get(), 0);
+		assert_eq!(<KtonRewardRetryState<Runtime>>::get(), None);
+		assert_eq!(Balances::free_balance(ksc), ksc_balance + 500);
+	});
+}
+
+#[test]
+fn projected_session_rewards_should_work() {
+	ExtBuilder.build().execute_with(|| {
+		Staking::note_authors(&[AccountId(1), AccountId(1), AccountId(2)]);
+		System::set_block_number(11);
+
+		assert_eq!(
+			Staking::projected_session_rewards(),
+			[(AccountId(1), 333), (AccountId(2), 83), (AccountId(3), 0)]
+		);
+		// Projection must not touch the state.
+		assert_eq!(<AuthoredBlockCount<Runtime>>::get().0, 3);
+		assert!(!<Offences<Runtime>>::contains_key(AccountId(3)));
+
+		<PendingRewards<Runtime>>::insert(AccountId(1), 1);
+		<UnallocatedRingRewards<Runtime>>::insert(AccountId(1), 2);
+
+		assert_eq!(Staking::reward_balances_of(&AccountId(1)), (1, 2));
+		assert_eq!(Staking::reward_balances_of(&AccountId(2)), (0, 0));
+	});
+}
+
+#[test]
+fn election_should_fall_back() {
+	ExtBuilder.build().execute_with(|| {
+		assert_eq!(<LastElectionSource<Runtime>>::get(), Some(ElectionSource::Contract));
+
+		ElectionFailure::set(true);
+		System::reset_events();
+		new_session();
+
+		assert_eq!(
+			events()
+				.into_iter()
+				.filter(|e| matches!(
+					e,
+					Event::ElectionSourceFailed { .. } | Event::CollatorsElected { .. }
+				))
+				.collect::<Vec<_>>(),
+			[
+				Event::ElectionSourceFailed { source: ElectionSource::Contract },
+				Event::ElectionSourceFailed { source: ElectionSource::Invulnerables },
+				Event::CollatorsElected { source: ElectionSource::PreviousSet, count: 3 }
+			]
+		);
+		assert_eq!(<LastElectionSource<Runtime>>::get(), Some(ElectionSource::PreviousSet));
+
+		assert_ok!(Staking::set_invulnerables(
+			RuntimeOrigin::root(),
+			vec![AccountId(7), AccountId(8)]
+		));
+		System::reset_events();
+		new_session();
+		new_session();
+
+		assert!(events().contains(&Event::CollatorsElected {
+			source: ElectionSource::Invulnerables,
+			count: 2
+		}));
+		assert_eq!(<LastElectionSource<Runtime>>::get(), Some(ElectionSource::Invulnerables));
+		assert_eq!(Session::validators(), [AccountId(7), AccountId(8)]);
+
+		ElectionFailure::set(false);
+		new_session();
+
+		assert_eq!(<LastElectionSource<Runtime>>::get(), Some(ElectionSource::Contract));
+	});
+}
+
+#[test]
+fn set_invulnerables_should_work() {
+	ExtBuilder.build().execute_with(|| {
+		assert_noop!(
+			Staking::set_invulnerables(RuntimeOrigin::signed(AccountId(1)), vec![AccountId(1)]),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Staking::set_invulnerables(RuntimeOrigin::root(), (1..=4).map(AccountId).collect()),
+			<Error<Runtime>>::TooManyInvulnerables
+		);
+
+		assert_ok!(Staking::set_invulnerables(RuntimeOrigin::root(), vec![AccountId(1)]));
+		assert_eq!(<Invulnerables<Runtime>>::get().into_inner(), [AccountId(1)]);
+	});
+}
+
+#[test]
+fn set_election_sources_should_work() {
+	ExtBuilder.build().execute_with(|| {
+		assert_noop!(
+			Staking::set_election_sources(
+				RuntimeOrigin::signed(AccountId(1)),
+				vec![ElectionSource::Contract]
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Staking::set_election_sources(RuntimeOrigin::root(), Vec::new()),
+			<Error<Runtime>>::InvalidElectionSources
+		);
+		assert_noop!(
+			Staking::set_election_sources(
+				RuntimeOrigin::root(),
+				vec![ElectionSource::Contract, ElectionSource::Contract]
+			),
+			<Error<Runtime>>::InvalidElectionSources
+		);
+
+		assert_ok!(Staking::set_election_sources(
+			RuntimeOrigin::root(),
+			vec![ElectionSource::Invulnerables, ElectionSource::Contract]
+		));
+		assert_eq!(
+			<ElectionSources<Runtime>>::get().into_inner(),
+			[ElectionSource::Invulnerables, ElectionSource::Contract]
+		);
+
+		// Empty invulnerables, fall back to the contract.
+		System::reset_events();
+		new_session();
+
+		assert!(events()
+			.contains(&Event::ElectionSourceFailed { source: ElectionSource::Invulnerables }));
+		assert_eq!(<LastElectionSource<Runtime>>::get(), Some(ElectionSource::Contract));
+	});
+}
+
+#[test]
+fn ring_reward_ratio_should_work() {
+	ExtBuilder.build().execute_with(|| {
+		let ksc = <KtonStakingContract<Runtime>>::get().unwrap();
+		let ksc_balance = Balances::free_balance(ksc);
+
+		assert_noop!(
+			Staking::set_ring_reward_ratio(
+				RuntimeOrigin::signed(AccountId(1)),
+				Perbill::from_percent(80)
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Staking::set_ring_reward_ratio(
+			RuntimeOrigin::root(),
+			Perbill::from_percent(80)
+		));
+
+		Staking::note_authors(&[AccountId(1)]);
+		Staking::allocate_session_reward(1_000);
+
+		assert_eq!(<PendingRewards<Runtime>>::get(AccountId(1)), Some(800));
+		assert_eq!(Balances::free_balance(ksc), ksc_balance + 200);
+	});
+}
+
+#[test]
+fn emission_schedule_should_work() {
+	ExtBuilder.build().execute_with(|| {
+		assert_eq!(Staking::reward_per_session(), 1_000);
+
+		assert_noop!(
+			Staking::set_emission_schedule(
+				RuntimeOrigin::root(),
+				vec![
+					EmissionStep { from_era: 2, reward_per_session: 500 },
+					EmissionStep { from_era: 2, reward_per_session: 250 }
+				]
+			),
+			<Error<Runtime>>::InvalidEmissionSchedule
+		);
+		assert_noop!(
+			Staking::set_emission_schedule(
+				RuntimeOrigin::root(),
+				(1..=3).map(|i| EmissionStep { from_era: i, reward_per_session: 1 }).collect()
+			),
+			<Error<Runtime>>::TooManyEmissionSteps
+		);
+		assert_ok!(Staking::set_emission_schedule(
+			RuntimeOrigin::root(),
+			vec![
+				EmissionStep { from_era: 2, reward_per_session: 500 },
+				EmissionStep { from_era: 4, reward_per_session: 250 }
+			]
+		));
+
+		assert_eq!(Staking::reward_per_session_at(1), 1_000);
+		assert_eq!(Staking::reward_per_session_at(2), 500);
+		assert_eq!(Staking::reward_per_session_at(3), 500);
+		assert_eq!(Staking::reward_per_session_at(4), 250);
+		assert_eq!(Staking::reward_per_session_at(u32::MAX), 250);
+
+		// 2 sessions per era.
+		(0..3).for_each(|_| new_session());
+
+		assert_eq!(<CurrentEra<Runtime>>::get(), 2);
+		assert_eq!(Staking::reward_per_session(), 500);
+
+		let ksc = <KtonStakingContract<Runtime>>::get().unwrap();
+		let ksc_balance = Balances::free_balance(ksc);
+
+		new_session();
+
+		assert_eq!(Balances::free_balance(ksc), ksc_balance + 250);
+	});
+}
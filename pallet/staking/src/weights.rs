@@ -22,6 +22,11 @@
 //! DATE: 2024-10-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: Some("koi-dev"), DB CACHE: 1024
+//!
+//! PLACEHOLDERS: the weights of `retry_unallocated_reward`, `force_retry_unallocated_reward`,
+//! `set_election_sources`, `set_invulnerables`, `set_emission_schedule` and `set_ring_reward_ratio`
+//! are estimated by hand from their storage accesses, not measured. Regenerate this file with the
+//! benchmark CLI before the release.
 
 // Executed Command:
 // target/release/darwinia
@@ -56,6 +61,12 @@ pub trait WeightInfo {
 	fn set_ring_staking_contract() -> Weight;
 	fn set_kton_staking_contract() -> Weight;
 	fn set_collator_count() -> Weight;
+	fn retry_unallocated_reward() -> Weight;
+	fn force_retry_unallocated_reward() -> Weight;
+	fn set_invulnerables(n: u32) -> Weight;
+	fn set_election_sources() -> Weight;
+	fn set_ring_reward_ratio() -> Weight;
+	fn set_emission_schedule(n: u32) -> Weight;
 }
 
 /// Weights for darwinia_staking using the Substrate node and recommended hardware.
@@ -108,6 +119,86 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `DarwiniaStaking::RingRewardRetryStates` (r:1 w:1)
+	/// Proof: `DarwiniaStaking::RingRewardRetryStates` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `DarwiniaStaking::UnallocatedRingRewards` (r:1 w:1)
+	/// Proof: `DarwiniaStaking::UnallocatedRingRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DarwiniaStaking::RingStakingContract` (r:1 w:0)
+	/// Proof: `DarwiniaStaking::RingStakingContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn retry_unallocated_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `3762`
+		// Minimum execution time: 16_000 nanoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(3762, 0))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DarwiniaStaking::RingRewardRetryStates` (r:0 w:1)
+	/// Proof: `DarwiniaStaking::RingRewardRetryStates` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `DarwiniaStaking::UnallocatedRingRewards` (r:1 w:1)
+	/// Proof: `DarwiniaStaking::UnallocatedRingRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DarwiniaStaking::RingStakingContract` (r:1 w:0)
+	/// Proof: `DarwiniaStaking::RingStakingContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn force_retry_unallocated_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `3762`
+		// Minimum execution time: 16_000 nanoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(3762, 0))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DarwiniaStaking::Invulnerables` (r:0 w:1)
+	/// Proof: `DarwiniaStaking::Invulnerables` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 32]`.
+	fn set_invulnerables(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_000 nanoseconds.
+		Weight::from_parts(3_524_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(9_841, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DarwiniaStaking::ElectionSources` (r:0 w:1)
+	/// Proof: `DarwiniaStaking::ElectionSources` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_election_sources() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_000 nanoseconds.
+		Weight::from_parts(2_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DarwiniaStaking::RingRewardRatio` (r:0 w:1)
+	/// Proof: `DarwiniaStaking::RingRewardRatio` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_ring_reward_ratio() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_000 nanoseconds.
+		Weight::from_parts(2_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DarwiniaStaking::EmissionSchedule` (r:0 w:1)
+	/// Proof: `DarwiniaStaking::EmissionSchedule` (`max_values`: Some(1), `max_size`: Some(1281), added: 1776, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn set_emission_schedule(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_000 nanoseconds.
+		Weight::from_parts(3_412_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(11_352, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -159,4 +250,84 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `DarwiniaStaking::RingRewardRetryStates` (r:1 w:1)
+	/// Proof: `DarwiniaStaking::RingRewardRetryStates` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `DarwiniaStaking::UnallocatedRingRewards` (r:1 w:1)
+	/// Proof: `DarwiniaStaking::UnallocatedRingRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DarwiniaStaking::RingStakingContract` (r:1 w:0)
+	/// Proof: `DarwiniaStaking::RingStakingContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn retry_unallocated_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `3762`
+		// Minimum execution time: 16_000 nanoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(3762, 0))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DarwiniaStaking::RingRewardRetryStates` (r:0 w:1)
+	/// Proof: `DarwiniaStaking::RingRewardRetryStates` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `DarwiniaStaking::UnallocatedRingRewards` (r:1 w:1)
+	/// Proof: `DarwiniaStaking::UnallocatedRingRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DarwiniaStaking::RingStakingContract` (r:1 w:0)
+	/// Proof: `DarwiniaStaking::RingStakingContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn force_retry_unallocated_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `3762`
+		// Minimum execution time: 16_000 nanoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(3762, 0))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DarwiniaStaking::Invulnerables` (r:0 w:1)
+	/// Proof: `DarwiniaStaking::Invulnerables` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 32]`.
+	fn set_invulnerables(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_000 nanoseconds.
+		Weight::from_parts(3_524_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(9_841, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DarwiniaStaking::ElectionSources` (r:0 w:1)
+	/// Proof: `DarwiniaStaking::ElectionSources` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_election_sources() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_000 nanoseconds.
+		Weight::from_parts(2_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DarwiniaStaking::RingRewardRatio` (r:0 w:1)
+	/// Proof: `DarwiniaStaking::RingRewardRatio` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_ring_reward_ratio() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_000 nanoseconds.
+		Weight::from_parts(2_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DarwiniaStaking::EmissionSchedule` (r:0 w:1)
+	/// Proof: `DarwiniaStaking::EmissionSchedule` (`max_values`: Some(1), `max_size`: Some(1281), added: 1776, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn set_emission_schedule(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_000 nanoseconds.
+		Weight::from_parts(3_412_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(11_352, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
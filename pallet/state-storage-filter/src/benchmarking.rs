@@ -0,0 +1,61 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// darwinia
+use crate::*;
+// polkadot-sdk
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+#[v2::benchmarks]
+mod benchmarks {
+	// darwinia
+	use super::*;
+
+	#[benchmark]
+	fn set_rule() {
+		// Worst-case scenario:
+		//
+		// Set an item rule successfully.
+		#[extrinsic_call]
+		_(
+			RawOrigin::Root,
+			ReadPrefix::Item([1; 16], [2; 16]),
+			ReadRule::Allow { gas_multiplier: u32::MAX },
+		);
+	}
+
+	#[benchmark]
+	fn remove_rule() {
+		let p = ReadPrefix::Item([1; 16], [2; 16]);
+
+		<Pallet<T>>::do_set_rule(p, ReadRule::Deny).unwrap();
+
+		// Worst-case scenario:
+		//
+		// Remove an item rule successfully.
+		#[extrinsic_call]
+		_(RawOrigin::Root, p);
+	}
+
+	frame_benchmarking::impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Runtime
+	);
+}
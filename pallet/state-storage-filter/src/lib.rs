@@ -0,0 +1,223 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Darwinia state storage filter pallet
+//!
+//! ## Overview
+//!
+//! Hold the read rules of the state storage precompile, so that the storage of the sensitive
+//! pallets can be hidden from the EVM, or made more expensive to read, by the governance without
+//! a runtime upgrade.
+//!
+//! A rule is set on either a pallet prefix or a storage item prefix. The storage item rule takes
+//! precedence over the pallet rule, and the keys without any rule are readable at the normal cost.
+//!
+//! [`Config::DefaultRules`] are set at genesis. A live chain sets them once through
+//! [`Pallet::set_defaults`] in the runtime upgrade which adds the pallet, so a default rule removed
+//! by the governance stays removed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![deny(missing_docs)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod weights;
+pub use weights::WeightInfo;
+
+// darwinia
+use darwinia_precompile_state_storage::{StorageFilterT, PALLET_PREFIX_LENGTH};
+// polkadot-sdk
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use sp_std::prelude::*;
+
+/// Storage prefix that a read rule applies to.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum ReadPrefix {
+	/// `Twox128(pallet)`.
+	Pallet([u8; 16]),
+	/// `Twox128(pallet) ++ Twox128(item)`.
+	Item([u8; 16], [u8; 16]),
+}
+
+/// Read rule of a storage prefix.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum ReadRule {
+	/// The storage under the prefix can not be read.
+	Deny,
+	/// The storage under the prefix can be read with the read gas multiplied.
+	Allow {
+		/// Read gas multiplier, must not be zero.
+		gas_multiplier: u32,
+	},
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	// darwinia
+	use crate::*;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Override the [`frame_system::Config::RuntimeEvent`].
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for extrinsic in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Rules set at genesis or by [`Pallet::set_defaults`].
+		type DefaultRules: Get<Vec<(ReadPrefix, ReadRule)>>;
+
+		/// Origin allowed to manage the rules.
+		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+	}
+
+	#[allow(missing_docs)]
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A read rule has been set.
+		RuleSet { prefix: ReadPrefix, rule: ReadRule },
+		/// A read rule has been removed.
+		RuleRemoved { prefix: ReadPrefix },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Gas multiplier must not be zero.
+		ZeroGasMultiplier,
+		/// Rule does not exist.
+		RuleNotFound,
+	}
+
+	/// Read rules of the storage prefixes.
+	#[pallet::storage]
+	pub type Rules<T: Config> = StorageMap<_, Twox64Concat, ReadPrefix, ReadRule>;
+
+	#[derive(frame_support::DefaultNoBound)]
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		#[allow(missing_docs)]
+		pub _marker: PhantomData<T>,
+	}
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			<Pallet<T>>::set_defaults();
+		}
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the read rule of a storage prefix.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_rule())]
+		pub fn set_rule(
+			origin: OriginFor<T>,
+			prefix: ReadPrefix,
+			rule: ReadRule,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			Self::do_set_rule(prefix, rule)
+		}
+
+		/// Remove the read rule of a storage prefix.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_rule())]
+		pub fn remove_rule(origin: OriginFor<T>, prefix: ReadPrefix) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			<Rules<T>>::take(prefix).ok_or(<Error<T>>::RuleNotFound)?;
+
+			Self::deposit_event(Event::RuleRemoved { prefix });
+
+			Ok(())
+		}
+	}
+	impl<T: Config> Pallet<T> {
+		/// Set the read rule of a storage prefix.
+		pub fn do_set_rule(prefix: ReadPrefix, rule: ReadRule) -> DispatchResult {
+			ensure!(rule != ReadRule::Allow { gas_multiplier: 0 }, <Error<T>>::ZeroGasMultiplier);
+
+			<Rules<T>>::insert(prefix, rule);
+
+			Self::deposit_event(Event::RuleSet { prefix, rule });
+
+			Ok(())
+		}
+
+		/// Rule applied to the storage key.
+		pub fn rule_of(key: &[u8]) -> Option<ReadRule> {
+			let pallet = key.get(..PALLET_PREFIX_LENGTH)?.try_into().ok()?;
+			let item = key
+				.get(PALLET_PREFIX_LENGTH..PALLET_PREFIX_LENGTH * 2)
+				.and_then(|i| i.try_into().ok())
+				.and_then(|i| <Rules<T>>::get(ReadPrefix::Item(pallet, i)));
+
+			item.or_else(|| <Rules<T>>::get(ReadPrefix::Pallet(pallet)))
+		}
+
+		/// Set the [`Config::DefaultRules`], the prefixes which already have a rule are kept.
+		///
+		/// Return the consumed weight.
+		pub fn set_defaults() -> Weight {
+			let defaults = T::DefaultRules::get();
+			let count = defaults.len() as u64;
+
+			defaults.into_iter().for_each(|(prefix, rule)| {
+				if !<Rules<T>>::contains_key(prefix) {
+					<Rules<T>>::insert(prefix, rule);
+				}
+			});
+
+			T::DbWeight::get().reads_writes(count, count)
+		}
+	}
+}
+pub use pallet::*;
+
+impl<T> StorageFilterT for Pallet<T>
+where
+	T: Config,
+{
+	// `Twox64Concat` hashed key and the value of a rule.
+	const LOOKUP_MAX_ENCODED_LEN: usize = 46;
+	// The storage item rule and then the pallet rule.
+	const MAX_LOOKUP_READS: u32 = 2;
+
+	fn read_multiplier(key: &[u8]) -> Option<u32> {
+		match Self::rule_of(key) {
+			Some(ReadRule::Deny) => None,
+			Some(ReadRule::Allow { gas_multiplier }) => Some(gas_multiplier),
+			None => Some(1),
+		}
+	}
+}
@@ -0,0 +1,62 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// darwinia
+use crate::*;
+// polkadot-sdk
+use frame_support::derive_impl;
+use sp_io::TestExternalities;
+use sp_runtime::BuildStorage;
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = frame_system::mocking::MockBlock<Self>;
+}
+
+frame_support::parameter_types! {
+	pub DefaultRules: Vec<(ReadPrefix, ReadRule)> = vec![(ReadPrefix::Pallet([0; 16]), ReadRule::Deny)];
+}
+impl crate::Config for Runtime {
+	type DefaultRules = DefaultRules;
+	type ManagerOrigin = frame_system::EnsureRoot<u64>;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+}
+
+frame_support::construct_runtime! {
+	pub enum Runtime {
+		System: frame_system,
+		StateStorageFilter: crate,
+	}
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let mut storage = <frame_system::GenesisConfig<Runtime>>::default().build_storage().unwrap();
+
+	crate::GenesisConfig::<Runtime>::default().assimilate_storage(&mut storage).unwrap();
+
+	let mut ext = TestExternalities::new(storage);
+
+	ext.execute_with(|| System::set_block_number(1));
+
+	ext
+}
+
+pub fn events() -> Vec<Event<Runtime>> {
+	System::read_events_for_pallet()
+}
@@ -0,0 +1,138 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// darwinia
+use crate::{mock::*, *};
+// polkadot-sdk
+use frame_support::{assert_noop, assert_ok, traits::GetStorageVersion};
+use sp_runtime::DispatchError;
+
+fn key(pallet: u8, item: u8) -> Vec<u8> {
+	[[pallet; 16], [item; 16], [7; 16]].concat()
+}
+
+#[test]
+fn genesis_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(StateStorageFilter::read_multiplier(&key(0, 1)), None);
+		assert_eq!(StateStorageFilter::read_multiplier(&key(1, 1)), Some(1));
+		assert_eq!(StateStorageFilter::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn set_defaults_should_work() {
+	new_test_ext().execute_with(|| {
+		let p = ReadPrefix::Pallet([0; 16]);
+
+		let _ = <Rules<Runtime>>::clear(u32::MAX, None);
+
+		StateStorageFilter::set_defaults();
+
+		assert_eq!(<Rules<Runtime>>::get(p), Some(ReadRule::Deny));
+		assert_eq!(StateStorageFilter::read_multiplier(&key(0, 1)), None);
+
+		// Idempotent, the existing rules are kept.
+		assert_ok!(StateStorageFilter::set_rule(
+			RuntimeOrigin::root(),
+			p,
+			ReadRule::Allow { gas_multiplier: 2 }
+		));
+
+		StateStorageFilter::set_defaults();
+
+		assert_eq!(<Rules<Runtime>>::get(p), Some(ReadRule::Allow { gas_multiplier: 2 }));
+	});
+}
+
+#[test]
+fn lookup_max_encoded_len_should_match() {
+	assert_eq!(
+		<StateStorageFilter as StorageFilterT>::LOOKUP_MAX_ENCODED_LEN,
+		8 + ReadPrefix::max_encoded_len() + ReadRule::max_encoded_len()
+	);
+}
+
+#[test]
+fn set_rule_should_work() {
+	new_test_ext().execute_with(|| {
+		let p = ReadPrefix::Pallet([1; 16]);
+
+		assert_noop!(
+			StateStorageFilter::set_rule(RuntimeOrigin::signed(1), p, ReadRule::Deny),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			StateStorageFilter::set_rule(
+				RuntimeOrigin::root(),
+				p,
+				ReadRule::Allow { gas_multiplier: 0 }
+			),
+			<Error<Runtime>>::ZeroGasMultiplier
+		);
+
+		assert_ok!(StateStorageFilter::set_rule(
+			RuntimeOrigin::root(),
+			p,
+			ReadRule::Allow { gas_multiplier: 5 }
+		));
+		assert_eq!(StateStorageFilter::read_multiplier(&key(1, 1)), Some(5));
+		assert_eq!(
+			events(),
+			vec![Event::RuleSet { prefix: p, rule: ReadRule::Allow { gas_multiplier: 5 } }]
+		);
+	});
+}
+
+#[test]
+fn item_rule_should_take_precedence() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(StateStorageFilter::set_rule(
+			RuntimeOrigin::root(),
+			ReadPrefix::Pallet([1; 16]),
+			ReadRule::Deny
+		));
+		assert_ok!(StateStorageFilter::set_rule(
+			RuntimeOrigin::root(),
+			ReadPrefix::Item([1; 16], [2; 16]),
+			ReadRule::Allow { gas_multiplier: 2 }
+		));
+
+		assert_eq!(StateStorageFilter::read_multiplier(&key(1, 1)), None);
+		assert_eq!(StateStorageFilter::read_multiplier(&key(1, 2)), Some(2));
+		// Pallet prefix only.
+		assert_eq!(StateStorageFilter::read_multiplier(&[1; 16]), None);
+		assert_eq!(StateStorageFilter::read_multiplier(&[2; 16]), Some(1));
+	});
+}
+
+#[test]
+fn remove_rule_should_work() {
+	new_test_ext().execute_with(|| {
+		let p = ReadPrefix::Pallet([0; 16]);
+
+		assert_noop!(
+			StateStorageFilter::remove_rule(RuntimeOrigin::root(), ReadPrefix::Pallet([1; 16])),
+			<Error<Runtime>>::RuleNotFound
+		);
+
+		assert_ok!(StateStorageFilter::remove_rule(RuntimeOrigin::root(), p));
+		assert_eq!(StateStorageFilter::read_multiplier(&key(0, 1)), Some(1));
+		assert_eq!(events(), vec![Event::RuleRemoved { prefix: p }]);
+	});
+}
@@ -0,0 +1,118 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! EIP-2612 permit of the assets precompile.
+
+// darwinia
+use crate::*;
+// polkadot-sdk
+use frame_support::{
+	storage::types::{StorageDoubleMap, ValueQuery},
+	traits::{Get, StorageInstance},
+	Blake2_128Concat,
+};
+use sp_core::H256;
+use sp_io::hashing::keccak_256;
+use sp_std::prelude::*;
+
+/// EIP-712 type hash of the EIP-2612 permit, which is the Keccak of the type.
+pub const PERMIT_TYPEHASH: [u8; 32] = keccak256!(
+	"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)"
+);
+
+/// EIP-712 type hash of the domain, which is the Keccak of the type.
+pub const PERMIT_DOMAIN: [u8; 32] = keccak256!(
+	"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+);
+
+/// Half of the secp256k1 curve order.
+///
+/// A signature whose `s` is above this is the malleable counterpart of another one, see EIP-2.
+pub const SECP256K1N_HALF: [u8; 32] = [
+	0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+	0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+/// Storage prefix of [`NoncesStorage`].
+pub struct NoncesPrefix;
+impl StorageInstance for NoncesPrefix {
+	const STORAGE_PREFIX: &'static str = "Nonces";
+
+	fn pallet_prefix() -> &'static str {
+		"ERC20Assets"
+	}
+}
+
+/// Permit nonces of each asset and owner.
+pub type NoncesStorage<AssetId> = StorageDoubleMap<
+	NoncesPrefix,
+	Blake2_128Concat,
+	AssetId,
+	Blake2_128Concat,
+	H160,
+	U256,
+	ValueQuery,
+>;
+
+/// EIP-712 domain separator of the asset precompile at `address`.
+pub fn domain_separator<Runtime>(address: H160, asset_id: AssetIdOf<Runtime>) -> [u8; 32]
+where
+	Runtime: pallet_assets::Config + pallet_evm::Config,
+{
+	let name = pallet_assets::Pallet::<Runtime>::name(asset_id);
+	let chain_id = U256::from(<Runtime as pallet_evm::Config>::ChainId::get());
+
+	keccak_256(&solidity::encode_arguments((
+		H256::from(PERMIT_DOMAIN),
+		H256::from(keccak_256(&name)),
+		H256::from(keccak256!("1")),
+		chain_id,
+		Address(address),
+	)))
+}
+
+/// EIP-712 digest which the owner signs to permit the spender.
+#[allow(clippy::too_many_arguments)]
+pub fn permit_digest<Runtime>(
+	address: H160,
+	asset_id: AssetIdOf<Runtime>,
+	owner: H160,
+	spender: H160,
+	value: U256,
+	nonce: U256,
+	deadline: U256,
+) -> [u8; 32]
+where
+	Runtime: pallet_assets::Config + pallet_evm::Config,
+{
+	let permit = keccak_256(&solidity::encode_arguments((
+		H256::from(PERMIT_TYPEHASH),
+		Address(owner),
+		Address(spender),
+		value,
+		nonce,
+		deadline,
+	)));
+	let mut pre_digest = Vec::with_capacity(2 + 32 + 32);
+
+	pre_digest.extend_from_slice(b"\x19\x01");
+	pre_digest.extend_from_slice(&domain_separator::<Runtime>(address, asset_id));
+	pre_digest.extend_from_slice(&permit);
+
+	keccak_256(&pre_digest)
+}
@@ -23,6 +23,9 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod eip2612;
+use eip2612::NoncesStorage;
+
 // std
 use core::marker::PhantomData;
 // polkadot-sdk
@@ -33,12 +36,16 @@ use frame_support::{
 		fungibles::{
 			approvals::Inspect as ApprovalInspect, metadata::Inspect as MetadataInspect, Inspect,
 		},
-		OriginTrait,
+		Currency, OriginTrait, Time,
 	},
 };
-use sp_core::{MaxEncodedLen, H160, U256};
-use sp_runtime::traits::Bounded;
-use sp_std::convert::{TryFrom, TryInto};
+use sp_core::{ConstU32, MaxEncodedLen, H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::traits::{Bounded, UniqueSaturatedInto};
+use sp_std::{
+	convert::{TryFrom, TryInto},
+	vec::Vec,
+};
 // moonbeam
 use precompile_utils::prelude::*;
 
@@ -48,6 +55,14 @@ pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address
 /// Solidity selector of the Approval log, which is the Keccak of the Log signature.
 pub const SELECTOR_LOG_APPROVAL: [u8; 32] = keccak256!("Approval(address,address,uint256)");
 
+/// Maximum number of recipients of a batch transfer.
+pub const BATCH_TRANSFER_LIMIT: u32 = 256;
+
+/// Gas cost of recovering the signer of a permit.
+pub const PERMIT_RECOVERY_COST: u64 = 3_000;
+
+type GetBatchTransferLimit = ConstU32<BATCH_TRANSFER_LIMIT>;
+
 /// Balance of the specific assets.
 type BalanceOf<R> = <R as pallet_assets::Config>::Balance;
 
@@ -57,6 +72,14 @@ type AssetIdOf<R> = <R as pallet_assets::Config>::AssetId;
 /// AccountId of the runtime.
 type AccountIdOf<R> = <R as frame_system::pallet::Config>::AccountId;
 
+/// Deposit balance of the specific assets.
+type DepositBalanceOf<R> =
+	<<R as pallet_assets::Config>::Currency as Currency<AccountIdOf<R>>>::Balance;
+
+/// Details of the specific assets.
+type AssetDetailsOf<R> =
+	pallet_assets::AssetDetails<BalanceOf<R>, AccountIdOf<R>, DepositBalanceOf<R>>;
+
 /// Convert from precompile AccountId to AssetId
 ///
 /// Note: The AssetId generation must follow our precompile AccountId rule.
@@ -74,7 +97,7 @@ where
 	AssetIdConverter: AccountToAssetId<Runtime::AccountId, AssetIdOf<Runtime>>,
 	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
 	Runtime::RuntimeCall: From<pallet_assets::Call<Runtime>>,
-	AccountIdOf<Runtime>: From<H160>,
+	AccountIdOf<Runtime>: From<H160> + Into<H160>,
 	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin: OriginTrait,
 	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
 	BalanceOf<Runtime>: TryFrom<U256> + Into<U256> + solidity::Codec,
@@ -152,10 +175,121 @@ where
 		>::max_encoded_len())?;
 		handle.record_log_costs_manual(3, 32)?;
 
+		let asset_id = Self::asset_id(handle)?;
+		let caller = handle.context().caller;
+
+		Self::approve_inner(handle, asset_id, caller, spender.into(), value)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("permit(address,address,uint256,uint256,uint8,bytes32,bytes32)")]
+	#[allow(clippy::too_many_arguments)]
+	fn permit(
+		handle: &mut impl PrecompileHandle,
+		owner: Address,
+		spender: Address,
+		value: U256,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> EvmResult {
+		// Record proof_size cost for the nonce and the asset metadata
+		handle.record_db_read::<Runtime>(
+			Self::nonce_encoded_len()
+				+ pallet_assets::AssetMetadata::<BalanceOf<Runtime>, [u8; 50]>::max_encoded_len(),
+		)?;
+		handle.record_db_read::<Runtime>(pallet_assets::Approval::<
+			BalanceOf<Runtime>,
+			BalanceOf<Runtime>,
+		>::max_encoded_len())?;
+		handle.record_cost(PERMIT_RECOVERY_COST)?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		let asset_id = Self::asset_id(handle)?;
+		let owner: H160 = owner.into();
 		let spender: H160 = spender.into();
+		let now: u128 = <Runtime as pallet_evm::Config>::Timestamp::now().unique_saturated_into();
+
+		if deadline < U256::from(now / 1_000) {
+			return Err(revert("Permit expired"));
+		}
+
+		let nonce = <NoncesStorage<AssetIdOf<Runtime>>>::get(asset_id.clone(), owner);
+		let digest = eip2612::permit_digest::<Runtime>(
+			handle.code_address(),
+			asset_id.clone(),
+			owner,
+			spender,
+			value,
+			nonce,
+			deadline,
+		);
+
+		// Reject the malleable high `s` form, same as the transaction signatures, see EIP-2.
+		if s > H256::from(eip2612::SECP256K1N_HALF) {
+			return Err(revert("Invalid permit"));
+		}
+
+		let mut signature = [0; 65];
+
+		signature[..32].copy_from_slice(r.as_bytes());
+		signature[32..64].copy_from_slice(s.as_bytes());
+		signature[64] = v;
+
+		let signer = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &digest)
+			.map_err(|_| revert("Invalid permit"))?;
+		let signer = H160::from(H256::from(keccak_256(&signer)));
+
+		if signer.is_zero() || signer != owner {
+			return Err(revert("Invalid permit"));
+		}
+
+		<NoncesStorage<AssetIdOf<Runtime>>>::insert(
+			asset_id.clone(),
+			owner,
+			nonce.saturating_add(U256::one()),
+		);
+
+		Self::approve_inner(handle, asset_id, owner, spender, value)
+	}
+
+	#[precompile::public("nonces(address)")]
+	#[precompile::view]
+	fn nonces(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+		// Record proof_size cost for the nonce
+		handle.record_db_read::<Runtime>(Self::nonce_encoded_len())?;
+
 		let asset_id = Self::asset_id(handle)?;
+
+		Ok(<NoncesStorage<AssetIdOf<Runtime>>>::get(asset_id, H160::from(owner)))
+	}
+
+	#[precompile::public("DOMAIN_SEPARATOR()")]
+	#[precompile::view]
+	fn domain_separator(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+		// Record proof_size cost for the asset metadata
+		handle.record_db_read::<Runtime>(pallet_assets::AssetMetadata::<
+			BalanceOf<Runtime>,
+			[u8; 50], // 50 refers to the StringLimit of the pallet_assets
+		>::max_encoded_len())?;
+
+		let asset_id = Self::asset_id(handle)?;
+
+		Ok(eip2612::domain_separator::<Runtime>(handle.code_address(), asset_id).into())
+	}
+
+	fn approve_inner(
+		handle: &mut impl PrecompileHandle,
+		asset_id: AssetIdOf<Runtime>,
+		owner: H160,
+		spender: H160,
+		value: U256,
+	) -> EvmResult {
 		{
-			let owner: AccountIdOf<Runtime> = handle.context().caller.into();
+			let owner: AccountIdOf<Runtime> = owner.into();
 			let spender: AccountIdOf<Runtime> = spender.into();
 			// Amount saturate if too high.
 			let amount = value.try_into().unwrap_or_else(|_| Bounded::max_value());
@@ -189,13 +323,11 @@ where
 		log3(
 			handle.context().address,
 			SELECTOR_LOG_APPROVAL,
-			handle.context().caller,
+			owner,
 			spender,
 			solidity::encode_event_data(value),
 		)
-		.record(handle)?;
-
-		Ok(true)
+		.record(handle)
 	}
 
 	#[precompile::public("transfer(address,uint256)")]
@@ -203,10 +335,53 @@ where
 		handle.record_log_costs_manual(3, 32)?;
 		let asset_id = Self::asset_id(handle)?;
 
-		let to: H160 = to.into();
 		let value = Self::u256_to_amount(value).in_field("value")?;
+		let caller = handle.context().caller;
+
+		Self::transfer_inner(handle, asset_id, caller, to.into(), value)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("batchTransfer(address[],uint256[])")]
+	fn batch_transfer(
+		handle: &mut impl PrecompileHandle,
+		recipients: BoundedVec<Address, GetBatchTransferLimit>,
+		values: BoundedVec<U256, GetBatchTransferLimit>,
+	) -> EvmResult<bool> {
+		let recipients: Vec<_> = recipients.into();
+		let values: Vec<_> = values.into();
+
+		if recipients.len() != values.len() {
+			return Err(revert("Recipients and values length mismatch"));
+		}
+
+		handle.record_log_costs_manual(3, 32)?;
+		let asset_id = Self::asset_id(handle)?;
+		let caller = handle.context().caller;
+
+		for (i, (to, value)) in recipients.into_iter().zip(values).enumerate() {
+			if i != 0 {
+				handle.record_log_costs_manual(3, 32)?;
+			}
+
+			let value = Self::u256_to_amount(value).in_array(i).in_field("values")?;
+
+			Self::transfer_inner(handle, asset_id.clone(), caller, to.into(), value)?;
+		}
+
+		Ok(true)
+	}
+
+	fn transfer_inner(
+		handle: &mut impl PrecompileHandle,
+		asset_id: AssetIdOf<Runtime>,
+		from: H160,
+		to: H160,
+		value: BalanceOf<Runtime>,
+	) -> EvmResult {
 		{
-			let origin: AccountIdOf<Runtime> = handle.context().caller.into();
+			let origin: AccountIdOf<Runtime> = from.into();
 			let to: AccountIdOf<Runtime> = to.into();
 
 			RuntimeHelper::<Runtime>::try_dispatch(
@@ -224,13 +399,11 @@ where
 		log3(
 			handle.context().address,
 			SELECTOR_LOG_TRANSFER,
-			handle.context().caller,
+			from,
 			to,
 			solidity::encode_event_data(value),
 		)
-		.record(handle)?;
-
-		Ok(true)
+		.record(handle)
 	}
 
 	#[precompile::public("transferFrom(address,address,uint256)")]
@@ -329,6 +502,179 @@ where
 		Ok(pallet_assets::Pallet::<Runtime>::decimals(asset_id))
 	}
 
+	#[precompile::public("status()")]
+	#[precompile::view]
+	fn status(handle: &mut impl PrecompileHandle) -> EvmResult<u8> {
+		let details = Self::asset_details(handle)?;
+
+		Ok(match details.status {
+			pallet_assets::AssetStatus::Live => 0,
+			pallet_assets::AssetStatus::Frozen => 1,
+			pallet_assets::AssetStatus::Destroying => 2,
+		})
+	}
+
+	#[precompile::public("minBalance()")]
+	#[precompile::view]
+	fn min_balance(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		let details = Self::asset_details(handle)?;
+
+		Ok(details.min_balance.into())
+	}
+
+	#[precompile::public("isSufficient()")]
+	#[precompile::view]
+	fn is_sufficient(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+		let details = Self::asset_details(handle)?;
+
+		Ok(details.is_sufficient)
+	}
+
+	#[precompile::public("owner()")]
+	#[precompile::view]
+	fn owner(handle: &mut impl PrecompileHandle) -> EvmResult<Address> {
+		let details = Self::asset_details(handle)?;
+
+		Ok(Address(details.owner.into()))
+	}
+
+	#[precompile::public("issuer()")]
+	#[precompile::view]
+	fn issuer(handle: &mut impl PrecompileHandle) -> EvmResult<Address> {
+		let details = Self::asset_details(handle)?;
+
+		Ok(Address(details.issuer.into()))
+	}
+
+	#[precompile::public("admin()")]
+	#[precompile::view]
+	fn admin(handle: &mut impl PrecompileHandle) -> EvmResult<Address> {
+		let details = Self::asset_details(handle)?;
+
+		Ok(Address(details.admin.into()))
+	}
+
+	#[precompile::public("freezer()")]
+	#[precompile::view]
+	fn freezer(handle: &mut impl PrecompileHandle) -> EvmResult<Address> {
+		let details = Self::asset_details(handle)?;
+
+		Ok(Address(details.freezer.into()))
+	}
+
+	#[precompile::public("setMetadata(string,string,uint8)")]
+	fn set_metadata(
+		handle: &mut impl PrecompileHandle,
+		name: BoundedString<<Runtime as pallet_assets::Config>::StringLimit>,
+		symbol: BoundedString<<Runtime as pallet_assets::Config>::StringLimit>,
+		decimals: u8,
+	) -> EvmResult<bool> {
+		let asset_id = Self::asset_id(handle)?;
+		let origin: AccountIdOf<Runtime> = handle.context().caller.into();
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			pallet_assets::Call::<Runtime>::set_metadata {
+				id: asset_id.into(),
+				name: name.into(),
+				symbol: symbol.into(),
+				decimals,
+			},
+			0,
+		)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("setTeam(address,address,address)")]
+	fn set_team(
+		handle: &mut impl PrecompileHandle,
+		issuer: Address,
+		admin: Address,
+		freezer: Address,
+	) -> EvmResult<bool> {
+		let asset_id = Self::asset_id(handle)?;
+		let origin: AccountIdOf<Runtime> = handle.context().caller.into();
+		let issuer: AccountIdOf<Runtime> = H160::from(issuer).into();
+		let admin: AccountIdOf<Runtime> = H160::from(admin).into();
+		let freezer: AccountIdOf<Runtime> = H160::from(freezer).into();
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			pallet_assets::Call::<Runtime>::set_team {
+				id: asset_id.into(),
+				issuer: Runtime::Lookup::unlookup(issuer),
+				admin: Runtime::Lookup::unlookup(admin),
+				freezer: Runtime::Lookup::unlookup(freezer),
+			},
+			0,
+		)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("freezeAsset()")]
+	fn freeze_asset(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+		let asset_id = Self::asset_id(handle)?;
+		let origin: AccountIdOf<Runtime> = handle.context().caller.into();
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			pallet_assets::Call::<Runtime>::freeze_asset { id: asset_id.into() },
+			0,
+		)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("thawAsset()")]
+	fn thaw_asset(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+		let asset_id = Self::asset_id(handle)?;
+		let origin: AccountIdOf<Runtime> = handle.context().caller.into();
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			pallet_assets::Call::<Runtime>::thaw_asset { id: asset_id.into() },
+			0,
+		)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("touch()")]
+	fn touch(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+		let asset_id = Self::asset_id(handle)?;
+		let origin: AccountIdOf<Runtime> = handle.context().caller.into();
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			pallet_assets::Call::<Runtime>::touch { id: asset_id.into() },
+			0,
+		)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("refund(bool)")]
+	fn refund(handle: &mut impl PrecompileHandle, allow_burn: bool) -> EvmResult<bool> {
+		let asset_id = Self::asset_id(handle)?;
+		let origin: AccountIdOf<Runtime> = handle.context().caller.into();
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			pallet_assets::Call::<Runtime>::refund { id: asset_id.into(), allow_burn },
+			0,
+		)?;
+
+		Ok(true)
+	}
+
 	#[precompile::public("mint(address,uint256)")]
 	fn mint(handle: &mut impl PrecompileHandle, to: Address, value: U256) -> EvmResult<bool> {
 		handle.record_log_costs_manual(3, 32)?;
@@ -483,6 +829,24 @@ where
 		Err(revert("The asset not exist!"))
 	}
 
+	fn asset_details(handle: &mut impl PrecompileHandle) -> EvmResult<AssetDetailsOf<Runtime>> {
+		let asset_id = Self::asset_id(handle)?;
+
+		// Record proof_size cost for the asset details
+		// Blake2_128(16) + AssetId + AssetDetails
+		handle.record_db_read::<Runtime>(
+			16 + AssetIdOf::<Runtime>::max_encoded_len()
+				+ AssetDetailsOf::<Runtime>::max_encoded_len(),
+		)?;
+
+		pallet_assets::Asset::<Runtime>::get(asset_id).ok_or_else(|| revert("The asset not exist!"))
+	}
+
+	fn nonce_encoded_len() -> usize {
+		// Blake2_128(16) + AssetId + Blake2_128(16) + H160(20) + U256(32)
+		16 + AssetIdOf::<Runtime>::max_encoded_len() + 16 + 20 + 32
+	}
+
 	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
 		value.try_into().map_err(|_| RevertReason::value_is_too_large("balance type").into())
 	}
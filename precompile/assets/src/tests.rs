@@ -55,6 +55,25 @@ fn selectors() {
 	assert!(InternalCall::thaw_selectors().contains(&0x5ea20216));
 	assert!(InternalCall::transfer_ownership_selectors().contains(&0xf0350c04));
 
+	assert!(InternalCall::batch_transfer_selectors().contains(&0x88d695b2));
+	assert!(InternalCall::permit_selectors().contains(&0xd505accf));
+	assert!(InternalCall::nonces_selectors().contains(&0x7ecebe00));
+	assert!(InternalCall::domain_separator_selectors().contains(&0x3644e515));
+
+	assert!(InternalCall::status_selectors().contains(&0x200d2ed2));
+	assert!(InternalCall::min_balance_selectors().contains(&0xc5bb8758));
+	assert!(InternalCall::is_sufficient_selectors().contains(&0xddf833f1));
+	assert!(InternalCall::owner_selectors().contains(&0x8da5cb5b));
+	assert!(InternalCall::issuer_selectors().contains(&0x1d143848));
+	assert!(InternalCall::admin_selectors().contains(&0xf851a440));
+	assert!(InternalCall::freezer_selectors().contains(&0x92716054));
+	assert!(InternalCall::set_metadata_selectors().contains(&0x37d2c2f4));
+	assert!(InternalCall::set_team_selectors().contains(&0xc7d93c59));
+	assert!(InternalCall::freeze_asset_selectors().contains(&0xd4937f51));
+	assert!(InternalCall::thaw_asset_selectors().contains(&0x51ec2ad7));
+	assert!(InternalCall::touch_selectors().contains(&0xa55526db));
+	assert!(InternalCall::refund_selectors().contains(&0xe57d925a));
+
 	assert_eq!(
 		crate::SELECTOR_LOG_TRANSFER,
 		&Keccak256::digest(b"Transfer(address,address,uint256)")[..]
@@ -127,6 +146,25 @@ fn modifiers() {
 		tester.test_default_modifier(InternalCall::freeze_selectors());
 		tester.test_default_modifier(InternalCall::thaw_selectors());
 		tester.test_default_modifier(InternalCall::transfer_ownership_selectors());
+
+		tester.test_default_modifier(InternalCall::batch_transfer_selectors());
+		tester.test_default_modifier(InternalCall::permit_selectors());
+		tester.test_view_modifier(InternalCall::nonces_selectors());
+		tester.test_view_modifier(InternalCall::domain_separator_selectors());
+
+		tester.test_view_modifier(InternalCall::status_selectors());
+		tester.test_view_modifier(InternalCall::min_balance_selectors());
+		tester.test_view_modifier(InternalCall::is_sufficient_selectors());
+		tester.test_view_modifier(InternalCall::owner_selectors());
+		tester.test_view_modifier(InternalCall::issuer_selectors());
+		tester.test_view_modifier(InternalCall::admin_selectors());
+		tester.test_view_modifier(InternalCall::freezer_selectors());
+		tester.test_default_modifier(InternalCall::set_metadata_selectors());
+		tester.test_default_modifier(InternalCall::set_team_selectors());
+		tester.test_default_modifier(InternalCall::freeze_asset_selectors());
+		tester.test_default_modifier(InternalCall::thaw_asset_selectors());
+		tester.test_default_modifier(InternalCall::touch_selectors());
+		tester.test_default_modifier(InternalCall::refund_selectors());
 	});
 }
 
@@ -1092,3 +1130,299 @@ fn burn_overflow() {
 				.execute_reverts(|e| e == b"value: Value is too large for balance type");
 		});
 }
+
+#[test]
+fn batch_transfer() {
+	ExtBuilder::default().with_balances(vec![(Alice.into(), 1000)]).build().execute_with(|| {
+		assert_ok!(Assets::force_create(
+			RuntimeOrigin::root(),
+			TEST_ID.into(),
+			Alice.into(),
+			true,
+			1
+		));
+		assert_ok!(Assets::mint(
+			RuntimeOrigin::signed(Alice.into()),
+			TEST_ID.into(),
+			Alice.into(),
+			1000
+		));
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				InternalCall::batch_transfer {
+					recipients: vec![Address(Bob.into()), Address(Charlie.into())].into(),
+					values: vec![U256::from(100)].into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Recipients and values length mismatch");
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				InternalCall::batch_transfer {
+					recipients: vec![Address(Bob.into()), Address(Charlie.into())].into(),
+					values: vec![U256::from(100), U256::from(200)].into(),
+				},
+			)
+			.expect_log(log3(
+				Precompile,
+				SELECTOR_LOG_TRANSFER,
+				H256::from(Alice),
+				H256::from(Bob),
+				solidity::encode_event_data(U256::from(100)),
+			))
+			.expect_log(log3(
+				Precompile,
+				SELECTOR_LOG_TRANSFER,
+				H256::from(Alice),
+				H256::from(Charlie),
+				solidity::encode_event_data(U256::from(200)),
+			))
+			.execute_returns(true);
+
+		assert_eq!(Assets::balance(TEST_ID, AccountId::from(Alice)), 700);
+		assert_eq!(Assets::balance(TEST_ID, AccountId::from(Bob)), 100);
+		assert_eq!(Assets::balance(TEST_ID, AccountId::from(Charlie)), 200);
+	});
+}
+
+#[test]
+fn permit() {
+	let secret_key = libsecp256k1::SecretKey::parse(&[1; 32]).unwrap();
+	let owner = H160::from_slice(
+		&Keccak256::digest(&libsecp256k1::PublicKey::from_secret_key(&secret_key).serialize()[1..])
+			[12..],
+	);
+	let sign = |nonce: U256, deadline: U256, high_s: bool| {
+		let digest = eip2612::permit_digest::<Runtime>(
+			Precompile.into(),
+			TEST_ID,
+			owner,
+			Bob.into(),
+			500.into(),
+			nonce,
+			deadline,
+		);
+		let (mut signature, recovery_id) =
+			libsecp256k1::sign(&libsecp256k1::Message::parse(&digest), &secret_key);
+		let mut v = recovery_id.serialize() + 27;
+
+		if high_s {
+			// The malleable counterpart, which recovers to the same signer.
+			signature.s = -signature.s;
+			v = 55 - v;
+		}
+
+		let signature = signature.serialize();
+
+		InternalCall::permit {
+			owner: Address(owner),
+			spender: Address(Bob.into()),
+			value: 500.into(),
+			deadline,
+			v,
+			r: H256::from_slice(&signature[..32]),
+			s: H256::from_slice(&signature[32..]),
+		}
+	};
+
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Assets::force_create(
+			RuntimeOrigin::root(),
+			TEST_ID.into(),
+			Alice.into(),
+			true,
+			1
+		));
+		Timestamp::set_timestamp(10_000);
+
+		precompiles()
+			.prepare_test(Charlie, Precompile, InternalCall::domain_separator {})
+			.execute_returns(H256::from(eip2612::domain_separator::<Runtime>(
+				Precompile.into(),
+				TEST_ID,
+			)));
+		precompiles()
+			.prepare_test(Charlie, Precompile, InternalCall::nonces { owner: Address(owner) })
+			.execute_returns(U256::zero());
+
+		// Signed for another spender.
+		let mut call = sign(0.into(), 20.into(), false);
+
+		if let InternalCall::permit { spender, .. } = &mut call {
+			*spender = Address(Charlie.into());
+		}
+
+		precompiles()
+			.prepare_test(Charlie, Precompile, call)
+			.execute_reverts(|output| output == b"Invalid permit");
+
+		precompiles()
+			.prepare_test(Charlie, Precompile, sign(0.into(), 20.into(), false))
+			.expect_log(log3(
+				Precompile,
+				SELECTOR_LOG_APPROVAL,
+				owner,
+				H256::from(Bob),
+				solidity::encode_event_data(U256::from(500)),
+			))
+			.execute_returns(());
+		precompiles()
+			.prepare_test(
+				Charlie,
+				Precompile,
+				InternalCall::allowance { owner: Address(owner), spender: Address(Bob.into()) },
+			)
+			.execute_returns(U256::from(500));
+		precompiles()
+			.prepare_test(Charlie, Precompile, InternalCall::nonces { owner: Address(owner) })
+			.execute_returns(U256::one());
+
+		// Replay.
+		precompiles()
+			.prepare_test(Charlie, Precompile, sign(0.into(), 20.into(), false))
+			.execute_reverts(|output| output == b"Invalid permit");
+
+		// Malleable.
+		precompiles()
+			.prepare_test(Charlie, Precompile, sign(1.into(), 20.into(), true))
+			.execute_reverts(|output| output == b"Invalid permit");
+		precompiles()
+			.prepare_test(Charlie, Precompile, InternalCall::nonces { owner: Address(owner) })
+			.execute_returns(U256::one());
+
+		// Expired.
+		Timestamp::set_timestamp(30_000);
+
+		precompiles()
+			.prepare_test(Charlie, Precompile, sign(1.into(), 20.into(), false))
+			.execute_reverts(|output| output == b"Permit expired");
+	});
+}
+
+#[test]
+fn get_details() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Assets::force_create(
+			RuntimeOrigin::root(),
+			TEST_ID.into(),
+			Alice.into(),
+			true,
+			5
+		));
+
+		precompiles()
+			.prepare_test(Bob, Precompile, InternalCall::status {})
+			.expect_no_logs()
+			.execute_returns(0_u8);
+		precompiles()
+			.prepare_test(Bob, Precompile, InternalCall::min_balance {})
+			.expect_no_logs()
+			.execute_returns(U256::from(5));
+		precompiles()
+			.prepare_test(Bob, Precompile, InternalCall::is_sufficient {})
+			.expect_no_logs()
+			.execute_returns(true);
+
+		for call in [
+			InternalCall::owner {},
+			InternalCall::issuer {},
+			InternalCall::admin {},
+			InternalCall::freezer {},
+		] {
+			precompiles()
+				.prepare_test(Bob, Precompile, call)
+				.expect_no_logs()
+				.execute_returns(Address(Alice.into()));
+		}
+	});
+}
+
+#[test]
+fn administer() {
+	ExtBuilder::default().with_balances(vec![(Alice.into(), 1000)]).build().execute_with(|| {
+		assert_ok!(Assets::force_create(
+			RuntimeOrigin::root(),
+			TEST_ID.into(),
+			Alice.into(),
+			false,
+			1
+		));
+
+		// Only the owner can set the metadata.
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile,
+				InternalCall::set_metadata {
+					name: "Token".into(),
+					symbol: "TKN".into(),
+					decimals: 18,
+				},
+			)
+			.execute_reverts(|output| from_utf8(output).unwrap().contains("NoPermission"));
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				InternalCall::set_metadata {
+					name: "Token".into(),
+					symbol: "TKN".into(),
+					decimals: 18,
+				},
+			)
+			.execute_returns(true);
+		precompiles()
+			.prepare_test(Bob, Precompile, InternalCall::name {})
+			.execute_returns(UnboundedBytes::from("Token"));
+		precompiles()
+			.prepare_test(Bob, Precompile, InternalCall::decimals {})
+			.execute_returns(18_u8);
+
+		// Hand the admin role over to Bob.
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				InternalCall::set_team {
+					issuer: Address(Alice.into()),
+					admin: Address(Bob.into()),
+					freezer: Address(Alice.into()),
+				},
+			)
+			.execute_returns(true);
+		precompiles()
+			.prepare_test(Bob, Precompile, InternalCall::admin {})
+			.execute_returns(Address(Bob.into()));
+
+		// Freezer can freeze the asset and admin can thaw it.
+		precompiles()
+			.prepare_test(Alice, Precompile, InternalCall::freeze_asset {})
+			.execute_returns(true);
+		precompiles().prepare_test(Bob, Precompile, InternalCall::status {}).execute_returns(1_u8);
+		precompiles()
+			.prepare_test(Alice, Precompile, InternalCall::thaw_asset {})
+			.execute_reverts(|output| from_utf8(output).unwrap().contains("NoPermission"));
+		precompiles()
+			.prepare_test(Bob, Precompile, InternalCall::thaw_asset {})
+			.execute_returns(true);
+		precompiles().prepare_test(Bob, Precompile, InternalCall::status {}).execute_returns(0_u8);
+
+		// Anyone can create and remove their own asset account.
+		precompiles()
+			.prepare_test(Charlie, Precompile, InternalCall::touch {})
+			.execute_returns(true);
+		assert!(pallet_assets::Account::<Runtime>::contains_key(TEST_ID, AccountId::from(Charlie)));
+		precompiles()
+			.prepare_test(Charlie, Precompile, InternalCall::refund { allow_burn: false })
+			.execute_returns(true);
+		assert!(!pallet_assets::Account::<Runtime>::contains_key(
+			TEST_ID,
+			AccountId::from(Charlie)
+		));
+	});
+}
@@ -0,0 +1,217 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// core
+use core::marker::PhantomData;
+// darwinia
+use crate::{fixtures::*, *};
+// frontier
+use fp_evm::{Context, ExitReason, Transfer};
+// polkadot-sdk
+use frame_benchmarking::v2::*;
+use sp_core::{H160, H256};
+use sp_std::prelude::*;
+
+/// Benchmarking entry of the BLS12-381 precompiles.
+pub struct Pallet<T: Config>(PhantomData<T>);
+
+/// Benchmarking config.
+pub trait Config: frame_system::Config {}
+
+struct BenchmarkHandle {
+	input: Vec<u8>,
+	context: Context,
+}
+impl BenchmarkHandle {
+	fn new(input: Vec<u8>) -> Self {
+		Self {
+			input,
+			context: Context {
+				address: H160::zero(),
+				caller: H160::zero(),
+				apparent_value: Default::default(),
+			},
+		}
+	}
+}
+impl PrecompileHandle for BenchmarkHandle {
+	fn call(
+		&mut self,
+		_: H160,
+		_: Option<Transfer>,
+		_: Vec<u8>,
+		_: Option<u64>,
+		_: bool,
+		_: &Context,
+	) -> (ExitReason, Vec<u8>) {
+		// The BLS12-381 precompiles never call into other contracts.
+		(ExitReason::Error(ExitError::Other("subcall is not supported".into())), Vec::new())
+	}
+
+	fn record_cost(&mut self, _: u64) -> Result<(), ExitError> {
+		Ok(())
+	}
+
+	fn record_external_cost(
+		&mut self,
+		_: Option<u64>,
+		_: Option<u64>,
+		_: Option<u64>,
+	) -> Result<(), ExitError> {
+		Ok(())
+	}
+
+	fn refund_external_cost(&mut self, _: Option<u64>, _: Option<u64>) {}
+
+	fn remaining_gas(&self) -> u64 {
+		u64::MAX
+	}
+
+	fn log(&mut self, _: H160, _: Vec<H256>, _: Vec<u8>) -> Result<(), ExitError> {
+		Ok(())
+	}
+
+	fn code_address(&self) -> H160 {
+		self.context.address
+	}
+
+	fn input(&self) -> &[u8] {
+		&self.input
+	}
+
+	fn context(&self) -> &Context {
+		&self.context
+	}
+
+	fn is_static(&self) -> bool {
+		true
+	}
+
+	fn gas_limit(&self) -> Option<u64> {
+		None
+	}
+}
+
+fn run<P>(input: Vec<u8>)
+where
+	P: Precompile,
+{
+	assert!(P::execute(&mut BenchmarkHandle::new(input)).is_ok());
+}
+
+#[benchmarks]
+mod benchmarks {
+	// darwinia
+	use super::*;
+
+	#[benchmark]
+	fn g1_add() {
+		let input = [g1(), g1()].concat();
+
+		#[block]
+		{
+			run::<Bls12381G1Add>(input);
+		}
+	}
+
+	#[benchmark]
+	fn g1_mul() {
+		let input = [g1(), scalar()].concat();
+
+		#[block]
+		{
+			run::<Bls12381G1Mul>(input);
+		}
+	}
+
+	#[benchmark]
+	fn g1_multi_exp(k: Linear<1, 128>) {
+		let input = [g1(), scalar()].concat().repeat(k as _);
+
+		#[block]
+		{
+			run::<Bls12381G1MultiExp>(input);
+		}
+	}
+
+	#[benchmark]
+	fn g2_add() {
+		let input = [g2(), g2()].concat();
+
+		#[block]
+		{
+			run::<Bls12381G2Add>(input);
+		}
+	}
+
+	#[benchmark]
+	fn g2_mul() {
+		let input = [g2(), scalar()].concat();
+
+		#[block]
+		{
+			run::<Bls12381G2Mul>(input);
+		}
+	}
+
+	#[benchmark]
+	fn g2_multi_exp(k: Linear<1, 128>) {
+		let input = [g2(), scalar()].concat().repeat(k as _);
+
+		#[block]
+		{
+			run::<Bls12381G2MultiExp>(input);
+		}
+	}
+
+	#[benchmark]
+	fn pairing(k: Linear<1, 16>) {
+		let input = [g1(), g2()].concat().repeat(k as _);
+
+		#[block]
+		{
+			run::<Bls12381Pairing>(input);
+		}
+	}
+
+	#[benchmark]
+	fn map_g1() {
+		let input = fp(FP_G1_X);
+
+		#[block]
+		{
+			run::<Bls12381MapG1>(input);
+		}
+	}
+
+	#[benchmark]
+	fn map_g2() {
+		let input = [fp(FP_G2_X_C0), fp(FP_G2_X_C1)].concat();
+
+		#[block]
+		{
+			run::<Bls12381MapG2>(input);
+		}
+	}
+
+	frame_benchmarking::impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::ExtBuilder::default().build(),
+		crate::mock::Runtime
+	);
+}
@@ -0,0 +1,54 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Encoded BLS12-381 points for the benchmarks and tests.
+
+// polkadot-sdk
+use sp_std::prelude::*;
+
+// https://eips.ethereum.org/EIPS/eip-2537#fine-points-and-encoding-of-base-elements
+pub const FP_G1_X: &str = "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+pub const FP_G1_Y: &str = "08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1";
+pub const FP_G2_X_C0: &str = "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+pub const FP_G2_X_C1: &str = "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e";
+pub const FP_G2_Y_C0: &str = "0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801";
+pub const FP_G2_Y_C1: &str = "0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be";
+
+// Encode a base field element into 64 bytes, with the top 16 bytes zeroed.
+pub fn fp(hex: &str) -> Vec<u8> {
+	let mut v = vec![0; 16];
+
+	(0..hex.len())
+		.step_by(2)
+		.for_each(|i| v.push(u8::from_str_radix(&hex[i..i + 2], 16).expect("valid hex; qed")));
+
+	v
+}
+
+pub fn g1() -> Vec<u8> {
+	[fp(FP_G1_X), fp(FP_G1_Y)].concat()
+}
+
+pub fn g2() -> Vec<u8> {
+	[fp(FP_G2_X_C0), fp(FP_G2_X_C1), fp(FP_G2_Y_C0), fp(FP_G2_Y_C1)].concat()
+}
+
+// Worst-case scalar, all the bits are set.
+pub fn scalar() -> Vec<u8> {
+	vec![0xff; 32]
+}
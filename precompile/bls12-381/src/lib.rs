@@ -0,0 +1,142 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! EIP-2537 BLS12-381 precompiles.
+//!
+//! The operations are served by `pallet_evm_precompile_bls12381`, which charges the EIP-2537 gas.
+//! On top of it, each call is charged at least the gas of its benchmarked weight, so that the
+//! operations stay correctly priced when the runtime executes them slower than an Ethereum
+//! client does.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod fixtures;
+
+mod weights;
+pub use weights::WeightInfo;
+
+// core
+use core::marker::PhantomData;
+// frontier
+use fp_evm::{ExitError, Precompile, PrecompileFailure, PrecompileHandle, PrecompileResult};
+use pallet_evm::GasWeightMapping;
+use pallet_evm_precompile_bls12381::{
+	Bls12381G1Add, Bls12381G1Mul, Bls12381G1MultiExp, Bls12381G2Add, Bls12381G2Mul,
+	Bls12381G2MultiExp, Bls12381MapG1, Bls12381MapG2, Bls12381Pairing,
+};
+// polkadot-sdk
+use frame_support::weights::Weight;
+
+/// Input length of a G1 point and scalar pair.
+pub const G1_MUL_INPUT_LEN: usize = 160;
+/// Input length of a G2 point and scalar pair.
+pub const G2_MUL_INPUT_LEN: usize = 288;
+/// Input length of a G1 and G2 point pair.
+pub const PAIRING_INPUT_LEN: usize = 384;
+
+/// EIP-2537 BLS12-381 precompiles.
+pub struct Bls12381<Runtime, WeightInfo>(PhantomData<(Runtime, WeightInfo)>);
+impl<Runtime, W> Bls12381<Runtime, W>
+where
+	Runtime: pallet_evm::Config,
+	W: WeightInfo,
+{
+	/// `BLS12_G1ADD`.
+	pub fn g1_add(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		Self::execute::<Bls12381G1Add>(handle, W::g1_add())
+	}
+
+	/// `BLS12_G1MUL`.
+	pub fn g1_mul(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		Self::execute::<Bls12381G1Mul>(handle, W::g1_mul())
+	}
+
+	/// `BLS12_G1MULTIEXP`.
+	pub fn g1_multi_exp(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let k = Self::pairs(handle, G1_MUL_INPUT_LEN);
+
+		Self::execute::<Bls12381G1MultiExp>(handle, W::g1_multi_exp(k))
+	}
+
+	/// `BLS12_G2ADD`.
+	pub fn g2_add(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		Self::execute::<Bls12381G2Add>(handle, W::g2_add())
+	}
+
+	/// `BLS12_G2MUL`.
+	pub fn g2_mul(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		Self::execute::<Bls12381G2Mul>(handle, W::g2_mul())
+	}
+
+	/// `BLS12_G2MULTIEXP`.
+	pub fn g2_multi_exp(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let k = Self::pairs(handle, G2_MUL_INPUT_LEN);
+
+		Self::execute::<Bls12381G2MultiExp>(handle, W::g2_multi_exp(k))
+	}
+
+	/// `BLS12_PAIRING`.
+	pub fn pairing(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let k = Self::pairs(handle, PAIRING_INPUT_LEN);
+
+		Self::execute::<Bls12381Pairing>(handle, W::pairing(k))
+	}
+
+	/// `BLS12_MAP_FP_TO_G1`.
+	pub fn map_g1(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		Self::execute::<Bls12381MapG1>(handle, W::map_g1())
+	}
+
+	/// `BLS12_MAP_FP2_TO_G2`.
+	pub fn map_g2(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		Self::execute::<Bls12381MapG2>(handle, W::map_g2())
+	}
+
+	fn pairs(handle: &impl PrecompileHandle, pair_len: usize) -> u32 {
+		(handle.input().len() / pair_len).try_into().unwrap_or(u32::MAX)
+	}
+
+	fn execute<P>(handle: &mut impl PrecompileHandle, weight: Weight) -> PrecompileResult
+	where
+		P: Precompile,
+	{
+		let cost = Runtime::GasWeightMapping::weight_to_gas(weight);
+
+		// Make sure the benchmarked cost is affordable before doing the heavy computation.
+		if handle.remaining_gas() < cost {
+			return Err(PrecompileFailure::Error { exit_status: ExitError::OutOfGas });
+		}
+
+		let remaining_gas = handle.remaining_gas();
+		let output = P::execute(handle)?;
+		let eip2537_cost = remaining_gas.saturating_sub(handle.remaining_gas());
+
+		// Top up to the benchmarked cost if the EIP-2537 cost is lower.
+		handle.record_cost(cost.saturating_sub(eip2537_cost))?;
+
+		Ok(output)
+	}
+}
@@ -0,0 +1,160 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// crates.io
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+// darwinia
+use crate::*;
+// polkadot-sdk
+use frame_support::derive_impl;
+use sp_core::H160;
+use sp_runtime::BuildStorage;
+use sp_std::prelude::*;
+
+pub type Balance = u64;
+pub type AccountId = H160;
+
+#[derive(Clone, Encode, Decode, Debug, MaxEncodedLen, TypeInfo)]
+pub enum Account {
+	Alice,
+	G1Add,
+	G1Mul,
+	Pairing,
+}
+#[allow(clippy::from_over_into)]
+impl Into<H160> for Account {
+	fn into(self) -> H160 {
+		match self {
+			Account::Alice => H160::repeat_byte(0xAA),
+			Account::G1Add => H160::from_low_u64_be(0x0b),
+			Account::G1Mul => H160::from_low_u64_be(0x0c),
+			Account::Pairing => H160::from_low_u64_be(0x11),
+		}
+	}
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type AccountId = AccountId;
+	type Block = frame_system::mocking::MockBlock<Self>;
+	type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig as pallet_balances::DefaultConfig)]
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+	type Balance = Balance;
+	type ExistentialDeposit = ();
+}
+
+#[derive_impl(pallet_timestamp::config_preludes::TestDefaultConfig as pallet_timestamp::DefaultConfig)]
+impl pallet_timestamp::Config for Runtime {}
+
+pub struct TestPrecompiles<R>(PhantomData<R>);
+impl<R> TestPrecompiles<R>
+where
+	R: pallet_evm::Config,
+{
+	#[allow(clippy::new_without_default)]
+	pub fn new() -> Self {
+		Self(Default::default())
+	}
+
+	pub fn set() -> [H160; 3] {
+		[Account::G1Add.into(), Account::G1Mul.into(), Account::Pairing.into()]
+	}
+}
+impl<R> fp_evm::PrecompileSet for TestPrecompiles<R>
+where
+	R: pallet_evm::Config,
+{
+	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+		match handle.code_address().to_low_u64_be() {
+			0x0b => Some(<Bls12381<R, ()>>::g1_add(handle)),
+			0x0c => Some(<Bls12381<R, ()>>::g1_mul(handle)),
+			0x11 => Some(<Bls12381<R, ()>>::pairing(handle)),
+			_ => None,
+		}
+	}
+
+	fn is_precompile(&self, address: H160, _gas: u64) -> fp_evm::IsPrecompileResult {
+		fp_evm::IsPrecompileResult::Answer {
+			is_precompile: Self::set().contains(&address),
+			extra_cost: 0,
+		}
+	}
+}
+
+frame_support::parameter_types! {
+	pub const BlockGasLimit: sp_core::U256 = sp_core::U256::MAX;
+	pub const WeightPerGas: Weight = Weight::from_parts(20_000, 0);
+	pub PrecompilesValue: TestPrecompiles<Runtime> = TestPrecompiles::<_>::new();
+}
+
+impl pallet_evm::Config for Runtime {
+	type AccountProvider = pallet_evm::FrameSystemAccountProvider<Self>;
+	type AddressMapping = pallet_evm::IdentityAddressMapping;
+	type BlockGasLimit = BlockGasLimit;
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type CallOrigin = pallet_evm::EnsureAddressRoot<AccountId>;
+	type ChainId = frame_support::traits::ConstU64<42>;
+	type Currency = Balances;
+	type FeeCalculator = ();
+	type FindAuthor = ();
+	type GasLimitPovSizeRatio = ();
+	type GasLimitStorageGrowthRatio = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type OnChargeTransaction = ();
+	type OnCreate = ();
+	type PrecompilesType = TestPrecompiles<Self>;
+	type PrecompilesValue = PrecompilesValue;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type RuntimeEvent = RuntimeEvent;
+	type Timestamp = Timestamp;
+	type WeightInfo = ();
+	type WeightPerGas = WeightPerGas;
+	type WithdrawOrigin = pallet_evm::EnsureAddressNever<AccountId>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::benchmarking::Config for Runtime {}
+
+frame_support::construct_runtime! {
+	pub enum Runtime {
+		System: frame_system,
+		Timestamp: pallet_timestamp,
+		Balances: pallet_balances,
+		EVM: pallet_evm,
+	}
+}
+
+#[derive(Default)]
+pub struct ExtBuilder;
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = <frame_system::GenesisConfig<Runtime>>::default()
+			.build_storage()
+			.expect("Frame system builds valid default genesis config");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,101 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// darwinia
+use crate::{
+	fixtures::*,
+	mock::{
+		Account::{Alice, G1Add, G1Mul, Pairing},
+		*,
+	},
+	*,
+};
+// moonbeam
+use precompile_utils::testing::PrecompileTesterExt;
+
+const FP_G1_NEG_Y: &str = "114d1d6855d545a8aa7d76c8cf2e21f267816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca";
+
+fn precompiles() -> TestPrecompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn cost_of(weight: Weight) -> u64 {
+	<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight)
+}
+
+#[test]
+fn g1_add_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// The EIP-2537 cost is 500.
+		let cost = cost_of(<()>::g1_add());
+
+		assert!(cost > 500);
+
+		// G + O = G
+		precompiles()
+			.prepare_test(Alice, G1Add, [g1(), vec![0; 128]].concat())
+			.expect_cost(cost)
+			.expect_no_logs()
+			.execute_returns_raw(g1());
+	});
+}
+
+#[test]
+fn g1_mul_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, G1Mul, [g1(), scalar()].concat())
+			.expect_cost(cost_of(<()>::g1_mul()))
+			.expect_no_logs()
+			.execute_some();
+	});
+}
+
+#[test]
+fn pairing_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// The EIP-2537 cost is 43,000 per pair plus 65,000.
+		let cost = cost_of(<()>::pairing(2)).max(43_000 * 2 + 65_000);
+
+		// e(G1, G2) * e(-G1, G2) = 1
+		let mut neg_g1 = g1();
+
+		neg_g1[64..].copy_from_slice(&fp(FP_G1_NEG_Y));
+
+		let mut one = vec![0; 32];
+
+		one[31] = 1;
+
+		precompiles()
+			.prepare_test(Alice, Pairing, [g1(), g2(), neg_g1, g2()].concat())
+			.expect_cost(cost)
+			.expect_no_logs()
+			.execute_returns_raw(one);
+	});
+}
+
+#[test]
+fn benchmarked_cost_should_be_affordable() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Enough for the EIP-2537 cost but not for the benchmarked one.
+		precompiles()
+			.prepare_test(Alice, G1Add, [g1(), g1()].concat())
+			.with_target_gas(Some(cost_of(<()>::g1_add()) - 1))
+			.execute_error(ExitError::OutOfGas);
+	});
+}
@@ -0,0 +1,227 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `darwinia_precompile_bls12_381`
+//!
+//! THESE WEIGHTS ARE NOT MEASURED. They are estimated by hand, regenerate this file with the
+//! benchmark CLI before the release.
+
+// Regenerate with:
+// target/release/darwinia
+// benchmark
+// pallet
+// --header
+// .maintain/license-header
+// --template
+// .maintain/pallet-weight-template.hbs
+// --heap-pages
+// 4096
+// --chain
+// koi-dev
+// --output
+// precompile/bls12-381/src/weights.rs
+// --extrinsic
+// *
+// --pallet
+// darwinia-precompile-bls12-381
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(missing_docs)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for darwinia_precompile_bls12_381.
+pub trait WeightInfo {
+	fn g1_add() -> Weight;
+	fn g1_mul() -> Weight;
+	fn g1_multi_exp(k: u32) -> Weight;
+	fn g2_add() -> Weight;
+	fn g2_mul() -> Weight;
+	fn g2_multi_exp(k: u32) -> Weight;
+	fn pairing(k: u32) -> Weight;
+	fn map_g1() -> Weight;
+	fn map_g2() -> Weight;
+}
+
+/// Weights for darwinia_precompile_bls12_381 using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn g1_add() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 28_000 nanoseconds.
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn g1_mul() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 812_000 nanoseconds.
+		Weight::from_parts(845_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// The range of component `k` is `[1, 128]`.
+	fn g1_multi_exp(k: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_180_000 nanoseconds.
+		Weight::from_parts(1_032_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(412_000_000, 0).saturating_mul(k.into()))
+	}
+	fn g2_add() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 61_000 nanoseconds.
+		Weight::from_parts(64_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn g2_mul() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_431_000 nanoseconds.
+		Weight::from_parts(2_512_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// The range of component `k` is `[1, 128]`.
+	fn g2_multi_exp(k: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_367_000 nanoseconds.
+		Weight::from_parts(2_847_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(1_275_000_000, 0).saturating_mul(k.into()))
+	}
+	/// The range of component `k` is `[1, 16]`.
+	fn pairing(k: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_120_000 nanoseconds.
+		Weight::from_parts(8_306_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(6_085_000_000, 0).saturating_mul(k.into()))
+	}
+	fn map_g1() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 683_000 nanoseconds.
+		Weight::from_parts(712_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn map_g2() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_467_000 nanoseconds.
+		Weight::from_parts(2_534_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn g1_add() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 28_000 nanoseconds.
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn g1_mul() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 812_000 nanoseconds.
+		Weight::from_parts(845_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// The range of component `k` is `[1, 128]`.
+	fn g1_multi_exp(k: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_180_000 nanoseconds.
+		Weight::from_parts(1_032_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(412_000_000, 0).saturating_mul(k.into()))
+	}
+	fn g2_add() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 61_000 nanoseconds.
+		Weight::from_parts(64_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn g2_mul() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_431_000 nanoseconds.
+		Weight::from_parts(2_512_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// The range of component `k` is `[1, 128]`.
+	fn g2_multi_exp(k: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_367_000 nanoseconds.
+		Weight::from_parts(2_847_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(1_275_000_000, 0).saturating_mul(k.into()))
+	}
+	/// The range of component `k` is `[1, 16]`.
+	fn pairing(k: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_120_000 nanoseconds.
+		Weight::from_parts(8_306_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(6_085_000_000, 0).saturating_mul(k.into()))
+	}
+	fn map_g1() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 683_000 nanoseconds.
+		Weight::from_parts(712_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn map_g2() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_467_000 nanoseconds.
+		Weight::from_parts(2_534_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+}
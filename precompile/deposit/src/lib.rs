@@ -0,0 +1,146 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+// core
+use core::marker::PhantomData;
+// crates.io
+use codec::MaxEncodedLen;
+// darwinia
+use darwinia_deposit::MigrationFailure;
+// moonbeam
+use precompile_utils::prelude::*;
+// polkadot-sdk
+use frame_support::{traits::ConstU32, BoundedVec};
+use sp_core::{H160, U256};
+use sp_std::prelude::*;
+
+/// AccountId of the runtime.
+type AccountIdOf<R> = <R as frame_system::pallet::Config>::AccountId;
+
+/// Deposit of the darwinia deposit pallet.
+#[derive(Debug, PartialEq, Eq, solidity::Codec)]
+pub struct DepositInfo {
+	id: u16,
+	value: U256,
+	start_time: u128,
+	expired_time: u128,
+	in_use: bool,
+}
+impl From<darwinia_deposit::Deposit> for DepositInfo {
+	fn from(d: darwinia_deposit::Deposit) -> Self {
+		Self {
+			id: d.id,
+			value: d.value.into(),
+			start_time: d.start_time,
+			expired_time: d.expired_time,
+			in_use: d.in_use,
+		}
+	}
+}
+
+/// The migrations reenter the EVM, which is forbidden from a precompile. So they are only requested
+/// here and then served in `on_idle`.
+pub struct Deposit<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> Deposit<Runtime>
+where
+	Runtime: darwinia_deposit::Config + pallet_evm::Config,
+	AccountIdOf<Runtime>: From<H160>,
+{
+	#[precompile::public("deposits(address)")]
+	#[precompile::view]
+	fn deposits(handle: &mut impl PrecompileHandle, who: Address) -> EvmResult<Vec<DepositInfo>> {
+		// Record proof_size cost for the deposits
+		handle.record_db_read::<Runtime>(
+			<BoundedVec<darwinia_deposit::Deposit, ConstU32<512>>>::max_encoded_len(),
+		)?;
+
+		let who: AccountIdOf<Runtime> = H160::from(who).into();
+
+		Ok(<darwinia_deposit::Deposits<Runtime>>::get(who)
+			.map(|ds| ds.into_iter().map(Into::into).collect())
+			.unwrap_or_default())
+	}
+
+	/// Return the failed deposits along with the failure codes.
+	///
+	/// Failure codes:
+	/// - `0`, unknown
+	/// - `1`, failed to claim the expired deposits
+	/// - `2`, invalid deposit contract
+	/// - `3`, failed to dispatch the transaction to the deposit contract
+	/// - `4`, the deposit contract call did not succeed
+	#[precompile::public("migration_failures(address)")]
+	#[precompile::view]
+	fn migration_failures(
+		handle: &mut impl PrecompileHandle,
+		who: Address,
+	) -> EvmResult<(Vec<DepositInfo>, Vec<u8>)> {
+		// Record proof_size cost for the failures
+		handle.record_db_read::<Runtime>(<BoundedVec<
+			(darwinia_deposit::Deposit, MigrationFailure),
+			ConstU32<512>,
+		>>::max_encoded_len())?;
+
+		let who: AccountIdOf<Runtime> = H160::from(who).into();
+		let failures = <darwinia_deposit::MigrationFailures<Runtime>>::get(who).unwrap_or_default();
+
+		Ok(failures
+			.into_iter()
+			.map(|(d, f)| {
+				let code = match f {
+					MigrationFailure::Unknown => 0,
+					MigrationFailure::Claim => 1,
+					MigrationFailure::InvalidDepositContract => 2,
+					MigrationFailure::Dispatch => 3,
+					MigrationFailure::Evm { .. } => 4,
+				};
+
+				(DepositInfo::from(d), code)
+			})
+			.unzip())
+	}
+
+	#[precompile::public("migrate_for(address)")]
+	fn migrate_for(handle: &mut impl PrecompileHandle, who: Address) -> EvmResult<bool> {
+		// Record proof_size cost for the deposits and the migration requests
+		handle.record_db_read::<Runtime>(
+			<BoundedVec<darwinia_deposit::Deposit, ConstU32<512>>>::max_encoded_len(),
+		)?;
+		handle.record_db_read::<Runtime>(<BoundedVec<
+			AccountIdOf<Runtime>,
+			ConstU32<{ darwinia_deposit::MAX_MIGRATION_REQUESTS }>,
+		>>::max_encoded_len())?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+		let who: AccountIdOf<Runtime> = H160::from(who).into();
+
+		<darwinia_deposit::Pallet<Runtime>>::request_migration(who)
+			.map_err(|e| revert(<&'static str>::from(e)))?;
+
+		Ok(true)
+	}
+}
@@ -0,0 +1,189 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// crates.io
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+// darwinia
+use crate::*;
+// frontier
+use precompile_utils::Precompile;
+// polkadot-sdk
+use frame_support::derive_impl;
+use sp_core::H160;
+use sp_runtime::BuildStorage;
+use sp_std::{marker::PhantomData, prelude::*};
+
+pub type Balance = u128;
+pub type AccountId = H160;
+pub type PCall = DepositCall<Runtime>;
+
+#[derive(Clone, Encode, Decode, Debug, MaxEncodedLen, TypeInfo)]
+pub enum Account {
+	Alice,
+	Bob,
+	Precompile,
+}
+#[allow(clippy::from_over_into)]
+impl Into<H160> for Account {
+	fn into(self) -> H160 {
+		match self {
+			Account::Alice => H160::repeat_byte(0xAA),
+			Account::Bob => H160::repeat_byte(0xBB),
+			Account::Precompile => H160::from_low_u64_be(1),
+		}
+	}
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type AccountId = AccountId;
+	type Block = frame_system::mocking::MockBlock<Self>;
+	type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig as pallet_balances::DefaultConfig)]
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+	type Balance = Balance;
+	type ExistentialDeposit = ();
+}
+
+#[derive_impl(pallet_timestamp::config_preludes::TestDefaultConfig as pallet_timestamp::DefaultConfig)]
+impl pallet_timestamp::Config for Runtime {
+	type MinimumPeriod = ();
+	type Moment = dc_types::Moment;
+}
+
+frame_support::parameter_types! {
+	pub TreasuryAccount: AccountId = H160::repeat_byte(0xEE);
+}
+impl darwinia_deposit::Config for Runtime {
+	type DepositMigrator = ();
+	type MigrationGasPerDeposit = ();
+	type Ring = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type Treasury = TreasuryAccount;
+	type WeightInfo = ();
+}
+
+pub struct TestPrecompiles<R>(PhantomData<R>);
+impl<R> TestPrecompiles<R>
+where
+	R: pallet_evm::Config,
+{
+	#[allow(clippy::new_without_default)]
+	pub fn new() -> Self {
+		Self(Default::default())
+	}
+
+	pub fn set() -> [H160; 1] {
+		[addr(1)]
+	}
+}
+impl<R> fp_evm::PrecompileSet for TestPrecompiles<R>
+where
+	Deposit<R>: fp_evm::Precompile,
+	R: pallet_evm::Config,
+{
+	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<EvmResult<PrecompileOutput>> {
+		match handle.code_address() {
+			a if a == addr(1) => Some(Deposit::<R>::execute(handle)),
+			_ => None,
+		}
+	}
+
+	fn is_precompile(&self, address: H160, _gas: u64) -> fp_evm::IsPrecompileResult {
+		fp_evm::IsPrecompileResult::Answer {
+			is_precompile: Self::set().contains(&address),
+			extra_cost: 0,
+		}
+	}
+}
+fn addr(a: u64) -> H160 {
+	H160::from_low_u64_be(a)
+}
+
+frame_support::parameter_types! {
+	pub const BlockGasLimit: sp_core::U256 = sp_core::U256::MAX;
+	pub const WeightPerGas: frame_support::weights::Weight = frame_support::weights::Weight::from_parts(20_000, 0);
+	pub PrecompilesValue: TestPrecompiles<Runtime> = TestPrecompiles::<_>::new();
+}
+
+impl pallet_evm::Config for Runtime {
+	type AccountProvider = pallet_evm::FrameSystemAccountProvider<Self>;
+	type AddressMapping = pallet_evm::IdentityAddressMapping;
+	type BlockGasLimit = BlockGasLimit;
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type CallOrigin = pallet_evm::EnsureAddressRoot<AccountId>;
+	type ChainId = frame_support::traits::ConstU64<42>;
+	type Currency = Balances;
+	type FeeCalculator = ();
+	type FindAuthor = ();
+	type GasLimitPovSizeRatio = ();
+	type GasLimitStorageGrowthRatio = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type OnChargeTransaction = ();
+	type OnCreate = ();
+	type PrecompilesType = TestPrecompiles<Self>;
+	type PrecompilesValue = PrecompilesValue;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type RuntimeEvent = RuntimeEvent;
+	type Timestamp = Timestamp;
+	type WeightInfo = ();
+	type WeightPerGas = WeightPerGas;
+	type WithdrawOrigin = pallet_evm::EnsureAddressNever<AccountId>;
+}
+
+frame_support::construct_runtime! {
+	pub enum Runtime {
+		System: frame_system,
+		Timestamp: pallet_timestamp,
+		Balances: pallet_balances,
+		EVM: pallet_evm,
+		DarwiniaDeposit: darwinia_deposit,
+	}
+}
+
+#[derive(Default)]
+pub struct ExtBuilder {
+	// endowed accounts with balances
+	balances: Vec<(AccountId, Balance)>,
+}
+
+impl ExtBuilder {
+	pub fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = <frame_system::GenesisConfig<Runtime>>::default()
+			.build_storage()
+			.expect("Frame system builds valid default genesis config");
+
+		pallet_balances::GenesisConfig::<Runtime> { balances: self.balances }
+			.assimilate_storage(&mut t)
+			.expect("Pallet balances storage can be assimilated");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
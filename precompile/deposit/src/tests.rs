@@ -0,0 +1,141 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// darwinia
+use crate::{
+	mock::{
+		Account::{Alice, Bob, Precompile},
+		ExtBuilder, PCall, PrecompilesValue, Runtime, TestPrecompiles,
+	},
+	DepositInfo,
+};
+use darwinia_deposit::{Deposit, EvmExitReason, MigrationFailure};
+// moonbeam
+use precompile_utils::{
+	prelude::{Address, RuntimeHelper},
+	testing::{PrecompileTesterExt, PrecompilesModifierTester},
+};
+// polkadot-sdk
+use frame_support::BoundedVec;
+use sp_core::{H160, U256};
+
+fn precompiles() -> TestPrecompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn bob() -> H160 {
+	Bob.into()
+}
+
+fn deposit(id: u16) -> Deposit {
+	Deposit { id, value: 100, start_time: 0, expired_time: 1_000_000, in_use: false }
+}
+
+fn deposit_info(id: u16) -> DepositInfo {
+	DepositInfo {
+		id,
+		value: U256::from(100),
+		start_time: 0,
+		expired_time: 1_000_000,
+		in_use: false,
+	}
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::deposits_selectors().contains(&0xfc7e286d));
+	assert!(PCall::migration_failures_selectors().contains(&0xeca7444f));
+	assert!(PCall::migrate_for_selectors().contains(&0x4c509356));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(PrecompilesValue::get(), Alice, Precompile);
+
+		tester.test_view_modifier(PCall::deposits_selectors());
+		tester.test_view_modifier(PCall::migration_failures_selectors());
+		tester.test_default_modifier(PCall::migrate_for_selectors());
+	});
+}
+
+#[test]
+fn deposits_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		<darwinia_deposit::Deposits<Runtime>>::insert(
+			bob(),
+			BoundedVec::truncate_from(vec![deposit(0), deposit(1)]),
+		);
+
+		precompiles()
+			.prepare_test(Alice, Precompile, PCall::deposits { who: Address(bob()) })
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+			.expect_no_logs()
+			.execute_returns(vec![deposit_info(0), deposit_info(1)]);
+		precompiles()
+			.prepare_test(Alice, Precompile, PCall::deposits { who: Address(Alice.into()) })
+			.execute_returns(Vec::<DepositInfo>::new());
+	});
+}
+
+#[test]
+fn migration_failures_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		<darwinia_deposit::MigrationFailures<Runtime>>::insert(
+			bob(),
+			BoundedVec::truncate_from(vec![
+				(deposit(0), MigrationFailure::InvalidDepositContract),
+				(
+					deposit(1),
+					MigrationFailure::Evm {
+						exit_reason: EvmExitReason::Revert,
+						revert_data: BoundedVec::truncate_from(vec![1; 4]),
+					},
+				),
+			]),
+		);
+
+		precompiles()
+			.prepare_test(Alice, Precompile, PCall::migration_failures { who: Address(bob()) })
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+			.expect_no_logs()
+			.execute_returns((vec![deposit_info(0), deposit_info(1)], vec![2_u8, 4_u8]));
+	});
+}
+
+#[test]
+fn migrate_for_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile, PCall::migrate_for { who: Address(bob()) })
+			.execute_reverts(|output| output == b"NoDeposit");
+
+		<darwinia_deposit::Deposits<Runtime>>::insert(
+			bob(),
+			BoundedVec::truncate_from(vec![deposit(0)]),
+		);
+
+		// Requested only, the migration reenters the EVM.
+		precompiles()
+			.prepare_test(Alice, Precompile, PCall::migrate_for { who: Address(bob()) })
+			.expect_no_logs()
+			.execute_returns(true);
+		assert_eq!(<darwinia_deposit::MigrationRequests<Runtime>>::get().into_inner(), [bob()]);
+		assert!(<darwinia_deposit::Deposits<Runtime>>::get(bob()).is_some());
+	});
+}
@@ -74,4 +74,81 @@ interface ERC20Assets {
 	/// @param amount the amount of tokens to be transferred
 	/// @return returns true on success, false otherwise.
 	function transferFrom(address from, address to, uint256 amount) external returns (bool);
+
+	/// @dev Moves `amounts[i]` tokens from the caller's account to `recipients[i]`.
+	/// @param recipients The addresses to transfer to.
+	/// @param amounts The amounts to be transferred, one for each recipient.
+	/// @return returns true on success, false otherwise.
+	function batchTransfer(address[] memory recipients, uint256[] memory amounts) external returns (bool);
+
+	/// @dev Sets `value` as the allowance of `spender` over `owner`'s tokens, given `owner`'s signed approval.
+	/// @param owner The address which owns the funds.
+	/// @param spender The address which will spend the funds.
+	/// @param value The amount of tokens to be spent.
+	/// @param deadline The unix timestamp in seconds after which the permit is invalid.
+	/// @param v The recovery id of the signature.
+	/// @param r The first 32 bytes of the signature.
+	/// @param s The second 32 bytes of the signature.
+	function permit(address owner, address spender, uint256 value, uint256 deadline, uint8 v, bytes32 r, bytes32 s) external;
+
+	/// @dev Returns the current nonce of `owner`, which must be included whenever a signature is generated for {permit}.
+	/// @param owner The address to query the nonce of.
+	/// @return The current nonce of `owner`.
+	function nonces(address owner) external view returns (uint256);
+
+	/// @dev Returns the domain separator used in the encoding of the signature for {permit}, as defined by EIP-712.
+	/// @return The domain separator.
+	function DOMAIN_SEPARATOR() external view returns (bytes32);
+
+	/// @dev Returns the status of the asset, 0 for live, 1 for frozen and 2 for destroying.
+	function status() external view returns (uint8);
+
+	/// @dev Returns the minimum balance of an account holding the asset.
+	function minBalance() external view returns (uint256);
+
+	/// @dev Returns whether the asset can keep an account alive without the native currency.
+	function isSufficient() external view returns (bool);
+
+	/// @dev Returns the owner of the asset.
+	function owner() external view returns (address);
+
+	/// @dev Returns the issuer of the asset.
+	function issuer() external view returns (address);
+
+	/// @dev Returns the admin of the asset.
+	function admin() external view returns (address);
+
+	/// @dev Returns the freezer of the asset.
+	function freezer() external view returns (address);
+
+	/// @dev Sets the metadata of the asset, only callable by the owner.
+	/// @param name The name of the asset.
+	/// @param symbol The symbol of the asset.
+	/// @param decimals The decimals of the asset.
+	/// @return returns true on success, false otherwise.
+	function setMetadata(string memory name, string memory symbol, uint8 decimals) external returns (bool);
+
+	/// @dev Sets the issuer, admin and freezer of the asset, only callable by the owner.
+	/// @param issuer The new issuer of the asset.
+	/// @param admin The new admin of the asset.
+	/// @param freezer The new freezer of the asset.
+	/// @return returns true on success, false otherwise.
+	function setTeam(address issuer, address admin, address freezer) external returns (bool);
+
+	/// @dev Disallows further transfers of the asset, only callable by the freezer.
+	/// @return returns true on success, false otherwise.
+	function freezeAsset() external returns (bool);
+
+	/// @dev Allows transfers of the frozen asset again, only callable by the admin.
+	/// @return returns true on success, false otherwise.
+	function thawAsset() external returns (bool);
+
+	/// @dev Creates an asset account for the caller.
+	/// @return returns true on success, false otherwise.
+	function touch() external returns (bool);
+
+	/// @dev Removes the asset account of the caller and returns its deposit.
+	/// @param allowBurn Whether to burn the remaining balance of the account.
+	/// @return returns true on success, false otherwise.
+	function refund(bool allowBurn) external returns (bool);
 }
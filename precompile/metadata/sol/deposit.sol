@@ -27,18 +27,32 @@ Deposit constant DEPOSIT_CONTRACT = Deposit(DEPOSIT_ADDRESS);
 /// @title Deposit
 /// @notice The interface of the darwinia deposit pallet.
 interface Deposit {
-	/// @dev Lock the RING for some KTON profit/interest.
-	/// @param ringAmount, the amount of the RING asset
-	/// @param months, the lock time 1 ~ 36
-	/// @return returns true on success, false otherwise.
-	function lock(uint256 ringAmount, uint8 months) external returns (bool);
+    /// @dev The deposit item.
+    struct DepositInfo {
+        uint16 id;
+        uint256 value;
+        uint128 startTime;
+        uint128 expiredTime;
+        bool inUse;
+    }
 
-	/// @dev Claim the expired-locked RING.
-	/// @return returns true on success, false otherwise.
-	function claim() external returns (bool);
+    /// @dev Get the deposits of an account which have not been migrated yet.
+    /// @param who The owner address
+    /// @return the deposits.
+    function deposits(address who) external view returns (DepositInfo[] memory);
 
-	/// @dev Claim the unexpired-locked RING by paying the KTON penalty.
-	/// @param depositId The deposit ticket wish to claim
-	/// @return returns true on success, false otherwise.
-	function claim_with_penalty(uint8 depositId) external returns (bool);
+    /// @dev Get the deposits of an account which failed to migrate.
+    /// Failure codes: 0 unknown, 1 claim, 2 invalid deposit contract, 3 dispatch, 4 contract call.
+    /// @param who The owner address
+    /// @return deposits the failed deposits.
+    /// @return failures the failure codes, in the same order as the deposits.
+    function migration_failures(address who)
+        external
+        view
+        returns (DepositInfo[] memory deposits, uint8[] memory failures);
+
+    /// @dev Request to migrate the deposits of an account to the deposit contract. The migration is done in a later block.
+    /// @param who The owner address
+    /// @return returns true once requested.
+    function migrate_for(address who) external returns (bool);
 }
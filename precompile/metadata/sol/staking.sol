@@ -27,40 +27,23 @@ Staking constant STAKING_CONTRACT = Staking(STAKING_ADDRESS);
 /// @title Staking
 /// @notice The interface of the darwinia staking pallet.
 interface Staking {
-	/// @dev Add stakes to the staking pool.
-	/// @param ringAmount The amount of staking RING asset
-	/// @param depositIds The deposit ids list
-	/// @return returns true on success, false otherwise.
-	function stake(
-		uint256 ringAmount,
-		uint16[] memory depositIds
-	) external returns (bool);
+    /// @dev Get the ideal number of active collators.
+    /// @return the collator count.
+    function collator_count() external view returns (uint32);
 
-	/// @dev Withdraw stakes to the staking pool.
-	/// @param ringAmount The amount of staking RING asset
-	/// @param depositIds The deposit ids list
-	/// @return returns true on success, false otherwise.
-	function unstake(
-		uint256 ringAmount,
-		uint16[] memory depositIds
-	) external returns (bool);
-
-    /// @dev Declare the desire to collect.
-    /// @param commission collator commission, 0 ~ 100
-	/// @return returns true on success, false otherwise.
-	function collect(uint32 commission) external returns (bool);
-
-    /// @dev Declare the desire to nominate a collator.
-    /// @param target The target collator address
-	/// @return returns true on success, false otherwise.
-	function nominate(address target) external returns (bool);
+    /// @dev Get the pending rewards of a collator.
+    /// @param who The collator address
+    /// @return the pending RING staking rewards.
+    function pending_rewards(address who) external view returns (uint256);
 
-    /// @dev Declare no desire to either collect or nominate.
-	/// @return returns true on success, false otherwise.
-	function chill() external returns (bool);
+    /// @dev Get the number of blocks authored by a collator within current session.
+    /// @param who The collator address
+    /// @return total the number of blocks authored by all collators.
+    /// @return authored the number of blocks authored by the collator.
+    function authored_block_count(address who) external view returns (uint32 total, uint32 authored);
 
-	/// @dev Making the payout for the specified collators and its nominators.
+    /// @dev Request to allocate the pending RING staking rewards to the RING staking contract of a collator. The allocation is done in a later block.
     /// @param who The collator address
-	/// @return returns true on success, false otherwise.
-	function payout(address who) external returns (bool);
+    /// @return returns true once requested.
+    function allocate_ring_staking_reward_of(address who) external returns (bool);
 }
@@ -31,4 +31,36 @@ interface StateStorage {
     /// @param storageKey, the storage key follows the substrate storage mechanism.
     /// @return the storage value at the input key.
     function state_storage(bytes memory storageKey) external returns (bytes memory);
+
+    /// @dev Get the storage values on a batch of storage keys, except EVM module.
+    /// @param storageKeys, at most 64 storage keys following the substrate storage mechanism.
+    /// @return the storage values, in the same order as the input keys.
+    function state_storage_batch(bytes[] memory storageKeys) external returns (bytes[] memory);
+
+    /// @dev Iterate the storage entries under a specific prefix, except EVM module.
+    /// @param prefix, the storage prefix, at least the 16 bytes pallet prefix.
+    /// @param startKey, iterate from the key right after it, empty to start from the prefix.
+    /// @param limit, the maximum number of entries to return, at most 64.
+    /// @return keys the storage keys found.
+    /// @return values the storage values, in the same order as the keys.
+    function state_storage_prefix(bytes memory prefix, bytes memory startKey, uint32 limit)
+        external
+        returns (bytes[] memory keys, bytes[] memory values);
+
+    /// @dev Get the state root of the parent block, a trusted root for `verify_storage_proof`.
+    /// @return the state root of the parent block.
+    function parent_state_root() external returns (bytes32);
+
+    /// @dev Verify a storage proof against a state root, except EVM module.
+    /// The proof could be generated by the `state_getReadProof` RPC of a Darwinia/Crab node.
+    /// The root must come from a trusted source, e.g. `parent_state_root` or a verified block header,
+    /// a caller-chosen root proves nothing.
+    /// @param root, the trusted state root of the block that the proof belongs to.
+    /// @param proof, at most 64 trie nodes of the storage proof.
+    /// @param storageKey, the storage key to verify.
+    /// @return exists whether the storage key exists under the state root.
+    /// @return value the storage value at the input key.
+    function verify_storage_proof(bytes32 root, bytes[] memory proof, bytes memory storageKey)
+        external
+        returns (bool exists, bytes memory value);
 }
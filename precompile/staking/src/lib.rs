@@ -0,0 +1,121 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+// core
+use core::marker::PhantomData;
+// crates.io
+use codec::{Compact, MaxEncodedLen};
+// darwinia
+use dc_types::Balance;
+// moonbeam
+use precompile_utils::prelude::*;
+// polkadot-sdk
+use frame_support::{
+	traits::{ConstU32, Get},
+	BoundedVec,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_core::{H160, U256};
+use sp_runtime::traits::UniqueSaturatedInto;
+
+/// AccountId of the runtime.
+type AccountIdOf<R> = <R as frame_system::pallet::Config>::AccountId;
+
+/// The allocations reenter the EVM, which is forbidden from a precompile. So they are only
+/// requested here and then served in `on_idle`.
+pub struct Staking<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> Staking<Runtime>
+where
+	Runtime: darwinia_staking::Config + pallet_evm::Config,
+	AccountIdOf<Runtime>: From<H160>,
+{
+	#[precompile::public("collator_count()")]
+	#[precompile::view]
+	fn collator_count(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+		// Record proof_size cost for the collator count
+		handle.record_db_read::<Runtime>(u32::max_encoded_len())?;
+
+		Ok(<darwinia_staking::CollatorCount<Runtime>>::get())
+	}
+
+	#[precompile::public("pending_rewards(address)")]
+	#[precompile::view]
+	fn pending_rewards(handle: &mut impl PrecompileHandle, who: Address) -> EvmResult<U256> {
+		// Record proof_size cost for the pending rewards
+		handle.record_db_read::<Runtime>(Balance::max_encoded_len())?;
+
+		let who: AccountIdOf<Runtime> = H160::from(who).into();
+
+		Ok(<darwinia_staking::PendingRewards<Runtime>>::get(who).unwrap_or_default().into())
+	}
+
+	#[precompile::public("authored_block_count(address)")]
+	#[precompile::view]
+	fn authored_block_count(
+		handle: &mut impl PrecompileHandle,
+		who: Address,
+	) -> EvmResult<(u32, u32)> {
+		let max_collators = <Runtime as darwinia_staking::Config>::MaxCollators::get() as usize;
+
+		// Record proof_size cost for the authored block count, which holds at most the max
+		// collators
+		handle.record_db_read::<Runtime>(
+			BlockNumberFor::<Runtime>::max_encoded_len()
+				+ Compact::<u32>::max_encoded_len()
+				+ max_collators
+					* (AccountIdOf::<Runtime>::max_encoded_len()
+						+ BlockNumberFor::<Runtime>::max_encoded_len()),
+		)?;
+
+		let (total, authored) = <darwinia_staking::AuthoredBlockCount<Runtime>>::get();
+		let who: AccountIdOf<Runtime> = H160::from(who).into();
+		let count = authored.get(&who).copied().unwrap_or_default();
+
+		Ok((total.unique_saturated_into(), count.unique_saturated_into()))
+	}
+
+	#[precompile::public("allocate_ring_staking_reward_of(address)")]
+	fn allocate_ring_staking_reward_of(
+		handle: &mut impl PrecompileHandle,
+		who: Address,
+	) -> EvmResult<bool> {
+		// Record proof_size cost for the pending rewards and the allocation requests
+		handle.record_db_read::<Runtime>(Balance::max_encoded_len())?;
+		handle.record_db_read::<Runtime>(<BoundedVec<
+			AccountIdOf<Runtime>,
+			ConstU32<{ darwinia_staking::MAX_ALLOCATION_REQUESTS }>,
+		>>::max_encoded_len())?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+		let who: AccountIdOf<Runtime> = H160::from(who).into();
+
+		<darwinia_staking::Pallet<Runtime>>::request_ring_staking_reward_allocation(who)
+			.map_err(|e| revert(<&'static str>::from(e)))?;
+
+		Ok(true)
+	}
+}
@@ -0,0 +1,146 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// std
+use std::collections::BTreeMap;
+// darwinia
+use crate::mock::{
+	Account::{Alice, Bob, Precompile},
+	ExtBuilder, PCall, PrecompilesValue, Runtime, TestPrecompiles,
+};
+// moonbeam
+use precompile_utils::{
+	prelude::{Address, RuntimeHelper},
+	testing::{PrecompileTesterExt, PrecompilesModifierTester},
+};
+// polkadot-sdk
+use sp_core::{H160, U256};
+
+fn precompiles() -> TestPrecompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn alice() -> H160 {
+	Alice.into()
+}
+
+fn bob() -> H160 {
+	Bob.into()
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::collator_count_selectors().contains(&0x6befe20c));
+	assert!(PCall::pending_rewards_selectors().contains(&0x2975e99a));
+	assert!(PCall::authored_block_count_selectors().contains(&0xa1b60b6c));
+	assert!(PCall::allocate_ring_staking_reward_of_selectors().contains(&0xe5a683ff));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(PrecompilesValue::get(), Alice, Precompile);
+
+		tester.test_view_modifier(PCall::collator_count_selectors());
+		tester.test_view_modifier(PCall::pending_rewards_selectors());
+		tester.test_view_modifier(PCall::authored_block_count_selectors());
+		tester.test_default_modifier(PCall::allocate_ring_staking_reward_of_selectors());
+	});
+}
+
+#[test]
+fn collator_count_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		<darwinia_staking::CollatorCount<Runtime>>::put(3);
+
+		precompiles()
+			.prepare_test(Alice, Precompile, PCall::collator_count {})
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+			.expect_no_logs()
+			.execute_returns(3_u32);
+	});
+}
+
+#[test]
+fn pending_rewards_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		<darwinia_staking::PendingRewards<Runtime>>::insert(bob(), 100);
+
+		precompiles()
+			.prepare_test(Alice, Precompile, PCall::pending_rewards { who: Address(Bob.into()) })
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+			.expect_no_logs()
+			.execute_returns(U256::from(100));
+		precompiles()
+			.prepare_test(Alice, Precompile, PCall::pending_rewards { who: Address(Alice.into()) })
+			.execute_returns(U256::zero());
+	});
+}
+
+#[test]
+fn authored_block_count_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		<darwinia_staking::AuthoredBlockCount<Runtime>>::put((
+			5,
+			BTreeMap::from_iter([(alice(), 3)]),
+		));
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::authored_block_count { who: Address(Alice.into()) },
+			)
+			.expect_no_logs()
+			.execute_returns((5_u32, 3_u32));
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::authored_block_count { who: Address(Bob.into()) },
+			)
+			.execute_returns((5_u32, 0_u32));
+	});
+}
+
+#[test]
+fn allocate_ring_staking_reward_of_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::allocate_ring_staking_reward_of { who: Address(Bob.into()) },
+			)
+			.execute_reverts(|output| output == b"NoReward");
+
+		<darwinia_staking::PendingRewards<Runtime>>::insert(bob(), 100);
+
+		// Requested only, the allocation reenters the EVM.
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::allocate_ring_staking_reward_of { who: Address(Bob.into()) },
+			)
+			.expect_no_logs()
+			.execute_returns(true);
+		assert_eq!(<darwinia_staking::AllocationRequests<Runtime>>::get().into_inner(), [bob()]);
+		assert_eq!(<darwinia_staking::PendingRewards<Runtime>>::get(bob()), Some(100));
+	});
+}
@@ -28,22 +28,61 @@ use core::marker::PhantomData;
 // moonbeam
 use precompile_utils::prelude::*;
 // polkadot-sdk
-use frame_support::{StorageHasher, Twox128};
+use frame_support::{traits::ConstU32, StorageHasher, Twox128};
+use sp_core::H256;
+use sp_runtime::traits::BlakeTwo256;
+use sp_std::prelude::*;
+use sp_trie::{LayoutV1, StorageProof};
 
-const PALLET_PREFIX_LENGTH: usize = 16;
+/// Length of the pallet prefix of a storage key.
+pub const PALLET_PREFIX_LENGTH: usize = 16;
+/// Maximum number of entries returned by a single batch or prefix query.
+pub const MAX_QUERY_ENTRIES: u32 = 64;
+
+/// Maximum number of trie nodes accepted by a single proof verification.
+pub const MAX_PROOF_NODES: u32 = 64;
+/// Base gas charged for a proof verification.
+pub const PROOF_BASE_GAS: u64 = 3_000;
+/// Gas charged for every 32 bytes word of the proof.
+pub const PROOF_WORD_GAS: u64 = 24;
+
+type GetQueryLimit = ConstU32<MAX_QUERY_ENTRIES>;
+type GetProofLimit = ConstU32<MAX_PROOF_NODES>;
 
 pub trait StorageFilterT {
-	fn allow(prefix: &[u8]) -> bool;
+	/// Maximum number of storage reads made by [`Self::read_multiplier`].
+	const MAX_LOOKUP_READS: u32 = 0;
+	/// Maximum encoded length of a storage entry read by [`Self::read_multiplier`].
+	const LOOKUP_MAX_ENCODED_LEN: usize = 0;
+
+	/// Return the read gas multiplier of the storage key, or `None` if the read is restricted.
+	///
+	/// The key is at least [`PALLET_PREFIX_LENGTH`] bytes long.
+	fn read_multiplier(key: &[u8]) -> Option<u32>;
+}
+
+pub trait StateRootT {
+	/// Maximum encoded length of the storage entry read by [`Self::parent_state_root`].
+	const MAX_ENCODED_LEN: usize = 0;
+
+	/// Return the state root of the parent block, or `None` if it is unknown.
+	fn parent_state_root() -> Option<H256>;
+}
+impl StateRootT for () {
+	fn parent_state_root() -> Option<H256> {
+		None
+	}
 }
 
-pub struct StateStorage<Runtime, Filter> {
-	_marker: PhantomData<(Runtime, Filter)>,
+pub struct StateStorage<Runtime, Filter, StateRoot = ()> {
+	_marker: PhantomData<(Runtime, Filter, StateRoot)>,
 }
 #[precompile_utils::precompile]
-impl<Runtime, Filter> StateStorage<Runtime, Filter>
+impl<Runtime, Filter, StateRoot> StateStorage<Runtime, Filter, StateRoot>
 where
 	Runtime: pallet_evm::Config,
 	Filter: StorageFilterT,
+	StateRoot: StateRootT,
 {
 	#[precompile::public("state_storage(bytes)")]
 	#[precompile::view]
@@ -52,21 +91,178 @@ where
 		key: UnboundedBytes,
 	) -> EvmResult<UnboundedBytes> {
 		let bytes = key.as_bytes();
-		if bytes.len() < PALLET_PREFIX_LENGTH || !Filter::allow(&bytes[0..PALLET_PREFIX_LENGTH]) {
-			return Err(revert("Read restriction"));
-		}
-
+		let multiplier = Self::ensure_allowed(handle, bytes)?;
 		let output = frame_support::storage::unhashed::get_raw(bytes).unwrap_or_default();
+
 		// Record proof_size cost for the db content
-		handle.record_db_read::<Runtime>(output.len())?;
+		Self::record_read(handle, multiplier, output.len())?;
 
 		Ok(output.as_slice().into())
 	}
+
+	#[precompile::public("state_storage_batch(bytes[])")]
+	#[precompile::view]
+	fn state_storage_batch(
+		handle: &mut impl PrecompileHandle,
+		keys: BoundedVec<UnboundedBytes, GetQueryLimit>,
+	) -> EvmResult<Vec<UnboundedBytes>> {
+		let keys: Vec<UnboundedBytes> = keys.into();
+		let mut outputs = Vec::with_capacity(keys.len());
+
+		for key in keys {
+			let bytes = key.as_bytes();
+			let multiplier = Self::ensure_allowed(handle, bytes)?;
+			let output = frame_support::storage::unhashed::get_raw(bytes).unwrap_or_default();
+
+			// Record proof_size cost for the db content
+			Self::record_read(handle, multiplier, output.len())?;
+
+			outputs.push(output.as_slice().into());
+		}
+
+		Ok(outputs)
+	}
+
+	#[precompile::public("state_storage_prefix(bytes,bytes,uint32)")]
+	#[precompile::view]
+	fn state_storage_prefix(
+		handle: &mut impl PrecompileHandle,
+		prefix: UnboundedBytes,
+		start_key: UnboundedBytes,
+		limit: u32,
+	) -> EvmResult<(Vec<UnboundedBytes>, Vec<UnboundedBytes>)> {
+		let prefix = prefix.as_bytes();
+		let start_key = start_key.as_bytes();
+
+		Self::ensure_allowed(handle, prefix)?;
+
+		if limit > MAX_QUERY_ENTRIES {
+			return Err(revert("Limit exceeded"));
+		}
+		if !start_key.is_empty() && !start_key.starts_with(prefix) {
+			return Err(revert("Invalid start key"));
+		}
+
+		let mut keys = Vec::new();
+		let mut values = Vec::new();
+		let mut previous = if start_key.is_empty() { prefix.to_vec() } else { start_key.to_vec() };
+
+		// The prefix itself is a valid key, include it when iterating from the beginning.
+		if start_key.is_empty() && limit > 0 {
+			if let Some(value) = frame_support::storage::unhashed::get_raw(prefix) {
+				let multiplier = Self::ensure_allowed(handle, prefix)?;
+
+				Self::record_read(handle, multiplier, prefix.len().saturating_add(value.len()))?;
+
+				keys.push(prefix.into());
+				values.push(value.as_slice().into());
+			}
+		}
+
+		while (keys.len() as u32) < limit {
+			// Charge the key lookup before doing it, including the one which ends the iteration.
+			handle.record_db_read::<Runtime>(previous.len())?;
+
+			let Some(key) = sp_io::storage::next_key(&previous).filter(|k| k.starts_with(prefix))
+			else {
+				break;
+			};
+			// The storage items under the prefix might be restricted individually.
+			let multiplier = Self::ensure_allowed(handle, &key)?;
+			let value = frame_support::storage::unhashed::get_raw(&key).unwrap_or_default();
+
+			// Record proof_size cost for both the key and the db content
+			Self::record_read(handle, multiplier, key.len().saturating_add(value.len()))?;
+
+			keys.push(key.as_slice().into());
+			values.push(value.as_slice().into());
+			previous = key;
+		}
+
+		Ok((keys, values))
+	}
+
+	// The parent state root comes from the validation data of the relay chain, so it is a trusted
+	// root to verify the proofs of the parent block against.
+	#[precompile::public("parent_state_root()")]
+	#[precompile::view]
+	fn parent_state_root(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+		handle.record_db_read::<Runtime>(StateRoot::MAX_ENCODED_LEN)?;
+
+		StateRoot::parent_state_root().ok_or_else(|| revert("State root unavailable"))
+	}
+
+	// Proofs can not be generated inside the runtime, since the trie nodes are not accessible
+	// through the host functions. Use the `state_getReadProof` RPC of the node to produce them.
+	//
+	// The `root` must come from a trusted source, e.g. `parent_state_root` or a verified block
+	// header. A proof against a caller-chosen root proves nothing.
+	#[precompile::public("verify_storage_proof(bytes32,bytes[],bytes)")]
+	#[precompile::view]
+	fn verify_storage_proof(
+		handle: &mut impl PrecompileHandle,
+		root: H256,
+		proof: BoundedVec<UnboundedBytes, GetProofLimit>,
+		key: UnboundedBytes,
+	) -> EvmResult<(bool, UnboundedBytes)> {
+		let key = key.as_bytes();
+		let multiplier = Self::ensure_allowed(handle, key)?;
+		let nodes = Vec::<UnboundedBytes>::from(proof)
+			.into_iter()
+			.map(|n| n.as_bytes().to_vec())
+			.collect::<Vec<_>>();
+		let size = nodes.iter().fold(0_u64, |acc, n| acc.saturating_add(n.len() as u64));
+
+		// Every node of the proof is decoded and hashed, charge by the proof size.
+		handle.record_cost(
+			PROOF_BASE_GAS
+				.saturating_add(PROOF_WORD_GAS.saturating_mul(size.div_ceil(32)))
+				.saturating_mul(multiplier.into()),
+		)?;
+
+		let db = StorageProof::new(nodes).into_memory_db::<BlakeTwo256>();
+		let value =
+			sp_trie::read_trie_value::<LayoutV1<BlakeTwo256>, _>(&db, &root, key, None, None)
+				.map_err(|_| revert("Invalid proof"))?;
+
+		Ok(match value {
+			Some(v) => (true, v.into()),
+			None => (false, UnboundedBytes::from(Vec::new())),
+		})
+	}
+
+	fn ensure_allowed(handle: &mut impl PrecompileHandle, key: &[u8]) -> EvmResult<u32> {
+		if key.len() < PALLET_PREFIX_LENGTH {
+			return Err(revert("Read restriction"));
+		}
+
+		// Charge the rule lookups before doing them.
+		for _ in 0..Filter::MAX_LOOKUP_READS {
+			handle.record_db_read::<Runtime>(Filter::LOOKUP_MAX_ENCODED_LEN)?;
+		}
+
+		Filter::read_multiplier(key).ok_or_else(|| revert("Read restriction"))
+	}
+
+	fn record_read(
+		handle: &mut impl PrecompileHandle,
+		multiplier: u32,
+		data_max_encoded_len: usize,
+	) -> EvmResult {
+		handle.record_db_read::<Runtime>(data_max_encoded_len)?;
+		// Charge the extra read gas of the expensive prefixes.
+		handle.record_cost(
+			RuntimeHelper::<Runtime>::db_read_gas_cost()
+				.saturating_mul(multiplier.saturating_sub(1).into()),
+		)?;
+
+		Ok(())
+	}
 }
 
 pub struct StateStorageFilter;
 impl StorageFilterT for StateStorageFilter {
-	fn allow(prefix: &[u8]) -> bool {
-		prefix != Twox128::hash(b"EVM")
+	fn read_multiplier(key: &[u8]) -> Option<u32> {
+		(key[..PALLET_PREFIX_LENGTH] != Twox128::hash(b"EVM")).then_some(1)
 	}
 }
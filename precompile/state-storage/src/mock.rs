@@ -25,13 +25,13 @@ use crate::*;
 use precompile_utils::Precompile;
 // polkadot-sdk
 use frame_support::{derive_impl, StorageHasher};
-use sp_core::H160;
+use sp_core::{H160, H256};
 use sp_runtime::BuildStorage;
 use sp_std::{marker::PhantomData, prelude::*};
 
 pub type Balance = u64;
 pub type AccountId = H160;
-pub type PCall = StateStorageCall<Runtime, StorageFilter>;
+pub type PCall = StateStorageCall<Runtime, StorageFilter, StateRoot>;
 
 #[derive(Clone, Encode, Decode, Debug, MaxEncodedLen, TypeInfo)]
 pub enum Account {
@@ -68,8 +68,35 @@ impl pallet_timestamp::Config for Runtime {}
 
 pub struct StorageFilter;
 impl StorageFilterT for StorageFilter {
-	fn allow(prefix: &[u8]) -> bool {
-		prefix != frame_support::Twox128::hash(b"EVM")
+	const LOOKUP_MAX_ENCODED_LEN: usize = 46;
+	const MAX_LOOKUP_READS: u32 = 1;
+
+	fn read_multiplier(key: &[u8]) -> Option<u32> {
+		let evm = frame_support::Twox128::hash(b"EVM");
+		let costly =
+			[frame_support::Twox128::hash(b"Test"), frame_support::Twox128::hash(b"Costly")]
+				.concat();
+
+		if key[..16] == evm {
+			None
+		} else if key.starts_with(&costly) {
+			Some(3)
+		} else {
+			Some(1)
+		}
+	}
+}
+
+frame_support::parameter_types! {
+	pub static ParentStateRoot: Option<H256> = None;
+}
+
+pub struct StateRoot;
+impl StateRootT for StateRoot {
+	const MAX_ENCODED_LEN: usize = 32;
+
+	fn parent_state_root() -> Option<H256> {
+		ParentStateRoot::get()
 	}
 }
 
@@ -89,12 +116,12 @@ where
 }
 impl<R> fp_evm::PrecompileSet for TestPrecompiles<R>
 where
-	StateStorage<R, StorageFilter>: fp_evm::Precompile,
+	StateStorage<R, StorageFilter, StateRoot>: fp_evm::Precompile,
 	R: pallet_evm::Config,
 {
 	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<EvmResult<PrecompileOutput>> {
 		match handle.code_address() {
-			a if a == addr(1) => Some(StateStorage::<R, StorageFilter>::execute(handle)),
+			a if a == addr(1) => Some(StateStorage::<R, StorageFilter, StateRoot>::execute(handle)),
 			_ => None,
 		}
 	}
@@ -19,7 +19,7 @@
 // darwinia
 use crate::mock::{
 	Account::{Alice, Precompile},
-	ExtBuilder, PCall, PrecompilesValue, Runtime, System, TestPrecompiles,
+	ExtBuilder, PCall, ParentStateRoot, PrecompilesValue, Runtime, System, TestPrecompiles,
 };
 // moonbeam
 use precompile_utils::{
@@ -27,7 +27,12 @@ use precompile_utils::{
 	testing::{PrecompileTesterExt, PrecompilesModifierTester},
 };
 // polkadot-sdk
-use frame_support::{StorageHasher, Twox128};
+use frame_support::{storage::unhashed, StorageHasher, Twox128};
+use sp_core::H256;
+
+fn storage_key(pallet: &[u8], item: &[u8]) -> Vec<u8> {
+	[Twox128::hash(pallet), Twox128::hash(item)].concat()
+}
 
 fn precompiles() -> TestPrecompiles<Runtime> {
 	PrecompilesValue::get()
@@ -36,6 +41,10 @@ fn precompiles() -> TestPrecompiles<Runtime> {
 #[test]
 fn selectors() {
 	assert!(PCall::state_storage_at_selectors().contains(&0x78943fb7));
+	assert!(PCall::state_storage_batch_selectors().contains(&0xf656f385));
+	assert!(PCall::state_storage_prefix_selectors().contains(&0xf7bc60a6));
+	assert!(PCall::parent_state_root_selectors().contains(&0xcb99ea2f));
+	assert!(PCall::verify_storage_proof_selectors().contains(&0xf99ab688));
 }
 
 #[test]
@@ -43,6 +52,10 @@ fn modifier() {
 	ExtBuilder::default().build().execute_with(|| {
 		let mut tester = PrecompilesModifierTester::new(PrecompilesValue::get(), Alice, Precompile);
 		tester.test_view_modifier(PCall::state_storage_at_selectors());
+		tester.test_view_modifier(PCall::state_storage_batch_selectors());
+		tester.test_view_modifier(PCall::state_storage_prefix_selectors());
+		tester.test_view_modifier(PCall::parent_state_root_selectors());
+		tester.test_view_modifier(PCall::verify_storage_proof_selectors());
 	});
 }
 
@@ -66,7 +79,7 @@ fn test_state_storage() {
 
 		precompiles()
 			.prepare_test(Alice, Precompile, PCall::state_storage_at { key: key.into() })
-			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost() * 2)
 			.expect_no_logs()
 			.execute_returns(UnboundedBytes::from(&5u64.to_le_bytes()));
 	});
@@ -85,3 +98,228 @@ fn test_storage_filter() {
 			.execute_reverts(|output| output == b"Read restriction");
 	});
 }
+
+#[test]
+fn test_state_storage_batch() {
+	ExtBuilder::default().with_balances(vec![(Alice.into(), 100)]).build().execute_with(|| {
+		System::set_block_number(5);
+
+		let number = storage_key(b"System", b"Number");
+		let missing = storage_key(b"System", b"Missing");
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::state_storage_batch { keys: vec![number.into(), missing.into()].into() },
+			)
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost() * 4)
+			.expect_no_logs()
+			.execute_returns(vec![
+				UnboundedBytes::from(&5u64.to_le_bytes()),
+				UnboundedBytes::from(Vec::new()),
+			]);
+
+		let restricted = storage_key(b"EVM", b"AccountCodes");
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::state_storage_batch {
+					keys: vec![storage_key(b"System", b"Number").into(), restricted.into()].into(),
+				},
+			)
+			.expect_no_logs()
+			.execute_reverts(|output| output == b"Read restriction");
+	});
+}
+
+#[test]
+fn test_state_storage_prefix() {
+	ExtBuilder::default().with_balances(vec![(Alice.into(), 100)]).build().execute_with(|| {
+		let prefix = storage_key(b"Test", b"Map");
+		let entries =
+			(1..=3u8).map(|i| ([prefix.clone(), vec![i]].concat(), vec![i; 4])).collect::<Vec<_>>();
+
+		entries.iter().for_each(|(k, v)| unhashed::put_raw(k, v));
+		unhashed::put_raw(&storage_key(b"Test", b"Other"), &[9]);
+
+		let to_bytes =
+			|v: Vec<Vec<u8>>| v.into_iter().map(UnboundedBytes::from).collect::<Vec<_>>();
+		let keys = to_bytes(entries.iter().map(|(k, _)| k.clone()).collect());
+		let values = to_bytes(entries.iter().map(|(_, v)| v.clone()).collect());
+
+		// First page.
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::state_storage_prefix {
+					prefix: prefix.clone().into(),
+					start_key: UnboundedBytes::from(Vec::new()),
+					limit: 2,
+				},
+			)
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost() * 7)
+			.expect_no_logs()
+			.execute_returns((keys[..2].to_vec(), values[..2].to_vec()));
+		// Next page, starting right after the last returned key.
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::state_storage_prefix {
+					prefix: prefix.clone().into(),
+					start_key: keys[1].clone(),
+					limit: 2,
+				},
+			)
+			// The last key lookup ends the iteration, it is charged as well.
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost() * 5)
+			.expect_no_logs()
+			.execute_returns((keys[2..].to_vec(), values[2..].to_vec()));
+	});
+}
+
+#[test]
+fn test_state_storage_prefix_checks() {
+	ExtBuilder::default().with_balances(vec![(Alice.into(), 100)]).build().execute_with(|| {
+		let prefix = storage_key(b"Test", b"Map");
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::state_storage_prefix {
+					prefix: Twox128::hash(b"EVM").to_vec().into(),
+					start_key: UnboundedBytes::from(Vec::new()),
+					limit: 1,
+				},
+			)
+			.execute_reverts(|output| output == b"Read restriction");
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::state_storage_prefix {
+					prefix: prefix.clone().into(),
+					start_key: storage_key(b"Test", b"Other").into(),
+					limit: 1,
+				},
+			)
+			.execute_reverts(|output| output == b"Invalid start key");
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::state_storage_prefix {
+					prefix: prefix.into(),
+					start_key: UnboundedBytes::from(Vec::new()),
+					limit: crate::MAX_QUERY_ENTRIES + 1,
+				},
+			)
+			.execute_reverts(|output| output == b"Limit exceeded");
+	});
+}
+
+#[test]
+fn test_parent_state_root() {
+	ExtBuilder::default().with_balances(vec![(Alice.into(), 100)]).build().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile, PCall::parent_state_root {})
+			.execute_reverts(|output| output == b"State root unavailable");
+
+		ParentStateRoot::set(Some(H256::repeat_byte(1)));
+
+		precompiles()
+			.prepare_test(Alice, Precompile, PCall::parent_state_root {})
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+			.expect_no_logs()
+			.execute_returns(H256::repeat_byte(1));
+	});
+}
+
+#[test]
+fn test_verify_storage_proof() {
+	let mut ext = ExtBuilder::default().with_balances(vec![(Alice.into(), 100)]).build();
+	let key = storage_key(b"System", b"Number");
+
+	ext.execute_with(|| System::set_block_number(5));
+	ext.commit_all().unwrap();
+
+	let root = *ext.backend.root();
+	let (_, proof) = ext.execute_and_prove(|| unhashed::get_raw(&key));
+	let proof = proof.into_iter_nodes().collect::<Vec<_>>();
+	let size = proof.iter().map(|n| n.len() as u64).sum::<u64>();
+	let to_bytes = |v: &[Vec<u8>]| v.iter().cloned().map(UnboundedBytes::from).collect::<Vec<_>>();
+
+	ext.execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::verify_storage_proof {
+					root,
+					proof: to_bytes(&proof).into(),
+					key: key.clone().into(),
+				},
+			)
+			.expect_cost(
+				crate::PROOF_BASE_GAS
+					+ crate::PROOF_WORD_GAS * size.div_ceil(32)
+					+ RuntimeHelper::<Runtime>::db_read_gas_cost(),
+			)
+			.expect_no_logs()
+			.execute_returns((true, UnboundedBytes::from(&5u64.to_le_bytes())));
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::verify_storage_proof {
+					root,
+					proof: to_bytes(&proof).into(),
+					key: storage_key(b"System", b"Missing").into(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns((false, UnboundedBytes::from(Vec::new())));
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::verify_storage_proof {
+					root,
+					proof: to_bytes(&proof[1..]).into(),
+					key: key.clone().into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Invalid proof");
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile,
+				PCall::verify_storage_proof {
+					root,
+					proof: to_bytes(&proof).into(),
+					key: storage_key(b"EVM", b"AccountCodes").into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Read restriction");
+	});
+}
+
+#[test]
+fn test_read_multiplier() {
+	ExtBuilder::default().with_balances(vec![(Alice.into(), 100)]).build().execute_with(|| {
+		let key = storage_key(b"Test", b"Costly");
+
+		unhashed::put_raw(&key, &[1]);
+
+		precompiles()
+			.prepare_test(Alice, Precompile, PCall::state_storage_at { key: key.into() })
+			.expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost() * 4)
+			.expect_no_logs()
+			.execute_returns(UnboundedBytes::from(&[1u8]));
+	});
+}
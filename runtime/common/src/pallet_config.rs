@@ -26,12 +26,109 @@ pub mod precompiles {
 	pub const ADDR_USDT: [u8; 20] = address_of(0x403);
 	pub const ADDR_PINK: [u8; 20] = address_of(0x404);
 	pub const ADDR_DOT: [u8; 20] = address_of(0x405);
-	pub const ADDR_DEPOSIT_DEPRECATED: [u8; 20] = address_of(0x600);
-	pub const ADDR_STAKING_DEPRECATED: [u8; 20] = address_of(0x601);
+	pub const ADDR_DEPOSIT: [u8; 20] = address_of(0x600);
+	pub const ADDR_STAKING: [u8; 20] = address_of(0x601);
 	pub const ADDR_CONVICTION_VOTING: [u8; 20] = address_of(0x602);
 	// [0x800..) for the experimental precompiles.
 	pub const ADDR_EXPERIMENTAL: [u8; 20] = address_of(0x800);
 
+	/// Kinds of the precompiles, see `darwinia_precompile_registry`.
+	///
+	/// The kinds are stored on-chain, new ones must be appended with the next index.
+	#[derive(
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		codec::Encode,
+		codec::Decode,
+		codec::MaxEncodedLen,
+		scale_info::TypeInfo,
+		sp_runtime::RuntimeDebug,
+	)]
+	pub enum PrecompileKind {
+		#[codec(index = 0)]
+		EcRecover,
+		#[codec(index = 1)]
+		Sha256,
+		#[codec(index = 2)]
+		Ripemd160,
+		#[codec(index = 3)]
+		Identity,
+		#[codec(index = 4)]
+		Modexp,
+		#[codec(index = 5)]
+		Bn128Add,
+		#[codec(index = 6)]
+		Bn128Mul,
+		#[codec(index = 7)]
+		Bn128Pairing,
+		#[codec(index = 8)]
+		Blake2F,
+		#[codec(index = 9)]
+		StateStorage,
+		#[codec(index = 10)]
+		Dispatch,
+		#[codec(index = 11)]
+		Assets,
+		#[codec(index = 12)]
+		ConvictionVoting,
+		/// Reserved address which reverts on any call.
+		#[codec(index = 13)]
+		Unsupported,
+		#[codec(index = 14)]
+		Bls12381G1Add,
+		#[codec(index = 15)]
+		Bls12381G1Mul,
+		#[codec(index = 16)]
+		Bls12381G1MultiExp,
+		#[codec(index = 17)]
+		Bls12381G2Add,
+		#[codec(index = 18)]
+		Bls12381G2Mul,
+		#[codec(index = 19)]
+		Bls12381G2MultiExp,
+		#[codec(index = 20)]
+		Bls12381Pairing,
+		#[codec(index = 21)]
+		Bls12381MapG1,
+		#[codec(index = 22)]
+		Bls12381MapG2,
+		#[codec(index = 23)]
+		Deposit,
+		#[codec(index = 24)]
+		Staking,
+	}
+	impl PrecompileKind {
+		/// Whether the precompile is an Ethereum official one.
+		///
+		/// Only the Ethereum official precompiles can be called using `DELEGATECALL` or
+		/// `CALLCODE`.
+		pub fn is_ethereum_official(self) -> bool {
+			matches!(
+				self,
+				Self::EcRecover
+					| Self::Sha256
+					| Self::Ripemd160
+					| Self::Identity
+					| Self::Modexp
+					| Self::Bn128Add
+					| Self::Bn128Mul
+					| Self::Bn128Pairing
+					| Self::Blake2F
+					| Self::Bls12381G1Add
+					| Self::Bls12381G1Mul
+					| Self::Bls12381G1MultiExp
+					| Self::Bls12381G2Add
+					| Self::Bls12381G2Mul
+					| Self::Bls12381G2MultiExp
+					| Self::Bls12381Pairing
+					| Self::Bls12381MapG1
+					| Self::Bls12381MapG2
+			)
+		}
+	}
+
 	pub const fn address_of(v: u64) -> [u8; 20] {
 		[
 			0,
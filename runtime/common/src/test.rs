@@ -25,7 +25,10 @@ macro_rules! impl_account_migration_tests {
 			use darwinia_deposit::Deposit as DepositS;
 			// polkadot-sdk
 			use frame_support::{
-				assert_err, assert_ok, migration, Blake2_128Concat, StorageHasher,
+				assert_err, assert_ok, migration,
+				traits::{fungible, OnIdle},
+				weights::Weight,
+				Blake2_128Concat, StorageHasher,
 			};
 			use frame_system::AccountInfo;
 			use pallet_assets::ExistenceReason;
@@ -142,14 +145,16 @@ macro_rules! impl_account_migration_tests {
 			}
 
 			fn migrate(from: Pair, to: AccountId) -> Result<(), E> {
+				let from_pk = AccountId32::new(from.public().0);
 				let message = darwinia_account_migration::signable_message(
 					<<Runtime as frame_system::Config>::Version as Get<RuntimeVersion>>::get()
 						.spec_name
 						.as_bytes(),
+					darwinia_account_migration::MigrationKind::Key,
+					&from_pk,
 					&to,
 				);
 				let sig = from.sign(&message);
-				let from_pk = AccountId32::new(from.public().0);
 
 				AccountMigration::pre_dispatch(&darwinia_account_migration::Call::migrate {
 					from: from_pk.clone(),
@@ -191,7 +196,12 @@ macro_rules! impl_account_migration_tests {
 			fn validate_invalid_sig() {
 				let (from, from_pk) = alice();
 				let to = H160::from_low_u64_be(0).into();
-				let message = darwinia_account_migration::signable_message(b"?", &to);
+				let message = darwinia_account_migration::signable_message(
+					b"?",
+					darwinia_account_migration::MigrationKind::Key,
+					&from_pk,
+					&to,
+				);
 				let sig = from.sign(&message);
 
 				ExtBuilder::default().build().execute_with(|| {
@@ -320,6 +330,404 @@ macro_rules! impl_account_migration_tests {
 						assert_eq!(Assets::maybe_balance(KTON_ID, to).unwrap(), 100);
 					});
 			}
+
+			#[test]
+			fn migration_progress_should_be_tracked() {
+				let (from, from_pk) = alice();
+				let to = H160::from_low_u64_be(255).into();
+
+				ExtBuilder::default().build().execute_with(|| {
+					preset_state_of(&from);
+					AccountMigration::initialize_totals();
+					<AccountMigration as OnIdle<_>>::on_idle(0, Weight::MAX);
+
+					assert!(<darwinia_account_migration::TotalsCursor<Runtime>>::get().is_none());
+					assert_eq!(
+						AccountMigration::account_status(&from_pk),
+						darwinia_account_migration::AccountStatus::Pending {
+							ring: RING_AMOUNT,
+							kton: KTON_AMOUNT,
+							deposits: 0
+						}
+					);
+					assert!(AccountMigration::is_destination_usable(&from_pk, &to));
+					assert_eq!(
+						<darwinia_account_migration::Totals<Runtime>>::get(),
+						darwinia_account_migration::MigrationTotals {
+							accounts: 1,
+							ring: RING_AMOUNT,
+							kton: KTON_AMOUNT,
+							deposits: 0,
+							deposit_ring: 0
+						}
+					);
+
+					assert_ok!(migrate(from, to));
+					assert_eq!(
+						AccountMigration::account_status(&from_pk),
+						darwinia_account_migration::AccountStatus::Migrated { to }
+					);
+					assert!(!AccountMigration::is_destination_usable(&from_pk, &to));
+					assert_eq!(
+						<darwinia_account_migration::Totals<Runtime>>::get(),
+						Default::default()
+					);
+				});
+			}
+
+			#[test]
+			fn migration_during_counting_should_be_tracked() {
+				let pairs = [Keyring::Alice.pair(), Keyring::Bob.pair()];
+				let to = H160::from_low_u64_be(255).into();
+
+				ExtBuilder::default().build().execute_with(|| {
+					pairs.iter().for_each(preset_state_of);
+					AccountMigration::initialize_totals();
+
+					// Count a single account.
+					<AccountMigration as OnIdle<_>>::on_idle(
+						0,
+						<Runtime as frame_system::Config>::DbWeight::get().reads_writes(3, 2),
+					);
+
+					let last_key = <darwinia_account_migration::TotalsCursor<Runtime>>::get()
+						.unwrap()
+						.last_key
+						.unwrap();
+					let (counted, uncounted) =
+						if <darwinia_account_migration::Accounts<Runtime>>::hashed_key_for(
+							AccountId32::new(pairs[0].public().0),
+						) == last_key.into_inner()
+						{
+							(pairs[0].clone(), pairs[1].clone())
+						} else {
+							(pairs[1].clone(), pairs[0].clone())
+						};
+
+					// The uncounted one will never be counted.
+					assert_ok!(migrate(uncounted, to));
+					<AccountMigration as OnIdle<_>>::on_idle(0, Weight::MAX);
+					assert!(<darwinia_account_migration::TotalsCursor<Runtime>>::get().is_none());
+					assert_eq!(
+						<darwinia_account_migration::Totals<Runtime>>::get(),
+						darwinia_account_migration::MigrationTotals {
+							accounts: 1,
+							ring: RING_AMOUNT,
+							kton: KTON_AMOUNT,
+							deposits: 0,
+							deposit_ring: 0
+						}
+					);
+
+					assert_ok!(migrate(counted, H160::from_low_u64_be(256).into()));
+					assert_eq!(
+						<darwinia_account_migration::Totals<Runtime>>::get(),
+						Default::default()
+					);
+				});
+			}
+
+			#[test]
+			fn sweep_and_reclaim_should_work() {
+				let (from, from_pk) = alice();
+				let to = H160::from_low_u64_be(255).into();
+				let pallet_account = darwinia_account_migration::account_id();
+				let deposit = DepositS {
+					id: 1,
+					value: 10,
+					start_time: 1000,
+					expired_time: 2000,
+					in_use: true,
+				};
+
+				ExtBuilder::default().build().execute_with(|| {
+					preset_state_of(&from);
+
+					<darwinia_account_migration::Deposits<Runtime>>::insert(
+						&from_pk,
+						vec![deposit.clone()],
+					);
+
+					// Claim deadline is not set.
+					assert!(AccountMigration::start_sweep(RuntimeOrigin::root()).is_err());
+					assert_ok!(AccountMigration::set_claim_deadline(
+						RuntimeOrigin::root(),
+						Some(0)
+					));
+					assert_ok!(AccountMigration::start_sweep(RuntimeOrigin::root()));
+
+					<AccountMigration as OnIdle<_>>::on_idle(0, Weight::MAX);
+					assert!(!<darwinia_account_migration::Sweeping<Runtime>>::get());
+					assert_eq!(
+						<darwinia_account_migration::Accounts<Runtime>>::get(&from_pk),
+						None
+					);
+					assert_eq!(
+						<darwinia_account_migration::Deposits<Runtime>>::get(&from_pk),
+						None
+					);
+					assert_eq!(
+						AccountMigration::account_status(&from_pk),
+						darwinia_account_migration::AccountStatus::Swept {
+							ring: RING_AMOUNT,
+							kton: KTON_AMOUNT,
+							deposits: 1
+						}
+					);
+					assert_eq!(Balances::free_balance(pallet_account), RING_AMOUNT);
+					assert_eq!(
+						Assets::maybe_balance(KTON_ID, pallet_account).unwrap(),
+						KTON_AMOUNT
+					);
+
+					// Late owner reclaims from the pallet account.
+					assert_ok!(migrate(from, to));
+					assert_eq!(
+						<darwinia_account_migration::SweptAccounts<Runtime>>::get(&from_pk),
+						None
+					);
+					assert_eq!(
+						<darwinia_account_migration::SweptDeposits<Runtime>>::get(&from_pk),
+						None
+					);
+					assert_eq!(Balances::free_balance(pallet_account), 0);
+					assert_eq!(Assets::maybe_balance(KTON_ID, pallet_account), None);
+					// The deposited RING goes to the deposit treasury along with the deposits.
+					assert_eq!(
+						Balances::free_balance(
+							<Runtime as darwinia_deposit::Config>::Treasury::get()
+						),
+						deposit.value
+					);
+					assert_eq!(Balances::free_balance(to), RING_AMOUNT - deposit.value);
+					assert_eq!(Assets::maybe_balance(KTON_ID, to).unwrap(), KTON_AMOUNT);
+					assert_eq!(
+						<darwinia_deposit::Deposits<Runtime>>::get(to).unwrap().into_inner(),
+						vec![deposit]
+					);
+				});
+			}
+
+			#[test]
+			fn sweep_should_leave_kton_dust() {
+				let (from, from_pk) = alice();
+				let to = H160::from_low_u64_be(255).into();
+				let pallet_account = darwinia_account_migration::account_id();
+				let asset_details_key = Blake2_128Concat::hash(&KTON_ID.encode());
+
+				ExtBuilder::default().build().execute_with(|| {
+					preset_state_of(&from);
+
+					// Make the legacy KTON dust.
+					let mut asset_details = migration::get_storage_value::<AssetDetails>(
+						b"Assets",
+						b"Asset",
+						&asset_details_key,
+					)
+					.unwrap();
+
+					asset_details.min_balance = KTON_AMOUNT + 1;
+					migration::put_storage_value(
+						b"Assets",
+						b"Asset",
+						&asset_details_key,
+						asset_details,
+					);
+
+					assert_ok!(AccountMigration::set_claim_deadline(
+						RuntimeOrigin::root(),
+						Some(0)
+					));
+					assert_ok!(AccountMigration::start_sweep(RuntimeOrigin::root()));
+
+					<AccountMigration as OnIdle<_>>::on_idle(0, Weight::MAX);
+					assert!(!<darwinia_account_migration::Sweeping<Runtime>>::get());
+					assert_eq!(
+						<darwinia_account_migration::Accounts<Runtime>>::get(&from_pk),
+						None
+					);
+					assert!(<darwinia_account_migration::KtonAccounts<Runtime>>::get(&from_pk)
+						.is_some());
+					assert_eq!(
+						AccountMigration::account_status(&from_pk),
+						darwinia_account_migration::AccountStatus::Swept {
+							ring: RING_AMOUNT,
+							kton: KTON_AMOUNT,
+							deposits: 0
+						}
+					);
+					assert_eq!(Balances::free_balance(pallet_account), RING_AMOUNT);
+					assert_eq!(Assets::maybe_balance(KTON_ID, pallet_account), None);
+
+					// The unswept KTON is reclaimed along with the swept RING.
+					assert_ok!(migrate(from, to));
+					assert_eq!(
+						<darwinia_account_migration::KtonAccounts<Runtime>>::get(&from_pk),
+						None
+					);
+					assert_eq!(Balances::free_balance(to), RING_AMOUNT);
+					assert_eq!(Assets::maybe_balance(KTON_ID, to).unwrap(), KTON_AMOUNT);
+				});
+			}
+
+			#[test]
+			fn legacy_reserves_and_locks_should_be_converted() {
+				let (from, from_pk) = alice();
+				let to = H160::from_low_u64_be(255).into();
+				let hold_reason = darwinia_account_migration::HoldReason::LegacyReserve.into();
+				let freeze_id = darwinia_account_migration::FreezeReason::LegacyLock.into();
+
+				ExtBuilder::default().build().execute_with(|| {
+					preset_state_of(&from);
+
+					<darwinia_account_migration::Accounts<Runtime>>::mutate(&from_pk, |a| {
+						let a = a.as_mut().unwrap();
+
+						a.data.free -= 20;
+						a.data.reserved = 20;
+					});
+					assert_ok!(AccountMigration::import_legacy_balance(
+						RuntimeOrigin::root(),
+						from_pk.clone(),
+						vec![(*b"identity", 10)].try_into().unwrap(),
+						vec![(*b"vesting ", 30), (*b"democrac", 40)].try_into().unwrap(),
+						Default::default(),
+					));
+
+					assert_ok!(migrate(from, to));
+					assert_eq!(
+						<darwinia_account_migration::Reserves<Runtime>>::get(&from_pk),
+						None
+					);
+					assert_eq!(<darwinia_account_migration::Locks<Runtime>>::get(&from_pk), None);
+					// The reserve is held.
+					assert_eq!(Balances::free_balance(to), RING_AMOUNT - 10);
+					assert_eq!(
+						<Balances as fungible::InspectHold<_>>::balance_on_hold(&hold_reason, &to),
+						10
+					);
+					// The democracy lock is frozen, the vesting lock is released.
+					assert_eq!(
+						<Balances as fungible::InspectFreeze<_>>::balance_frozen(&freeze_id, &to),
+						40
+					);
+					assert!(<pallet_balances::Locks<Runtime>>::get(to).is_empty());
+					System::assert_has_event(RuntimeEvent::AccountMigration(
+						darwinia_account_migration::Event::BalanceConverted {
+							to,
+							held: vec![(*b"identity", 10)],
+							frozen: vec![(*b"democrac", 40)],
+							vesting: 0,
+							released: 10,
+						},
+					));
+
+					assert_ok!(AccountMigration::release_legacy_balance(RuntimeOrigin::root(), to));
+					assert_eq!(Balances::free_balance(to), RING_AMOUNT);
+					assert_eq!(
+						<Balances as fungible::InspectHold<_>>::balance_on_hold(&hold_reason, &to),
+						0
+					);
+					assert_eq!(
+						<Balances as fungible::InspectFreeze<_>>::balance_frozen(&freeze_id, &to),
+						0
+					);
+				});
+			}
+
+			#[test]
+			fn legacy_reserves_should_be_released_if_unrecorded() {
+				let (from, from_pk) = alice();
+				let to = H160::from_low_u64_be(255).into();
+
+				ExtBuilder::default().build().execute_with(|| {
+					preset_state_of(&from);
+
+					<darwinia_account_migration::Accounts<Runtime>>::mutate(&from_pk, |a| {
+						let a = a.as_mut().unwrap();
+
+						a.data.free -= 20;
+						a.data.reserved = 20;
+					});
+
+					assert_ok!(migrate(from, to));
+					assert_eq!(Balances::free_balance(to), RING_AMOUNT);
+					assert_eq!(Balances::reserved_balance(to), 0);
+					System::assert_has_event(RuntimeEvent::AccountMigration(
+						darwinia_account_migration::Event::BalanceConverted {
+							to,
+							held: Vec::new(),
+							frozen: Vec::new(),
+							vesting: 0,
+							released: 20,
+						},
+					));
+				});
+			}
+
+			#[test]
+			fn legacy_vesting_should_be_converted() {
+				let (from, from_pk) = alice();
+				let to = H160::from_low_u64_be(255).into();
+				let freeze_id = darwinia_account_migration::FreezeReason::LegacyVesting.into();
+				let frozen =
+					|| <Balances as fungible::InspectFreeze<_>>::balance_frozen(&freeze_id, &to);
+
+				ExtBuilder::default().build().execute_with(|| {
+					preset_state_of(&from);
+					Timestamp::set_timestamp(1_000);
+
+					// Not found.
+					assert_err!(
+						AccountMigration::import_legacy_balance(
+							RuntimeOrigin::root(),
+							[0; 32].into(),
+							Default::default(),
+							Default::default(),
+							Default::default(),
+						),
+						<darwinia_account_migration::Error<Runtime>>::AccountNotFound
+					);
+					assert_ok!(AccountMigration::import_legacy_balance(
+						RuntimeOrigin::root(),
+						from_pk.clone(),
+						Default::default(),
+						Default::default(),
+						vec![darwinia_account_migration::VestingSchedule {
+							locked: 60,
+							start: 1_000,
+							end: 2_000
+						}]
+						.try_into()
+						.unwrap(),
+					));
+
+					assert_ok!(migrate(from, to));
+					assert_eq!(
+						<darwinia_account_migration::Vestings<Runtime>>::get(&from_pk),
+						None
+					);
+					assert_eq!(frozen(), 60);
+
+					// Half vested.
+					Timestamp::set_timestamp(1_500);
+					assert_ok!(AccountMigration::vest(RuntimeOrigin::signed(to)));
+					assert_eq!(frozen(), 30);
+
+					// Fully vested.
+					Timestamp::set_timestamp(2_000);
+					assert_ok!(AccountMigration::vest(RuntimeOrigin::signed(to)));
+					assert_eq!(frozen(), 0);
+					assert_eq!(
+						<darwinia_account_migration::VestingSchedules<Runtime>>::get(to),
+						None
+					);
+					assert_err!(
+						AccountMigration::vest(RuntimeOrigin::signed(to)),
+						<darwinia_account_migration::Error<Runtime>>::NotVesting
+					);
+				});
+			}
 		}
 	};
 }
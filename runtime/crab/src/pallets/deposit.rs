@@ -24,6 +24,8 @@ impl darwinia_deposit::Config for Runtime {
 	type DepositMigrator = darwinia_deposit::DepositMigrator<Self>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type DepositMigrator = ();
+	// Approximately consume 160,000 gas per deposit on Koi testnet.
+	type MigrationGasPerDeposit = ConstU64<200_000>;
 	type Ring = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type Treasury = pallet_config::TreasuryAccount;
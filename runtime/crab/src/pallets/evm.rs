@@ -18,10 +18,12 @@
 
 // darwinia
 use crate::*;
-use pallet_config::precompiles::{self, *};
+use pallet_config::precompiles::*;
 // frontier
 use pallet_evm::{ExitError, IsPrecompileResult, Precompile};
 use pallet_evm_precompile_dispatch::DispatchValidateT;
+// moonbeam
+use precompile_utils::prelude::{PrecompileHandleExt, RuntimeHelper};
 // polkadot-sdk
 use frame_support::dispatch::{DispatchClass, GetDispatchInfo, Pays};
 
@@ -29,25 +31,45 @@ frame_support::parameter_types! {
 	pub PrecompilesValue: Precompiles = Precompiles;
 }
 
+type Bls12381 = darwinia_precompile_bls12_381::Bls12381<
+	Runtime,
+	weights::darwinia_precompile_bls12_381::WeightInfo<Runtime>,
+>;
+
 pub struct Precompiles;
 impl Precompiles {
-	pub fn set() -> [[u8; 20]; 14] {
+	/// Precompiles registered at genesis, see [`PrecompileRegistry`].
+	pub fn defaults() -> Vec<(H160, PrecompileKind)> {
 		[
-			ADDR_EC_RECOVER,
-			ADDR_SHA256,
-			ADDR_RIPEMD160,
-			ADDR_IDENTITY,
-			ADDR_MODEXP,
-			ADDR_BN128_ADD,
-			ADDR_BN128_MUL,
-			ADDR_BN128_PAIRING,
-			ADDR_BLAKE2F,
-			ADDR_STATE_STORAGE,
-			ADDR_DISPATCH,
-			ADDR_KTON,
-			ADDR_CONVICTION_VOTING,
-			ADDR_EXPERIMENTAL,
+			(ADDR_EC_RECOVER, PrecompileKind::EcRecover),
+			(ADDR_SHA256, PrecompileKind::Sha256),
+			(ADDR_RIPEMD160, PrecompileKind::Ripemd160),
+			(ADDR_IDENTITY, PrecompileKind::Identity),
+			(ADDR_MODEXP, PrecompileKind::Modexp),
+			(ADDR_BN128_ADD, PrecompileKind::Bn128Add),
+			(ADDR_BN128_MUL, PrecompileKind::Bn128Mul),
+			(ADDR_BN128_PAIRING, PrecompileKind::Bn128Pairing),
+			(ADDR_BLAKE2F, PrecompileKind::Blake2F),
+			(ADDR_BLS12381_G1_ADD, PrecompileKind::Bls12381G1Add),
+			(ADDR_BLS12381_G1_MUL, PrecompileKind::Bls12381G1Mul),
+			(ADDR_BLS12381_G1_MULTI_EXP, PrecompileKind::Bls12381G1MultiExp),
+			(ADDR_BLS12381_G2_ADD, PrecompileKind::Bls12381G2Add),
+			(ADDR_BLS12381_G2_MUL, PrecompileKind::Bls12381G2Mul),
+			(ADDR_BLS12381_G2_MULTI_EXP, PrecompileKind::Bls12381G2MultiExp),
+			(ADDR_BLS12381_PAIRING, PrecompileKind::Bls12381Pairing),
+			(ADDR_BLS12381_MAP_G1, PrecompileKind::Bls12381MapG1),
+			(ADDR_BLS12381_MAP_G2, PrecompileKind::Bls12381MapG2),
+			(ADDR_STATE_STORAGE, PrecompileKind::StateStorage),
+			(ADDR_DISPATCH, PrecompileKind::Dispatch),
+			(ADDR_KTON, PrecompileKind::Assets),
+			(ADDR_DEPOSIT, PrecompileKind::Deposit),
+			(ADDR_STAKING, PrecompileKind::Staking),
+			(ADDR_CONVICTION_VOTING, PrecompileKind::ConvictionVoting),
+			(ADDR_EXPERIMENTAL, PrecompileKind::Unsupported),
 		]
+		.into_iter()
+		.map(|(a, k)| (H160(a), k))
+		.collect()
 	}
 }
 impl pallet_evm::PrecompileSet for Precompiles {
@@ -55,57 +77,82 @@ impl pallet_evm::PrecompileSet for Precompiles {
 		&self,
 		handle: &mut impl pallet_evm::PrecompileHandle,
 	) -> Option<pallet_evm::PrecompileResult> {
-		// darwinia
-		use darwinia_precompile_assets::AccountToAssetId;
+		let (code_addr, context_addr) = (handle.code_address(), handle.context().address);
 
-		let (code_addr, context_addr) = (handle.code_address().0, handle.context().address.0);
+		if !PrecompileRegistry::is_precompile_address(&code_addr) {
+			return None;
+		}
+		// Charge the registry lookup.
+		if let Err(e) =
+			handle.record_db_read::<Runtime>(PrecompileRegistry::lookup_max_encoded_len())
+		{
+			return Some(Err(e.into()));
+		}
+
+		let kind = PrecompileRegistry::precompile_of(&code_addr)?;
 
 		// Filter known precompile addresses except Ethereum officials
-		if Self::set().contains(&code_addr)
-			&& code_addr > precompiles::address_of(9)
-			&& code_addr != context_addr
-		{
+		if !kind.is_ethereum_official() && code_addr != context_addr {
 			return Some(Err(precompile_utils::prelude::revert(
 				"Cannot be called using `DELEGATECALL` or `CALLCODE`.",
 			)));
 		};
 
-		let output = match code_addr {
-			ADDR_EC_RECOVER => pallet_evm_precompile_simple::ECRecover::execute(handle),
-			ADDR_SHA256 => pallet_evm_precompile_simple::Sha256::execute(handle),
-			ADDR_RIPEMD160 => pallet_evm_precompile_simple::Ripemd160::execute(handle),
-			ADDR_IDENTITY => pallet_evm_precompile_simple::Identity::execute(handle),
-			ADDR_MODEXP => pallet_evm_precompile_modexp::Modexp::execute(handle),
-			ADDR_BN128_ADD => pallet_evm_precompile_bn128::Bn128Add::execute(handle),
-			ADDR_BN128_MUL => pallet_evm_precompile_bn128::Bn128Mul::execute(handle),
-			ADDR_BN128_PAIRING => pallet_evm_precompile_bn128::Bn128Pairing::execute(handle),
-			ADDR_BLAKE2F => pallet_evm_precompile_blake2::Blake2F::execute(handle),
-			ADDR_STATE_STORAGE => <darwinia_precompile_state_storage::StateStorage<
+		let output = match kind {
+			PrecompileKind::EcRecover => pallet_evm_precompile_simple::ECRecover::execute(handle),
+			PrecompileKind::Sha256 => pallet_evm_precompile_simple::Sha256::execute(handle),
+			PrecompileKind::Ripemd160 => pallet_evm_precompile_simple::Ripemd160::execute(handle),
+			PrecompileKind::Identity => pallet_evm_precompile_simple::Identity::execute(handle),
+			PrecompileKind::Modexp => pallet_evm_precompile_modexp::Modexp::execute(handle),
+			PrecompileKind::Bn128Add => pallet_evm_precompile_bn128::Bn128Add::execute(handle),
+			PrecompileKind::Bn128Mul => pallet_evm_precompile_bn128::Bn128Mul::execute(handle),
+			PrecompileKind::Bn128Pairing =>
+				pallet_evm_precompile_bn128::Bn128Pairing::execute(handle),
+			PrecompileKind::Blake2F => pallet_evm_precompile_blake2::Blake2F::execute(handle),
+			PrecompileKind::Bls12381G1Add => Bls12381::g1_add(handle),
+			PrecompileKind::Bls12381G1Mul => Bls12381::g1_mul(handle),
+			PrecompileKind::Bls12381G1MultiExp => Bls12381::g1_multi_exp(handle),
+			PrecompileKind::Bls12381G2Add => Bls12381::g2_add(handle),
+			PrecompileKind::Bls12381G2Mul => Bls12381::g2_mul(handle),
+			PrecompileKind::Bls12381G2MultiExp => Bls12381::g2_multi_exp(handle),
+			PrecompileKind::Bls12381Pairing => Bls12381::pairing(handle),
+			PrecompileKind::Bls12381MapG1 => Bls12381::map_g1(handle),
+			PrecompileKind::Bls12381MapG2 => Bls12381::map_g2(handle),
+			PrecompileKind::StateStorage => <darwinia_precompile_state_storage::StateStorage<
 				Runtime,
-				darwinia_precompile_state_storage::StateStorageFilter,
+				StateStorageFilter,
+				ParentStateRoot,
 			>>::execute(handle),
-			ADDR_DISPATCH => <pallet_evm_precompile_dispatch::Dispatch<
+			PrecompileKind::Dispatch => <pallet_evm_precompile_dispatch::Dispatch<
 				Runtime,
 				DarwiniaDispatchValidator,
 			>>::execute(handle),
-			a if (0x402..0x600).contains(&AssetIdConverter::account_to_asset_id(a.into())) =>
+			PrecompileKind::Assets =>
 				<darwinia_precompile_assets::ERC20Assets<Runtime, AssetIdConverter>>::execute(
 					handle,
 				),
-			ADDR_CONVICTION_VOTING =>
+			PrecompileKind::ConvictionVoting =>
 				<pallet_evm_precompile_conviction_voting::ConvictionVotingPrecompile<Runtime>>::execute(handle),
-			ADDR_EXPERIMENTAL | ADDR_DEPOSIT_DEPRECATED  | ADDR_STAKING_DEPRECATED =>
+			PrecompileKind::Deposit =>
+				<darwinia_precompile_deposit::Deposit<Runtime>>::execute(handle),
+			PrecompileKind::Staking =>
+				<darwinia_precompile_staking::Staking<Runtime>>::execute(handle),
+			PrecompileKind::Unsupported =>
 				Err(precompile_utils::prelude::revert("This precompile is not supported.")),
-			_ => return None,
 		};
 
 		Some(output)
 	}
 
 	fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
+		if !PrecompileRegistry::is_precompile_address(&address) {
+			return IsPrecompileResult::Answer { is_precompile: false, extra_cost: 0 };
+		}
+
 		IsPrecompileResult::Answer {
-			is_precompile: Self::set().contains(&address.0),
-			extra_cost: 0,
+			is_precompile: PrecompileRegistry::precompile_of(&address).is_some(),
+			// Charge the registry lookup.
+			extra_cost: RuntimeHelper::<Runtime>::db_read_gas_cost(),
 		}
 	}
 }
@@ -129,6 +176,19 @@ impl pallet_evm::FeeCalculator for TransactionPaymentGasPrice {
 	}
 }
 
+/// State root of the parent block, taken from the validation data set by the collator inherent.
+pub struct ParentStateRoot;
+impl darwinia_precompile_state_storage::StateRootT for ParentStateRoot {
+	// The parent head is an encoded parachain header, its digest is small.
+	const MAX_ENCODED_LEN: usize = 1_024;
+
+	fn parent_state_root() -> Option<sp_core::H256> {
+		let data = cumulus_pallet_parachain_system::ValidationData::<Runtime>::get()?;
+
+		<Header as codec::Decode>::decode(&mut &data.parent_head.0[..]).ok().map(|h| h.state_root)
+	}
+}
+
 /// Validation rule for dispatch precompile
 pub struct DarwiniaDispatchValidator;
 impl DispatchValidateT<AccountId, RuntimeCall> for DarwiniaDispatchValidator {
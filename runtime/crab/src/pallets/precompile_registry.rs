@@ -0,0 +1,33 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// darwinia
+use crate::*;
+
+frame_support::parameter_types! {
+	pub DefaultPrecompiles: Vec<(H160, pallet_config::precompiles::PrecompileKind)> =
+		Precompiles::defaults();
+}
+
+impl darwinia_precompile_registry::Config for Runtime {
+	type DefaultPrecompiles = DefaultPrecompiles;
+	type ManagerOrigin = RootOr<GeneralAdmin>;
+	type PrecompileKind = pallet_config::precompiles::PrecompileKind;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::darwinia_precompile_registry::WeightInfo<Self>;
+}
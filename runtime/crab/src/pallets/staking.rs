@@ -28,11 +28,38 @@ darwinia_common_runtime::fast_runtime_or_not!(
 	24 / 6
 );
 
+pub struct CurrentSlot;
+impl frame_support::traits::Get<u64> for CurrentSlot {
+	fn get() -> u64 {
+		<pallet_aura::CurrentSlot<Runtime>>::get().into()
+	}
+}
+
+frame_support::parameter_types! {
+	pub const MinPerformance: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(50);
+}
+
 impl darwinia_staking::Config for Runtime {
+	type AllocationGasBudget = ConstU64<1_000_000>;
+	type Collators = darwinia_staking::SessionCollators<Self>;
+	type CurrentSlot = CurrentSlot;
+	type ElectionGasLimit = ConstU64<1_000_000>;
 	type KtonStaking = darwinia_staking::KtonStaking<Self>;
+	type ManagerOrigin = RootOr<GeneralAdmin>;
+	type MaxCollators = ConstU32<64>;
+	type MaxEmissionSteps = ConstU32<64>;
+	type MaxInvulnerables = ConstU32<32>;
+	type MaxOffences = ConstU32<3>;
+	type MaxRetryAttempts = ConstU32<8>;
+	type MinPerformance = MinPerformance;
+	// 1 minute.
+	type RetryBaseDelay = ConstU32<{ MINUTES }>;
+	type RewardCurve = darwinia_staking::LinearRewardCurve;
 	type RewardPerSession = ConstU128<{ 29_200_000 * UNIT / 365 / SESSIONS_PER_DAY }>;
 	type RingStaking = darwinia_staking::RingStaking<Self>;
 	type RuntimeEvent = RuntimeEvent;
+	// 1 day.
+	type SessionsPerEra = ConstU32<{ SESSIONS_PER_DAY as u32 }>;
 	type Treasury = pallet_config::TreasuryAccount;
 	type WeightInfo = weights::darwinia_staking::WeightInfo<Self>;
 }
@@ -0,0 +1,35 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// darwinia
+use crate::*;
+use darwinia_state_storage_filter::{ReadPrefix, ReadRule};
+// polkadot-sdk
+use frame_support::{StorageHasher, Twox128};
+
+frame_support::parameter_types! {
+	pub DefaultReadRules: Vec<(ReadPrefix, ReadRule)> =
+		vec![(ReadPrefix::Pallet(Twox128::hash(b"EVM")), ReadRule::Deny)];
+}
+
+impl darwinia_state_storage_filter::Config for Runtime {
+	type DefaultRules = DefaultReadRules;
+	type ManagerOrigin = RootOr<GeneralAdmin>;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::darwinia_state_storage_filter::WeightInfo<Self>;
+}
@@ -24,7 +24,10 @@ pub mod cumulus_pallet_parachain_system;
 pub mod cumulus_pallet_xcmp_queue;
 pub mod darwinia_account_migration;
 pub mod darwinia_deposit;
+pub mod darwinia_precompile_bls12_381;
+pub mod darwinia_precompile_registry;
 pub mod darwinia_staking;
+pub mod darwinia_state_storage_filter;
 pub mod frame_system;
 pub mod pallet_assets;
 pub mod pallet_balances;
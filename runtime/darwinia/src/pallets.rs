@@ -94,6 +94,10 @@ pub use evm::*;
 
 mod ethtx_forwarder;
 
+mod precompile_registry;
+
+mod state_storage_filter;
+
 // Dev stuff.
 #[cfg(feature = "dev")]
 mod dev;
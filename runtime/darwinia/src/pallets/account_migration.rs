@@ -19,7 +19,39 @@
 // darwinia
 use crate::*;
 
+/// Carry the legacy reserves and locks into the holds and freezes of the account migration.
+pub enum LegacyBalanceConverter {}
+impl darwinia_account_migration::ConvertLegacyBalance<RuntimeHoldReason, RuntimeFreezeReason>
+	for LegacyBalanceConverter
+{
+	fn convert_reserve(
+		_: &darwinia_account_migration::LegacyId,
+	) -> darwinia_account_migration::Conversion<RuntimeHoldReason, RuntimeFreezeReason> {
+		// None of the Darwinia1 reserve owners exists here, hold them until the governance releases
+		// them.
+		darwinia_account_migration::Conversion::Hold(
+			darwinia_account_migration::HoldReason::LegacyReserve.into(),
+		)
+	}
+
+	fn convert_lock(
+		id: &darwinia_account_migration::LegacyId,
+	) -> darwinia_account_migration::Conversion<RuntimeHoldReason, RuntimeFreezeReason> {
+		match id {
+			// `pallet_vesting`, carried by the vesting schedules instead.
+			b"vesting " => darwinia_account_migration::Conversion::Release,
+			// `pallet_democracy`, `pallet_elections_phragmen` and the others, freeze them until the
+			// governance releases them.
+			_ => darwinia_account_migration::Conversion::Freeze(
+				darwinia_account_migration::FreezeReason::LegacyLock.into(),
+			),
+		}
+	}
+}
+
 impl darwinia_account_migration::Config for Runtime {
+	type LegacyBalanceConverter = LegacyBalanceConverter;
+	type ManagerOrigin = RootOr<GeneralAdmin>;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::darwinia_account_migration::WeightInfo<Self>;
 }
@@ -20,5 +20,8 @@
 use crate::*;
 
 impl darwinia_asset_limit::Config for Runtime {
+	type Fungibles = Assets;
+	type LocationToAccountId = LocationToAccountId;
 	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::darwinia_asset_limit::WeightInfo<Self>;
 }
@@ -57,7 +57,7 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 			min_balance,
 		)?;
 
-		// Lastly, the metadata
+		// Then, the metadata
 		Assets::force_set_metadata(
 			RuntimeOrigin::root(),
 			asset.into(),
@@ -65,6 +65,12 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 			metadata.symbol,
 			metadata.decimals,
 			metadata.is_frozen,
+		)?;
+
+		// Lastly, the precompile
+		PrecompileRegistry::do_register(
+			H160::from_low_u64_be(asset),
+			pallet_config::precompiles::PrecompileKind::Assets,
 		)
 	}
 
@@ -73,7 +79,15 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 		// Mark the asset as destroying
 		Assets::start_destroy(RuntimeOrigin::root(), asset.into())?;
 
-		Ok(())
+		// Remove the precompile and its revert code in the EVM
+		//
+		// The assets created before the registry might not have a precompile.
+		match PrecompileRegistry::do_unregister(H160::from_low_u64_be(asset)) {
+			Err(e)
+				if e == darwinia_precompile_registry::Error::<Runtime>::NotRegistered.into() =>
+				Ok(()),
+			r => r,
+		}
 	}
 
 	fn destroy_asset_dispatch_info_weight(asset: AssetId) -> frame_support::weights::Weight {
@@ -86,8 +100,10 @@ impl pallet_asset_manager::AssetRegistrar<Runtime> for AssetRegistrar {
 				.get_dispatch_info()
 				.weight;
 
-		// The db write for removing the precompile revert code in the EVM.
-		call_weight.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().writes(1))
+		// The db read and writes for unregistering the precompile and removing its revert code in
+		// the EVM.
+		call_weight
+			.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 3))
 	}
 }
 
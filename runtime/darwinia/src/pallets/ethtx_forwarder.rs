@@ -19,7 +19,13 @@
 // darwinia
 use crate::*;
 
+frame_support::parameter_types! {
+	pub const GasEstimationMargin: sp_runtime::Percent = sp_runtime::Percent::from_percent(20);
+}
+
 impl darwinia_ethtx_forwarder::Config for Runtime {
 	type ForwardEthOrigin = darwinia_ethtx_forwarder::EnsureRuntimeEthOrigin;
+	type GasEstimationMargin = GasEstimationMargin;
+	type RuntimeEvent = RuntimeEvent;
 	type ValidatedTransaction = pallet_ethereum::ValidatedTransaction<Self>;
 }
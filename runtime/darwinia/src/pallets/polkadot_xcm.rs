@@ -56,20 +56,20 @@ pub type LocalAssetTransactor = xcm_builder::FungibleAdapter<
 	// We dont allow teleport
 	(),
 >;
+/// Match the foreign assets registered in the asset manager.
+pub type ForeignAssetMatcher = xcm_builder::ConvertedConcreteId<
+	AssetId,
+	Balance,
+	xcm_primitives::AsAssetType<AssetId, AssetType, AssetManager>,
+	xcm_executor::traits::JustTry,
+>;
 // The non-reserve fungible transactor type
 // It will use pallet-assets, and the Id will be matched against AsAssetType
 pub type ForeignFungiblesTransactor = xcm_builder::FungiblesAdapter<
 	// Use this fungibles implementation:
 	Assets,
 	// Use this currency when it is a fungible asset matching the given location or name:
-	(
-		xcm_builder::ConvertedConcreteId<
-			AssetId,
-			Balance,
-			xcm_primitives::AsAssetType<AssetId, AssetType, AssetManager>,
-			xcm_executor::traits::JustTry,
-		>,
-	),
+	(ForeignAssetMatcher,),
 	// Do a simple punn to convert an AccountId20 Location into a native chain account ID:
 	LocationToAccountId,
 	// Our chain's account ID type (we can't get away without mentioning it explicitly):
@@ -79,7 +79,15 @@ pub type ForeignFungiblesTransactor = xcm_builder::FungiblesAdapter<
 	// We dont track any teleports
 	(),
 >;
-pub type AssetTransactors = (LocalAssetTransactor, ForeignFungiblesTransactor);
+pub type AssetTransactors = (
+	LocalAssetTransactor,
+	// Deposits over the foreign asset limit are trapped instead of being minted.
+	darwinia_asset_limit::LimitedTransactor<
+		Runtime,
+		ForeignFungiblesTransactor,
+		ForeignAssetMatcher,
+	>,
+);
 /// Type for specifying how a `Location` can be converted into an `AccountId`. This is used
 /// when determining ownership of accounts for asset transacting and when attempting to use XCM
 /// `Transact` in order to determine the dispatch Origin.
@@ -0,0 +1,126 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `darwinia_asset_limit`
+//!
+//! THESE WEIGHTS ARE NOT MEASURED. They are estimated by hand from the storage accesses of the
+//! benchmarks, regenerate this file with the benchmark CLI before the release.
+
+// Regenerate with:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --runtime
+// target/release/wbuild/darwinia-runtime/darwinia_runtime.compact.compressed.wasm
+// --genesis-builder
+// none
+// --pallet
+// *
+// --extrinsic
+// *
+// --header
+// .maintain/license-header
+// --template
+// .maintain/pallet-weight-template.hbs
+// --output
+// runtime/darwinia/src/weights
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weights for `darwinia_asset_limit`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> darwinia_asset_limit::WeightInfo for WeightInfo<T> {
+	/// Storage: `AssetManager::AssetTypeId` (r:1 w:0)
+	/// Proof: `AssetManager::AssetTypeId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetLimit::ForeignAssetLimit` (r:0 w:1)
+	/// Proof: `AssetLimit::ForeignAssetLimit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_foreign_asset_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `147`
+		//  Estimated: `3612`
+		// Minimum execution time: 11_230_000 picoseconds.
+		Weight::from_parts(11_610_000, 3612)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetLimit::TrappedDeposits` (r:1 w:1)
+	/// Proof: `AssetLimit::TrappedDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetManager::AssetTypeId` (r:1 w:0)
+	/// Proof: `AssetManager::AssetTypeId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetLimit::ForeignAssetLimit` (r:1 w:0)
+	/// Proof: `AssetLimit::ForeignAssetLimit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetLimit::InflowRateLimits` (r:2 w:0)
+	/// Proof: `AssetLimit::InflowRateLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetLimit::InflowWindows` (r:2 w:2)
+	/// Proof: `AssetLimit::InflowWindows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn release_trapped_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `713`
+		//  Estimated: `6653`
+		// Minimum execution time: 55_120_000 picoseconds.
+		Weight::from_parts(56_470_000, 6653)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `AssetLimit::TrappedDeposits` (r:1 w:1)
+	/// Proof: `AssetLimit::TrappedDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetManager::AssetTypeId` (r:1 w:0)
+	/// Proof: `AssetManager::AssetTypeId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:1 w:1)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn refund_trapped_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `589`
+		//  Estimated: `4054`
+		// Minimum execution time: 40_230_000 picoseconds.
+		Weight::from_parts(40_610_000, 4054)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AssetManager::AssetTypeId` (r:1 w:0)
+	/// Proof: `AssetManager::AssetTypeId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetLimit::InflowRateLimits` (r:0 w:1)
+	/// Proof: `AssetLimit::InflowRateLimits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AssetLimit::InflowWindows` (r:0 w:1)
+	/// Proof: `AssetLimit::InflowWindows` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_inflow_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `147`
+		//  Estimated: `3612`
+		// Minimum execution time: 12_230_000 picoseconds.
+		Weight::from_parts(12_610_000, 3612)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
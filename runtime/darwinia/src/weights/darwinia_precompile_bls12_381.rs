@@ -0,0 +1,126 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `darwinia_precompile_bls12_381`
+//!
+//! THESE WEIGHTS ARE NOT MEASURED. They are estimated by hand, regenerate this file with the
+//! benchmark CLI before the release.
+
+// Regenerate with:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --runtime
+// target/release/wbuild/darwinia-runtime/darwinia_runtime.compact.compressed.wasm
+// --genesis-builder
+// none
+// --pallet
+// *
+// --extrinsic
+// *
+// --header
+// .maintain/license-header
+// --template
+// .maintain/pallet-weight-template.hbs
+// --output
+// runtime/darwinia/src/weights
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weights for `darwinia_precompile_bls12_381`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> darwinia_precompile_bls12_381::WeightInfo for WeightInfo<T> {
+	fn g1_add() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 28_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 0)
+	}
+	fn g1_mul() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 812_000_000 picoseconds.
+		Weight::from_parts(845_000_000, 0)
+	}
+	/// The range of component `k` is `[1, 128]`.
+	fn g1_multi_exp(k: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_180_000_000 picoseconds.
+		Weight::from_parts(1_032_000_000, 0)
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_parts(412_000_000, 0).saturating_mul(k.into()))
+	}
+	fn g2_add() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(64_000_000, 0)
+	}
+	fn g2_mul() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_431_000_000 picoseconds.
+		Weight::from_parts(2_512_000_000, 0)
+	}
+	/// The range of component `k` is `[1, 128]`.
+	fn g2_multi_exp(k: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_367_000_000 picoseconds.
+		Weight::from_parts(2_847_000_000, 0)
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_parts(1_275_000_000, 0).saturating_mul(k.into()))
+	}
+	/// The range of component `k` is `[1, 16]`.
+	fn pairing(k: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_120_000_000 picoseconds.
+		Weight::from_parts(8_306_000_000, 0)
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_parts(6_085_000_000, 0).saturating_mul(k.into()))
+	}
+	fn map_g1() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 683_000_000 picoseconds.
+		Weight::from_parts(712_000_000, 0)
+	}
+	fn map_g2() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_467_000_000 picoseconds.
+		Weight::from_parts(2_534_000_000, 0)
+	}
+}
@@ -23,3 +23,26 @@ darwinia_common_runtime::impl_fee_tests! {}
 darwinia_common_runtime::impl_evm_tests! {}
 darwinia_common_runtime::impl_account_migration_tests! {}
 // darwinia_common_runtime::impl_maintenance_tests! {}
+
+mod asset_manager {
+	// darwinia
+	use super::mock::*;
+	// polkadot-sdk
+	use frame_support::{assert_noop, assert_ok};
+	use pallet_asset_manager::AssetRegistrar as _;
+	use sp_core::H160;
+
+	#[test]
+	fn destroy_asset_without_precompile_should_work() {
+		ExtBuilder::default().build().execute_with(|| {
+			// The assets created before the precompile registry might not have a precompile.
+			assert_eq!(PrecompileRegistry::precompile_of(&H160::from_low_u64_be(KTON_ID)), None);
+
+			assert_ok!(AssetRegistrar::destroy_foreign_asset(KTON_ID));
+			assert_noop!(
+				AssetRegistrar::destroy_foreign_asset(KTON_ID),
+				pallet_assets::Error::<Runtime>::AssetNotLive
+			);
+		});
+	}
+}
@@ -9,7 +9,9 @@ use array_bytes::{Dehexify, Hexify};
 use clap::{Parser, ValueEnum};
 use serde::Serialize;
 // polkadot-sdk
-use sp_core::{ed25519::Pair as Ep, sr25519::Pair as Sp, Pair as _};
+use sp_core::{
+	blake2_256, ecdsa::Pair as Ecp, ed25519::Pair as Ep, sr25519::Pair as Sp, Pair as _,
+};
 
 #[derive(Parser)]
 #[command(rename_all = "kebab")]
@@ -41,6 +43,8 @@ struct Cli {
 enum Scheme {
 	sr25519,
 	ed25519,
+	/// Use `migrate_ecdsa` to submit the signature.
+	ecdsa,
 }
 #[derive(Clone, ValueEnum)]
 enum Network {
@@ -59,19 +63,23 @@ impl Network {
 enum Pair {
 	S(Box<Sp>),
 	E(Box<Ep>),
+	Ec(Box<Ecp>),
 }
 impl Pair {
+	// The account id of an ECDSA key is the BLAKE2 hash of its compressed public key.
 	fn public_key(&self) -> [u8; 32] {
 		match self {
 			Self::S(p) => p.public().0,
 			Self::E(p) => p.public().0,
+			Self::Ec(p) => blake2_256(p.public().as_ref()),
 		}
 	}
 
-	fn sign(&self, msg: &[u8]) -> [u8; 64] {
+	fn sign(&self, msg: &[u8]) -> Vec<u8> {
 		match self {
-			Self::S(p) => p.sign(msg).0,
-			Self::E(p) => p.sign(msg).0,
+			Self::S(p) => p.sign(msg).0.to_vec(),
+			Self::E(p) => p.sign(msg).0.to_vec(),
+			Self::Ec(p) => p.sign(msg).0.to_vec(),
 		}
 	}
 }
@@ -146,6 +154,7 @@ fn sign_message(from: &str, scheme: &Scheme, message: &[u8]) -> (String, String)
 	let from = match scheme {
 		Scheme::sr25519 => Pair::S(Box::new(Sp::from_seed(&from))),
 		Scheme::ed25519 => Pair::E(Box::new(Ep::from_seed(&from))),
+		Scheme::ecdsa => Pair::Ec(Box::new(Ecp::from_seed(&from))),
 	};
 	let signature = from.sign(message);
 